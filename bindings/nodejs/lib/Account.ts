@@ -22,6 +22,30 @@ import type {
     MintTokenTransaction,
     PreparedTransactionData,
     OutputOptions,
+    ScheduledPayment,
+    ScheduledPaymentTrigger,
+    RecurringPayment,
+    RecurringPaymentSchedule,
+    Payment,
+    NftWithMetadata,
+    MintCollectionTransaction,
+    AliasOptions,
+    CreateAliasTransaction,
+    ClaimPolicy,
+    RejectionPolicy,
+    TransactionQuery,
+    TransactionPage,
+    OutputQuery,
+    OutputPage,
+    DecodedTransaction,
+    ExportOptions,
+    BalanceSnapshot,
+    Annotation,
+    AddressBalance,
+    PaymentRequest,
+    Invoice,
+    InvoiceOptions,
+    AmountFormatOptions,
 } from '../types';
 import type { SignedTransactionEssence } from '../types/signedTransactionEssence';
 import type {
@@ -47,6 +71,34 @@ export class Account {
         this.messageHandler = messageHandler;
     }
 
+    /**
+     * Set the private note and tags of an output, an empty annotation removes
+     * them
+     */
+    async annotateOutput(
+        outputId: string,
+        annotation: Annotation,
+    ): Promise<void> {
+        await this.messageHandler.callAccountMethod(this.meta.index, {
+            name: 'AnnotateOutput',
+            data: { outputId, annotation },
+        });
+    }
+
+    /**
+     * Set the private note and tags of a sent or incoming transaction, an
+     * empty annotation removes them
+     */
+    async annotateTransaction(
+        transactionId: string,
+        annotation: Annotation,
+    ): Promise<void> {
+        await this.messageHandler.callAccountMethod(this.meta.index, {
+            name: 'AnnotateTransaction',
+            data: { transactionId, annotation },
+        });
+    }
+
     async buildAliasOutput(data: BuildAliasOutputData): Promise<IAliasOutput> {
         const resp = await this.messageHandler.callAccountMethod(
             this.meta.index,
@@ -93,6 +145,51 @@ export class Account {
         return JSON.parse(resp).payload;
     }
 
+    /**
+     * Cancel an invoice that isn't paid yet, payments to its address will no
+     * longer be matched
+     */
+    async cancelInvoice(invoiceId: number): Promise<Invoice> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'CancelInvoice',
+                data: { invoiceId },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    async cancelRecurringPayment(
+        paymentId: number,
+    ): Promise<RecurringPayment> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'CancelRecurringPayment',
+                data: {
+                    paymentId,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    async cancelScheduledPayment(
+        paymentId: number,
+    ): Promise<ScheduledPayment> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'CancelScheduledPayment',
+                data: {
+                    paymentId,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async claimOutputs(outputIds: string[]): Promise<Transaction[]> {
         const resp = await this.messageHandler.callAccountMethod(
             this.meta.index,
@@ -123,6 +220,172 @@ export class Account {
         return JSON.parse(resp).payload;
     }
 
+    /**
+     * Create an alias output, the state controller and governor are the first address of the account by default
+     */
+    async createAlias(
+        aliasOptions: AliasOptions,
+        transactionOptions?: TransactionOptions,
+    ): Promise<CreateAliasTransaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'CreateAlias',
+                data: {
+                    aliasOptions,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Create an invoice with a new public address, payments to it are matched
+     * to the invoice during syncing
+     */
+    async createInvoice(options: InvoiceOptions): Promise<Invoice> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'CreateInvoice',
+                data: { options },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Create an `iota:` payment request URI, the address has to be of the
+     * network the account is connected to
+     */
+    async createPaymentRequestUri(
+        paymentRequest: PaymentRequest,
+    ): Promise<string> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'CreatePaymentRequestUri',
+                data: { paymentRequest },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Create a payment that will be sent repeatedly according to the schedule
+     */
+    async createRecurringPayment(
+        outputs: OutputOptions[],
+        schedule: RecurringPaymentSchedule,
+        transactionOptions?: TransactionOptions,
+    ): Promise<RecurringPayment> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'CreateRecurringPayment',
+                data: {
+                    outputs,
+                    schedule,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Decode a sent or incoming transaction of the account into its effect on
+     * the account, returns null if the transaction is unknown
+     */
+    async decodeTransaction(
+        transactionId: string,
+    ): Promise<DecodedTransaction | null> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'DecodeTransaction',
+                data: { transactionId },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Replace the outputs, trigger and options of a pending or failed scheduled payment
+     */
+    async editScheduledPayment(
+        paymentId: number,
+        outputs: OutputOptions[],
+        trigger: ScheduledPaymentTrigger,
+        transactionOptions?: TransactionOptions,
+    ): Promise<ScheduledPayment> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'EditScheduledPayment',
+                data: {
+                    paymentId,
+                    outputs,
+                    trigger,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Export the sent and incoming transactions of the account as CSV or JSON
+     * lines, sorted by their timestamp
+     */
+    async exportTransactions(options: ExportOptions): Promise<string> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ExportTransactions',
+                data: { options },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Format an amount of the base coin with the decimals from the node info
+     */
+    async formatBaseCoinAmount(
+        amount: string,
+        options?: AmountFormatOptions,
+    ): Promise<string> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'FormatBaseCoinAmount',
+                data: { amount, options },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Format a hex encoded amount of a native token with the decimals from its
+     * IRC30 metadata
+     */
+    async formatNativeTokenAmount(
+        tokenId: string,
+        amount: string,
+        options?: AmountFormatOptions,
+    ): Promise<string> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'FormatNativeTokenAmount',
+                data: { tokenId, amount, options },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async generateAddress(
         options?: AddressGenerationOptions,
     ): Promise<Address> {
@@ -147,6 +410,19 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the balance of each public and internal address of the account
+     */
+    async getAddressBalances(): Promise<AddressBalance[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'GetAddressBalances',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     getAlias(): string {
         return this.meta.alias;
     }
@@ -162,6 +438,63 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the latest balance snapshot taken at or before a time in
+     * milliseconds, returns null if there is no snapshot from before this time
+     */
+    async getBalanceAt(timestamp: number): Promise<BalanceSnapshot | null> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'GetBalanceAt',
+                data: { timestamp },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the balance snapshots taken within a time range in milliseconds,
+     * sorted by their time. The end of the range is exclusive
+     */
+    async getBalanceHistory(
+        fromTime?: number,
+        toTime?: number,
+    ): Promise<BalanceSnapshot[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'GetBalanceHistory',
+                data: { fromTime, toTime },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    async getClaimPolicy(): Promise<ClaimPolicy> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'GetClaimPolicy',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get an invoice by its id
+     */
+    async getInvoice(invoiceId: number): Promise<Invoice | null> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'GetInvoice',
+                data: { invoiceId },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async getOutput(outputId: string): Promise<OutputData> {
         const response = await this.messageHandler.callAccountMethod(
             this.meta.index,
@@ -192,6 +525,20 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the private note and tags of an output
+     */
+    async getOutputAnnotation(outputId: string): Promise<Annotation | null> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'GetOutputAnnotation',
+                data: { outputId },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async getOutputsWithAdditionalUnlockConditions(
         outputs: OutputsToClaim,
     ): Promise<string[]> {
@@ -207,6 +554,16 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    async getRejectionPolicy(): Promise<RejectionPolicy | null> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'GetRejectionPolicy',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async getTransaction(transactionId: string): Promise<Transaction> {
         const response = await this.messageHandler.callAccountMethod(
             this.meta.index,
@@ -220,6 +577,58 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the private note and tags of a transaction
+     */
+    async getTransactionAnnotation(
+        transactionId: string,
+    ): Promise<Annotation | null> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'GetTransactionAnnotation',
+                data: { transactionId },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Exclude outputs from the balance, input selection and claiming
+     */
+    async ignoreOutputs(outputIds: string[]): Promise<void> {
+        await this.messageHandler.callAccountMethod(this.meta.index, {
+            name: 'IgnoreOutputs',
+            data: {
+                outputIds,
+            },
+        });
+    }
+
+    /**
+     * Mint additional native tokens with an existing foundry, the amount is hex encoded
+     */
+    async increaseNativeTokenSupply(
+        tokenId: string,
+        increaseAmount: string,
+        accountAddress?: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<MintTokenTransaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'IncreaseNativeTokenSupply',
+                data: {
+                    tokenId,
+                    increaseAmount,
+                    accountAddress,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async listAddresses(): Promise<Address[]> {
         const response = await this.messageHandler.callAccountMethod(
             this.meta.index,
@@ -244,6 +653,49 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * List the nfts of the account that were minted with the provided Bech32 encoded issuer
+     */
+    async listCollectionNfts(issuer: string): Promise<NftWithMetadata[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ListCollectionNfts',
+                data: {
+                    issuer,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns all invoices of the account with their payments, ordered by
+     * their id
+     */
+    async listInvoices(): Promise<Invoice[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ListInvoices',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * List the nfts of the account with their IRC27 metadata
+     */
+    async listNfts(): Promise<NftWithMetadata[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ListNfts',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async listOutputs(): Promise<OutputData[]> {
         const response = await this.messageHandler.callAccountMethod(
             this.meta.index,
@@ -265,37 +717,99 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    async listRecurringPayments(): Promise<RecurringPayment[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ListRecurringPayments',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    async listScheduledPayments(): Promise<ScheduledPayment[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ListScheduledPayments',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async listTransactions(): Promise<Transaction[]> {
         const response = await this.messageHandler.callAccountMethod(
             this.meta.index,
             {
-                name: 'ListTransactions',
+                name: 'ListTransactions',
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    async listUnspentOutputs(): Promise<OutputData[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ListUnspentOutputs',
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    async minimumRequiredStorageDeposit(
+        outputs: OutputTypes[],
+    ): Promise<string> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'MinimumRequiredStorageDeposit',
+                data: {
+                    outputs,
+                },
             },
         );
-
         return JSON.parse(response).payload;
     }
 
-    async listUnspentOutputs(): Promise<OutputData[]> {
+    /**
+     * Mint the nft that represents a collection
+     */
+    async mintCollection(
+        collectionOptions: NftOptions,
+        transactionOptions?: TransactionOptions,
+    ): Promise<MintCollectionTransaction> {
         const response = await this.messageHandler.callAccountMethod(
             this.meta.index,
             {
-                name: 'ListUnspentOutputs',
+                name: 'MintCollection',
+                data: {
+                    collectionOptions,
+                    options: transactionOptions,
+                },
             },
         );
-
         return JSON.parse(response).payload;
     }
 
-    async minimumRequiredStorageDeposit(
-        outputs: OutputTypes[],
-    ): Promise<string> {
+    /**
+     * Mint nfts with a collection nft or alias as issuer, split over multiple transactions if needed
+     */
+    async mintCollectionNfts(
+        issuer: string,
+        nftOptions: NftOptions[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction[]> {
         const response = await this.messageHandler.callAccountMethod(
             this.meta.index,
             {
-                name: 'MinimumRequiredStorageDeposit',
+                name: 'MintCollectionNfts',
                 data: {
-                    outputs,
+                    issuer,
+                    nftOptions,
+                    options: transactionOptions,
                 },
             },
         );
@@ -338,6 +852,54 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Parse a human-readable amount of the base coin with the decimals from the
+     * node info
+     */
+    async parseBaseCoinAmount(amount: string): Promise<string> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ParseBaseCoinAmount',
+                data: { amount },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Parse a human-readable amount of a native token with the decimals from its
+     * IRC30 metadata, returns the hex encoded amount
+     */
+    async parseNativeTokenAmount(
+        tokenId: string,
+        amount: string,
+    ): Promise<string> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ParseNativeTokenAmount',
+                data: { tokenId, amount },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Parse an `iota:` payment request URI into the options for
+     * `prepareOutput`, fails if the request is expired
+     */
+    async parsePaymentRequestUri(uri: string): Promise<OutputOptions> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ParsePaymentRequestUri',
+                data: { uri },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async prepareOutput(
         options: OutputOptions,
         transactionOptions?: TransactionOptions,
@@ -389,6 +951,90 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Query the outputs of the account with a filter, sorted and paginated
+     */
+    async queryOutputs(query: OutputQuery): Promise<OutputPage> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'QueryOutputs',
+                data: { query },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Query the sent and incoming transactions of the account with a filter,
+     * sorted by their timestamp and paginated
+     */
+    async queryTransactions(query: TransactionQuery): Promise<TransactionPage> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'QueryTransactions',
+                data: { query },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Claim back outputs sent by the account that expired without being claimed by the recipient
+     */
+    async reclaimExpiredOutputs(): Promise<Transaction[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ReclaimExpiredOutputs',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Send outputs with a storage deposit return unlock condition back to their return address
+     */
+    async returnOutputs(
+        outputIds: string[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'ReturnOutputs',
+                data: {
+                    outputIds,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Schedule a payment, it will be sent by the background syncing once the trigger is reached
+     */
+    async schedulePayment(
+        outputs: OutputOptions[],
+        trigger: ScheduledPaymentTrigger,
+        transactionOptions?: TransactionOptions,
+    ): Promise<ScheduledPayment> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'SchedulePayment',
+                data: {
+                    outputs,
+                    trigger,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async sendAmount(
         addressesWithAmount: AddressWithAmount[],
         transactionOptions?: TransactionOptions,
@@ -407,6 +1053,28 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Send outputs with the funds of a Bech32 encoded alias or nft address of the account
+     */
+    async sendFromChainAddress(
+        chainAddress: string,
+        outputs: OutputTypes[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'SendFromChainAddress',
+                data: {
+                    chainAddress,
+                    outputs,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async sendMicroTransaction(
         addressesWithMicroAmount: AddressWithMicroAmount[],
         transactionOptions?: TransactionOptions,
@@ -479,6 +1147,26 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Send base coins, native tokens and nfts to multiple addresses in as few transactions as possible
+     */
+    async sendPayments(
+        payments: Payment[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'SendPayments',
+                data: {
+                    payments,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async setAlias(alias: string): Promise<void> {
         await this.messageHandler.callAccountMethod(this.meta.index, {
             name: 'SetAlias',
@@ -488,6 +1176,89 @@ export class Account {
         });
     }
 
+    /**
+     * Change the governor of an alias with a governance transition
+     */
+    async setAliasGovernor(
+        aliasId: string,
+        address: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'SetAliasGovernor',
+                data: {
+                    aliasId,
+                    address,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Change the state controller of an alias with a governance transition
+     */
+    async setAliasStateController(
+        aliasId: string,
+        address: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'SetAliasStateController',
+                data: {
+                    aliasId,
+                    address,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Set the resolution of the balance history in milliseconds, only the
+     * latest snapshot of each time slot is kept, 0 keeps every change
+     */
+    async setBalanceHistoryResolution(
+        resolutionInMilliseconds: number,
+    ): Promise<void> {
+        await this.messageHandler.callAccountMethod(this.meta.index, {
+            name: 'SetBalanceHistoryResolution',
+            data: { resolutionInMilliseconds },
+        });
+    }
+
+    /**
+     * Set the policy which outputs are claimed automatically by the background syncing
+     */
+    async setClaimPolicy(claimPolicy: ClaimPolicy): Promise<void> {
+        await this.messageHandler.callAccountMethod(this.meta.index, {
+            name: 'SetClaimPolicy',
+            data: {
+                claimPolicy,
+            },
+        });
+    }
+
+    /**
+     * Set the policy which incoming outputs are rejected automatically by the background syncing, null disables it
+     */
+    async setRejectionPolicy(
+        rejectionPolicy: RejectionPolicy | null,
+    ): Promise<void> {
+        await this.messageHandler.callAccountMethod(this.meta.index, {
+            name: 'SetRejectionPolicy',
+            data: {
+                rejectionPolicy,
+            },
+        });
+    }
+
     async signTransactionEssence(
         preparedTransactionData: PreparedTransactionData,
     ): Promise<SignedTransactionEssence> {
@@ -531,6 +1302,28 @@ export class Account {
         return JSON.parse(resp).payload;
     }
 
+    /**
+     * Transfer an alias by setting the state controller and governor to the address
+     */
+    async transferAlias(
+        aliasId: string,
+        address: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'TransferAlias',
+                data: {
+                    aliasId,
+                    address,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     async tryClaimOutputs(
         outputsToClaim: OutputsToClaim,
     ): Promise<Transaction[]> {
@@ -546,4 +1339,60 @@ export class Account {
 
         return JSON.parse(response).payload;
     }
+
+    /**
+     * Include ignored outputs in the balance again
+     */
+    async unignoreOutputs(outputIds: string[]): Promise<void> {
+        await this.messageHandler.callAccountMethod(this.meta.index, {
+            name: 'UnignoreOutputs',
+            data: {
+                outputIds,
+            },
+        });
+    }
+
+    /**
+     * Set or remove the metadata feature of an alias with a governance transition
+     */
+    async updateAliasGovernorMetadata(
+        aliasId: string,
+        metadata?: number[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'UpdateAliasGovernorMetadata',
+                data: {
+                    aliasId,
+                    metadata,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Update the state metadata of an alias with a state transition
+     */
+    async updateAliasStateMetadata(
+        aliasId: string,
+        stateMetadata: number[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'UpdateAliasStateMetadata',
+                data: {
+                    aliasId,
+                    stateMetadata,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
 }
@@ -15,6 +15,8 @@ import type {
     AccountSyncOptions,
     WalletEvent,
     LedgerStatus,
+    ExportOptions,
+    Transaction,
 } from '../types';
 
 export class AccountManager {
@@ -93,6 +95,17 @@ export class AccountManager {
         });
     }
 
+    /**
+     * Export the transactions of all accounts as CSV or JSON lines
+     */
+    async exportTransactions(options: ExportOptions): Promise<string> {
+        const response = await this.messageHandler.sendMessage({
+            cmd: 'ExportTransactions',
+            payload: options,
+        });
+        return JSON.parse(response).payload;
+    }
+
     async generateMnemonic(): Promise<string> {
         const response = await this.messageHandler.sendMessage({
             cmd: 'GenerateMnemonic',
@@ -175,6 +188,17 @@ export class AccountManager {
         return this.messageHandler.clearListeners(eventTypes);
     }
 
+    /**
+     * Resume or reconcile transactions of all accounts which didn't finish, for
+     * example because of a crash
+     */
+    async reconcileOutboxes(): Promise<Transaction[]> {
+        const response = await this.messageHandler.sendMessage({
+            cmd: 'ReconcileOutboxes',
+        });
+        return JSON.parse(response).payload;
+    }
+
     // TODO: test this
     async recoverAccounts(
         accountGapLimit: number,
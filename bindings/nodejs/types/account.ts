@@ -1,6 +1,8 @@
+import type { INativeToken } from '@iota/types';
 import type { Address, AddressWithUnspentOutputs } from './address';
 import type { OutputData } from './output';
 import type { Transaction } from './transaction';
+import type { Irc30Metadata } from './transactionOptions';
 
 /**
  * Account identifier
@@ -8,6 +10,19 @@ import type { Transaction } from './transaction';
  */
 export type AccountId = number | string;
 
+/** The balance of an account after a sync that changed it */
+export interface BalanceSnapshot {
+    /** Time of the sync in milliseconds */
+    timestamp: string;
+    baseCoin: BaseCoinBalance;
+    nativeTokens: NativeTokenTotal[];
+}
+
+export interface NativeTokenTotal {
+    tokenId: string;
+    total: string;
+}
+
 export interface AccountBalance {
     baseCoin: BaseCoinBalance;
     requiredStorageDeposit: string;
@@ -20,6 +35,72 @@ export interface AccountBalance {
      * TimelockUnlockCondition or ExpirationUnlockCondition this can change at any time
      */
     potentiallyLockedOutputs: { [outputId: string]: boolean };
+    /**
+     * Balances of the alias and nft addresses owned by the account, these amounts are not included in the totals, as
+     * they can only be spent together with the alias or nft
+     */
+    chainAddresses: ChainAddressBalance[];
+    /** Outputs that were rejected and are excluded from the balance */
+    ignoredOutputs: string[];
+    /** Breakdown of the outputs that can't be spent at the moment by the reason why */
+    lockedBalance: LockedBalance;
+}
+
+/** Outputs that can't be spent at the moment, grouped by the reason why */
+export interface LockedBalance {
+    /** Outputs that can be unlocked once their timelock expired */
+    timelocked: LockedOutput[];
+    /** Outputs sent by the account that can be claimed back after their expiration */
+    claimableAfterExpiration: LockedOutput[];
+    /** Outputs with a storage deposit return, the amount is what needs to be sent back */
+    storageDepositReturns: LockedOutput[];
+    /** Outputs that are used as inputs in pending transactions */
+    pendingTransactions: LockedOutput[];
+    /** Outputs that were rejected and are ignored by the account */
+    frozen: LockedOutput[];
+    /** Required storage deposit by output kind */
+    storageDeposit: RequiredStorageDeposit;
+}
+
+export interface LockedOutput {
+    outputId: string;
+    amount: string;
+    nativeTokens: INativeToken[];
+    nftId?: string;
+    /** Unix time in seconds from which on the output can be unlocked */
+    unlockTime?: number;
+}
+
+export interface RequiredStorageDeposit {
+    alias: string;
+    basic: string;
+    foundry: string;
+    nft: string;
+}
+
+/** Balance of the outputs locked to an alias or nft address owned by the account */
+export interface ChainAddressBalance {
+    /** Bech32 encoded alias or nft address */
+    address: string;
+    baseCoin: BaseCoinBalance;
+    nativeTokens: NativeTokenBalance[];
+    nfts: string[];
+    outputIds: string[];
+}
+
+/** Balance of a public or internal address of the account */
+export interface AddressBalance {
+    /** Bech32 encoded address */
+    address: string;
+    keyIndex: number;
+    /** If the address is an internal (change) address */
+    internal: boolean;
+    baseCoin: BaseCoinBalance;
+    requiredStorageDeposit: string;
+    nativeTokens: NativeTokenBalance[];
+    nfts: string[];
+    aliases: string[];
+    foundries: string[];
 }
 
 export interface AccountSyncOptions {
@@ -71,7 +152,33 @@ export interface BaseCoinBalance {
 }
 
 export interface NativeTokenBalance extends BaseCoinBalance {
-    id: string
+    tokenId: string;
+    /** IRC30 metadata of the token, if the foundry has valid metadata */
+    metadata?: Irc30Metadata;
+    /** Total amount adjusted by the decimals from the metadata, for example `1234.5` */
+    formattedTotal?: string;
+    /** Available amount adjusted by the decimals from the metadata */
+    formattedAvailable?: string;
+}
+
+export interface AmountFormatOptions {
+    /** Maximum number of fraction digits, all decimals are shown if not set */
+    maxFractionDigits?: number;
+    /** How the amount is rounded if it has more fraction digits than shown. Default: Down */
+    rounding?: RoundingMode;
+    /** Keep trailing zeros of the fraction, so all amounts have the same number of fraction digits */
+    keepTrailingZeros?: boolean;
+}
+
+export enum RoundingMode {
+    /** Round towards zero, a formatted balance is never higher than the actual balance */
+    Down = 'Down',
+    /** Round away from zero */
+    Up = 'Up',
+    /** Round to the nearest value, ties away from zero */
+    HalfUp = 'HalfUp',
+    /** Round to the nearest value, ties to the even neighbour */
+    HalfEven = 'HalfEven',
 }
 
 export enum CoinType {
@@ -1,5 +1,5 @@
 import type { OutputTypes } from '@iota/types';
-import type { AccountSyncOptions } from '../account';
+import type { AccountSyncOptions, AmountFormatOptions } from '../account';
 import type {
     AddressWithAmount,
     AddressWithMicroAmount,
@@ -13,15 +13,48 @@ import type {
     BuildFoundryOutputData,
     BuildNftOutputData,
 } from '../buildOutputData';
-import type { OutputOptions } from '../outputOptions';
-import type { OutputsToClaim } from '../output';
+import type { OutputOptions, PaymentRequest } from '../outputOptions';
+import type {
+    OutputsToClaim,
+    ClaimPolicy,
+    RejectionPolicy,
+    OutputQuery,
+} from '../output';
 import type { SignedTransactionEssence } from '../signedTransactionEssence';
 import type { PreparedTransactionData } from '../preparedTransactionData';
 import type {
     NativeTokenOptions,
     TransactionOptions,
     NftOptions,
+    AliasOptions,
 } from '../transactionOptions';
+import type {
+    ScheduledPaymentTrigger,
+    RecurringPaymentSchedule,
+    Payment,
+    InvoiceOptions,
+} from '../payment';
+import type {
+    TransactionQuery,
+    ExportOptions,
+    Annotation,
+} from '../transaction';
+
+export type __AnnotateOutputMethod__ = {
+    name: 'AnnotateOutput';
+    data: {
+        outputId: string;
+        annotation: Annotation;
+    };
+};
+
+export type __AnnotateTransactionMethod__ = {
+    name: 'AnnotateTransaction';
+    data: {
+        transactionId: string;
+        annotation: Annotation;
+    };
+};
 
 export type __BuildAliasOutputMethod__ = {
     name: 'BuildAliasOutput';
@@ -43,6 +76,27 @@ export type __BuildNftOutputMethod__ = {
     data: BuildNftOutputData;
 };
 
+export type __CancelInvoiceMethod__ = {
+    name: 'CancelInvoice';
+    data: {
+        invoiceId: number;
+    };
+};
+
+export type __CancelRecurringPaymentMethod__ = {
+    name: 'CancelRecurringPayment';
+    data: {
+        paymentId: number;
+    };
+};
+
+export type __CancelScheduledPaymentMethod__ = {
+    name: 'CancelScheduledPayment';
+    data: {
+        paymentId: number;
+    };
+};
+
 export type __ClaimOutputsMethod__ = {
     name: 'ClaimOutputs';
     data: {
@@ -58,6 +112,78 @@ export type __ConsolidateOutputsMethod__ = {
     };
 };
 
+export type __CreateAliasMethod__ = {
+    name: 'CreateAlias';
+    data: {
+        aliasOptions: AliasOptions;
+        options?: TransactionOptions;
+    };
+};
+
+export type __CreateInvoiceMethod__ = {
+    name: 'CreateInvoice';
+    data: {
+        options: InvoiceOptions;
+    };
+};
+
+export type __CreatePaymentRequestUriMethod__ = {
+    name: 'CreatePaymentRequestUri';
+    data: {
+        paymentRequest: PaymentRequest;
+    };
+};
+
+export type __CreateRecurringPaymentMethod__ = {
+    name: 'CreateRecurringPayment';
+    data: {
+        outputs: OutputOptions[];
+        schedule: RecurringPaymentSchedule;
+        options?: TransactionOptions;
+    };
+};
+
+export type __DecodeTransactionMethod__ = {
+    name: 'DecodeTransaction';
+    data: {
+        transactionId: string;
+    };
+};
+
+export type __EditScheduledPaymentMethod__ = {
+    name: 'EditScheduledPayment';
+    data: {
+        paymentId: number;
+        outputs: OutputOptions[];
+        trigger: ScheduledPaymentTrigger;
+        options?: TransactionOptions;
+    };
+};
+
+export type __ExportTransactionsMethod__ = {
+    name: 'ExportTransactions';
+    data: {
+        options: ExportOptions;
+    };
+};
+
+export type __FormatBaseCoinAmountMethod__ = {
+    name: 'FormatBaseCoinAmount';
+    data: {
+        amount: string;
+        options?: AmountFormatOptions;
+    };
+};
+
+export type __FormatNativeTokenAmountMethod__ = {
+    name: 'FormatNativeTokenAmount';
+    data: {
+        tokenId: string;
+        amount: string;
+        options?: AmountFormatOptions;
+    };
+};
+
 export type __GenerateAddressesMethod__ = {
     name: 'GenerateAddresses';
     data: {
@@ -66,10 +192,40 @@ export type __GenerateAddressesMethod__ = {
     };
 };
 
+export type __GetAddressBalancesMethod__ = {
+    name: 'GetAddressBalances';
+};
+
 export type __GetBalanceMethod__ = {
     name: 'GetBalance';
 };
 
+export type __GetBalanceAtMethod__ = {
+    name: 'GetBalanceAt';
+    data: {
+        timestamp: number;
+    };
+};
+
+export type __GetBalanceHistoryMethod__ = {
+    name: 'GetBalanceHistory';
+    data: {
+        fromTime?: number;
+        toTime?: number;
+    };
+};
+
+export type __GetClaimPolicyMethod__ = {
+    name: 'GetClaimPolicy';
+};
+
+export type __GetInvoiceMethod__ = {
+    name: 'GetInvoice';
+    data: {
+        invoiceId: number;
+    };
+};
+
 export type __GetOutputMethod__ = {
     name: 'GetOutput';
     data: {
@@ -84,6 +240,13 @@ export type __GetFoundryOutputMethod__ = {
     };
 };
 
+export type __GetOutputAnnotationMethod__ = {
+    name: 'GetOutputAnnotation';
+    data: {
+        outputId: string;
+    };
+};
+
 export type __GetOutputsWithAdditionalUnlockConditionsMethod__ = {
     name: 'GetOutputsWithAdditionalUnlockConditions';
     data: {
@@ -91,6 +254,10 @@ export type __GetOutputsWithAdditionalUnlockConditionsMethod__ = {
     };
 };
 
+export type __GetRejectionPolicyMethod__ = {
+    name: 'GetRejectionPolicy';
+};
+
 export type __GetTransactionMethod__ = {
     name: 'GetTransaction';
     data: {
@@ -98,6 +265,30 @@ export type __GetTransactionMethod__ = {
     };
 };
 
+export type __GetTransactionAnnotationMethod__ = {
+    name: 'GetTransactionAnnotation';
+    data: {
+        transactionId: string;
+    };
+};
+
+export type __IgnoreOutputsMethod__ = {
+    name: 'IgnoreOutputs';
+    data: {
+        outputIds: string[];
+    };
+};
+
+export type __IncreaseNativeTokenSupplyMethod__ = {
+    name: 'IncreaseNativeTokenSupply';
+    data: {
+        tokenId: string;
+        increaseAmount: string;
+        accountAddress?: string;
+        options?: TransactionOptions;
+    };
+};
+
 export type __ListAddressesMethod__ = {
     name: 'ListAddresses';
 };
@@ -106,6 +297,21 @@ export type __ListAddressesWithUnspentOutputsMethod__ = {
     name: 'ListAddressesWithUnspentOutputs';
 };
 
+export type __ListCollectionNftsMethod__ = {
+    name: 'ListCollectionNfts';
+    data: {
+        issuer: string;
+    };
+};
+
+export type __ListInvoicesMethod__ = {
+    name: 'ListInvoices';
+};
+
+export type __ListNftsMethod__ = {
+    name: 'ListNfts';
+};
+
 export type __ListOutputsMethod__ = {
     name: 'ListOutputs';
 };
@@ -114,6 +320,14 @@ export type __ListPendingTransactionsMethod__ = {
     name: 'ListPendingTransactions';
 };
 
+export type __ListRecurringPaymentsMethod__ = {
+    name: 'ListRecurringPayments';
+};
+
+export type __ListScheduledPaymentsMethod__ = {
+    name: 'ListScheduledPayments';
+};
+
 export type __ListTransactionsMethod__ = {
     name: 'ListTransactions';
 };
@@ -129,6 +343,23 @@ export type __MinimumRequiredStorageDepositMethod__ = {
     };
 };
 
+export type __MintCollectionMethod__ = {
+    name: 'MintCollection';
+    data: {
+        collectionOptions: NftOptions;
+        options?: TransactionOptions;
+    };
+};
+
+export type __MintCollectionNftsMethod__ = {
+    name: 'MintCollectionNfts';
+    data: {
+        issuer: string;
+        nftOptions: NftOptions[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __MintNativeTokenMethod__ = {
     name: 'MintNativeToken';
     data: {
@@ -145,6 +376,28 @@ export type __MintNftsMethod__ = {
     };
 };
 
+export type __ParseBaseCoinAmountMethod__ = {
+    name: 'ParseBaseCoinAmount';
+    data: {
+        amount: string;
+    };
+};
+
+export type __ParseNativeTokenAmountMethod__ = {
+    name: 'ParseNativeTokenAmount';
+    data: {
+        tokenId: string;
+        amount: string;
+    };
+};
+
+export type __ParsePaymentRequestUriMethod__ = {
+    name: 'ParsePaymentRequestUri';
+    data: {
+        uri: string;
+    };
+};
+
 export type __PrepareOutputMethod__ = {
     name: 'PrepareOutput';
     data: {
@@ -169,6 +422,41 @@ export type __PrepareTransactionMethod__ = {
     };
 };
 
+export type __QueryOutputsMethod__ = {
+    name: 'QueryOutputs';
+    data: {
+        query: OutputQuery;
+    };
+};
+
+export type __QueryTransactionsMethod__ = {
+    name: 'QueryTransactions';
+    data: {
+        query: TransactionQuery;
+    };
+};
+
+export type __ReclaimExpiredOutputsMethod__ = {
+    name: 'ReclaimExpiredOutputs';
+};
+
+export type __ReturnOutputsMethod__ = {
+    name: 'ReturnOutputs';
+    data: {
+        outputIds: string[];
+        options?: TransactionOptions;
+    };
+};
+
+export type __SchedulePaymentMethod__ = {
+    name: 'SchedulePayment';
+    data: {
+        outputs: OutputOptions[];
+        trigger: ScheduledPaymentTrigger;
+        options?: TransactionOptions;
+    };
+};
+
 export type __SendAmountMethod__ = {
     name: 'SendAmount';
     data: {
@@ -177,6 +465,15 @@ export type __SendAmountMethod__ = {
     };
 };
 
+export type __SendFromChainAddressMethod__ = {
+    name: 'SendFromChainAddress';
+    data: {
+        chainAddress: string;
+        outputs: OutputTypes[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __SendMicroTransactionMethod__ = {
     name: 'SendMicroTransaction';
     data: {
@@ -209,6 +506,14 @@ export type __SendOutputsMethod__ = {
     };
 };
 
+export type __SendPaymentsMethod__ = {
+    name: 'SendPayments';
+    data: {
+        payments: Payment[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __SetAliasMethod__ = {
     name: 'SetAlias';
     data: {
@@ -216,6 +521,45 @@ export type __SetAliasMethod__ = {
     };
 };
 
+export type __SetAliasGovernorMethod__ = {
+    name: 'SetAliasGovernor';
+    data: {
+        aliasId: string;
+        address: string;
+        options?: TransactionOptions;
+    };
+};
+
+export type __SetAliasStateControllerMethod__ = {
+    name: 'SetAliasStateController';
+    data: {
+        aliasId: string;
+        address: string;
+        options?: TransactionOptions;
+    };
+};
+
+export type __SetBalanceHistoryResolutionMethod__ = {
+    name: 'SetBalanceHistoryResolution';
+    data: {
+        resolutionInMilliseconds: number;
+    };
+};
+
+export type __SetClaimPolicyMethod__ = {
+    name: 'SetClaimPolicy';
+    data: {
+        claimPolicy: ClaimPolicy;
+    };
+};
+
+export type __SetRejectionPolicyMethod__ = {
+    name: 'SetRejectionPolicy';
+    data: {
+        rejectionPolicy: RejectionPolicy | null;
+    };
+};
+
 export type __SignTransactionEssenceMethod__ = {
     name: 'SignTransactionEssence';
     data: {
@@ -237,9 +581,43 @@ export type __SyncAccountMethod__ = {
     };
 };
 
+export type __TransferAliasMethod__ = {
+    name: 'TransferAlias';
+    data: {
+        aliasId: string;
+        address: string;
+        options?: TransactionOptions;
+    };
+};
+
 export type __TryClaimOutputsMethod__ = {
     name: 'TryClaimOutputs';
     data: {
         outputsToClaim: OutputsToClaim;
     };
 };
+
+export type __UnignoreOutputsMethod__ = {
+    name: 'UnignoreOutputs';
+    data: {
+        outputIds: string[];
+    };
+};
+
+export type __UpdateAliasGovernorMetadataMethod__ = {
+    name: 'UpdateAliasGovernorMetadata';
+    data: {
+        aliasId: string;
+        metadata?: number[];
+        options?: TransactionOptions;
+    };
+};
+
+export type __UpdateAliasStateMetadataMethod__ = {
+    name: 'UpdateAliasStateMetadata';
+    data: {
+        aliasId: string;
+        stateMetadata: number[];
+        options?: TransactionOptions;
+    };
+};
@@ -5,6 +5,7 @@ import type {
 } from '../account';
 import type { WalletEvent } from '../event';
 import type { Auth, ClientOptions } from '../network';
+import type { ExportOptions } from '../transaction';
 
 export type __BackupMessage__ = {
     cmd: 'Backup';
@@ -45,6 +46,11 @@ export type __EmitTestEventMessage__ = {
     payload: WalletEvent;
 };
 
+export type __ExportTransactionsMessage__ = {
+    cmd: 'ExportTransactions';
+    payload: ExportOptions;
+};
+
 export type __GenerateMnemonicMessage__ = {
     cmd: 'GenerateMnemonic';
 };
@@ -82,6 +88,10 @@ export type __IsStrongholdPasswordAvailableMessage__ = {
     cmd: 'IsStrongholdPasswordAvailable';
 };
 
+export type __ReconcileOutboxesMessage__ = {
+    cmd: 'ReconcileOutboxes';
+};
+
 export type __RecoverAccountsMessage__ = {
     cmd: 'RecoverAccounts';
     payload: {
@@ -1,39 +1,89 @@
 import type { AccountId } from '../account';
 import type {
+    __AnnotateOutputMethod__,
+    __AnnotateTransactionMethod__,
     __BuildAliasOutputMethod__,
     __BuildBasicOutputMethod__,
     __BuildFoundryOutputMethod__,
     __BuildNftOutputMethod__,
+    __CancelInvoiceMethod__,
+    __CancelRecurringPaymentMethod__,
+    __CancelScheduledPaymentMethod__,
     __ClaimOutputsMethod__,
     __ConsolidateOutputsMethod__,
+    __CreateAliasMethod__,
+    __CreateInvoiceMethod__,
+    __CreatePaymentRequestUriMethod__,
+    __CreateRecurringPaymentMethod__,
+    __DecodeTransactionMethod__,
+    __EditScheduledPaymentMethod__,
+    __ExportTransactionsMethod__,
+    __FormatBaseCoinAmountMethod__,
+    __FormatNativeTokenAmountMethod__,
     __GenerateAddressesMethod__,
+    __GetAddressBalancesMethod__,
+    __GetBalanceAtMethod__,
+    __GetBalanceHistoryMethod__,
     __GetBalanceMethod__,
+    __GetClaimPolicyMethod__,
+    __GetInvoiceMethod__,
+    __GetOutputAnnotationMethod__,
     __GetOutputMethod__,
     __GetFoundryOutputMethod__,
     __GetOutputsWithAdditionalUnlockConditionsMethod__,
+    __GetRejectionPolicyMethod__,
+    __GetTransactionAnnotationMethod__,
     __GetTransactionMethod__,
+    __IgnoreOutputsMethod__,
+    __IncreaseNativeTokenSupplyMethod__,
     __ListAddressesMethod__,
     __ListAddressesWithUnspentOutputsMethod__,
+    __ListCollectionNftsMethod__,
+    __ListInvoicesMethod__,
+    __ListNftsMethod__,
     __ListOutputsMethod__,
     __ListPendingTransactionsMethod__,
+    __ListRecurringPaymentsMethod__,
+    __ListScheduledPaymentsMethod__,
     __ListTransactionsMethod__,
     __ListUnspentOutputsMethod__,
     __MinimumRequiredStorageDepositMethod__,
+    __MintCollectionMethod__,
+    __MintCollectionNftsMethod__,
     __MintNativeTokenMethod__,
     __MintNftsMethod__,
+    __ParseBaseCoinAmountMethod__,
+    __ParseNativeTokenAmountMethod__,
+    __ParsePaymentRequestUriMethod__,
     __PrepareOutputMethod__,
     __PrepareSendAmountMethod__,
     __PrepareTransactionMethod__,
+    __QueryOutputsMethod__,
+    __QueryTransactionsMethod__,
+    __ReclaimExpiredOutputsMethod__,
+    __ReturnOutputsMethod__,
+    __SchedulePaymentMethod__,
     __SendAmountMethod__,
+    __SendFromChainAddressMethod__,
     __SendMicroTransactionMethod__,
     __SendNativeTokensMethod__,
     __SendNftMethod__,
     __SendOutputsMethod__,
+    __SendPaymentsMethod__,
+    __SetAliasGovernorMethod__,
     __SetAliasMethod__,
+    __SetAliasStateControllerMethod__,
+    __SetBalanceHistoryResolutionMethod__,
+    __SetClaimPolicyMethod__,
+    __SetRejectionPolicyMethod__,
     __SignTransactionEssenceMethod__,
     __SubmitAndStoreTransactionMethod__,
     __SyncAccountMethod__,
+    __TransferAliasMethod__,
     __TryClaimOutputsMethod__,
+    __UnignoreOutputsMethod__,
+    __UpdateAliasGovernorMetadataMethod__,
+    __UpdateAliasStateMetadataMethod__,
 } from './account';
 import type {
     __BackupMessage__,
@@ -43,6 +93,7 @@ import type {
     __CreateAccountMessage__,
     __DeleteAccountsAndDatabaseMessage__,
     __EmitTestEventMessage__,
+    __ExportTransactionsMessage__,
     __GenerateMnemonicMessage__,
     __GetAccountMessage__,
     __GetAccountsMessage__,
@@ -50,6 +101,7 @@ import type {
     __GetNodeInfoMessage__,
     __HexToBech32__,
     __IsStrongholdPasswordAvailableMessage__,
+    __ReconcileOutboxesMessage__,
     __RecoverAccountsMessage__,
     __RemoveLatestAccountMessage__,
     __RestoreBackupMessage__,
@@ -63,40 +115,90 @@ import type {
 } from './accountManager';
 
 export type __AccountMethod__ =
+    | __AnnotateOutputMethod__
+    | __AnnotateTransactionMethod__
     | __BuildAliasOutputMethod__
     | __BuildBasicOutputMethod__
     | __BuildFoundryOutputMethod__
     | __BuildNftOutputMethod__
+    | __CancelInvoiceMethod__
+    | __CancelRecurringPaymentMethod__
+    | __CancelScheduledPaymentMethod__
     | __ClaimOutputsMethod__
     | __ConsolidateOutputsMethod__
+    | __CreateAliasMethod__
+    | __CreateInvoiceMethod__
+    | __CreatePaymentRequestUriMethod__
+    | __CreateRecurringPaymentMethod__
+    | __DecodeTransactionMethod__
+    | __EditScheduledPaymentMethod__
+    | __ExportTransactionsMethod__
+    | __FormatBaseCoinAmountMethod__
+    | __FormatNativeTokenAmountMethod__
     | __GenerateAddressesMethod__
+    | __GetAddressBalancesMethod__
+    | __GetBalanceAtMethod__
+    | __GetBalanceHistoryMethod__
     | __GetBalanceMethod__
+    | __GetClaimPolicyMethod__
+    | __GetInvoiceMethod__
+    | __GetOutputAnnotationMethod__
     | __GetOutputMethod__
     | __GetFoundryOutputMethod__
     | __GetOutputsWithAdditionalUnlockConditionsMethod__
+    | __GetRejectionPolicyMethod__
+    | __GetTransactionAnnotationMethod__
     | __GetTransactionMethod__
+    | __IgnoreOutputsMethod__
+    | __IncreaseNativeTokenSupplyMethod__
     | __ListAddressesMethod__
     | __ListAddressesWithUnspentOutputsMethod__
+    | __ListCollectionNftsMethod__
+    | __ListInvoicesMethod__
+    | __ListNftsMethod__
     | __ListOutputsMethod__
     | __ListPendingTransactionsMethod__
+    | __ListRecurringPaymentsMethod__
+    | __ListScheduledPaymentsMethod__
     | __ListTransactionsMethod__
     | __ListUnspentOutputsMethod__
     | __MinimumRequiredStorageDepositMethod__
+    | __MintCollectionMethod__
+    | __MintCollectionNftsMethod__
     | __MintNativeTokenMethod__
     | __MintNftsMethod__
+    | __ParseBaseCoinAmountMethod__
+    | __ParseNativeTokenAmountMethod__
+    | __ParsePaymentRequestUriMethod__
     | __PrepareOutputMethod__
     | __PrepareSendAmountMethod__
     | __PrepareTransactionMethod__
+    | __QueryOutputsMethod__
+    | __QueryTransactionsMethod__
+    | __ReclaimExpiredOutputsMethod__
+    | __ReturnOutputsMethod__
+    | __SchedulePaymentMethod__
     | __SendAmountMethod__
+    | __SendFromChainAddressMethod__
     | __SendMicroTransactionMethod__
     | __SendNativeTokensMethod__
     | __SendNftMethod__
     | __SendOutputsMethod__
+    | __SendPaymentsMethod__
+    | __SetAliasGovernorMethod__
     | __SetAliasMethod__
+    | __SetAliasStateControllerMethod__
+    | __SetBalanceHistoryResolutionMethod__
+    | __SetClaimPolicyMethod__
+    | __SetRejectionPolicyMethod__
     | __SignTransactionEssenceMethod__
     | __SubmitAndStoreTransactionMethod__
     | __SyncAccountMethod__
-    | __TryClaimOutputsMethod__;
+    | __TransferAliasMethod__
+    | __TryClaimOutputsMethod__
+    | __UnignoreOutputsMethod__
+    | __UpdateAliasGovernorMetadataMethod__
+    | __UpdateAliasStateMetadataMethod__;
 
 export type __CallAccountMethodMessage__ = {
     cmd: 'CallAccountMethod';
@@ -115,6 +217,7 @@ export type __Message__ =
     | __CreateAccountMessage__
     | __DeleteAccountsAndDatabaseMessage__
     | __EmitTestEventMessage__
+    | __ExportTransactionsMessage__
     | __GenerateMnemonicMessage__
     | __GetAccountMessage__
     | __GetAccountsMessage__
@@ -122,6 +225,7 @@ export type __Message__ =
     | __GetNodeInfoMessage__
     | __HexToBech32__
     | __IsStrongholdPasswordAvailableMessage__
+    | __ReconcileOutboxesMessage__
     | __RecoverAccountsMessage__
     | __RemoveLatestAccountMessage__
     | __RestoreBackupMessage__
@@ -6,7 +6,11 @@ export type EventType =
     | 'NewOutput'
     | 'SpentOutput'
     | 'TransactionInclusion'
-    | 'TransactionProgress';
+    | 'TransactionProgress'
+    | 'ScheduledPayment'
+    | 'RecurringPayment'
+    | 'OutputsClaimed'
+    | 'Invoice';
 
 export enum WalletEvent {
     ConsolidationRequired = 'ConsolidationRequired',
@@ -15,4 +19,8 @@ export enum WalletEvent {
     SpentOutput = 'SpentOutput',
     TransactionInclusion = 'TransactionInclusion',
     TransactionProgress = 'TransactionProgress',
+    ScheduledPayment = 'ScheduledPayment',
+    RecurringPayment = 'RecurringPayment',
+    OutputsClaimed = 'OutputsClaimed',
+    Invoice = 'Invoice',
 }
@@ -8,6 +8,7 @@ export * from './loggerConfig';
 export * from './network';
 export * from './output';
 export * from './outputOptions';
+export * from './payment';
 export * from './preparedTransactionData';
 export * from './secretManager';
 export * from './signedTransactionEssence';
@@ -3,6 +3,8 @@ import type {
     OutputTypes,
     IOutputMetadataResponse,
 } from '@iota/types';
+import type { SortOrder } from './transaction';
+import type { Irc27Metadata } from './transactionOptions';
 
 export enum OutputsToClaim {
     None = 'None',
@@ -13,6 +15,33 @@ export enum OutputsToClaim {
 }
 
 /** An output with metadata */
+/**
+ * Which outputs with additional unlock conditions are claimed automatically by the background syncing. The sender of
+ * an output is taken from its sender feature, outputs without sender feature aren't claimed with `AllowListedSenders`
+ */
+export type ClaimPolicy =
+    | { type: 'Always' }
+    | { type: 'Never' }
+    | { type: 'ReturnAmountBelow'; value: number }
+    | { type: 'AllowListedSenders'; value: string[] };
+
+/** What happens with outputs that are rejected by the `RejectionPolicy` */
+export enum RejectAction {
+    /** Exclude the outputs from the balance, input selection and claiming */
+    Ignore = 'Ignore',
+    /** Send the outputs back to the return address of their storage deposit return unlock condition */
+    Return = 'Return',
+}
+
+/** Incoming outputs are rejected by the background syncing if any of the conditions matches */
+export interface RejectionPolicy {
+    action: RejectAction;
+    minReturnAmount?: number;
+    /** Bech32 encoded addresses, outputs without sender feature have an unknown sender and aren't rejected by it */
+    blockedSenders?: string[];
+    blockedTokenIds?: string[];
+}
+
 export interface OutputData {
     /** The identifier of an Output */
     outputId: string;
@@ -36,3 +65,70 @@ export interface Segment {
     hardened: boolean;
     bs: number[];
 }
+
+export enum OutputKind {
+    Alias = 'Alias',
+    Basic = 'Basic',
+    Foundry = 'Foundry',
+    Nft = 'Nft',
+    Treasury = 'Treasury',
+}
+
+export enum UnlockConditionType {
+    Address = 'Address',
+    StorageDepositReturn = 'StorageDepositReturn',
+    Timelock = 'Timelock',
+    Expiration = 'Expiration',
+    StateControllerAddress = 'StateControllerAddress',
+    GovernorAddress = 'GovernorAddress',
+    ImmutableAliasAddress = 'ImmutableAliasAddress',
+}
+
+export enum OutputSortBy {
+    Timestamp = 'Timestamp',
+    Amount = 'Amount',
+}
+
+/** Only outputs that match all set fields are returned */
+export interface OutputFilter {
+    outputKinds?: OutputKind[];
+    /** Outputs need to have all of these unlock conditions */
+    unlockConditionTypes?: UnlockConditionType[];
+    tokenId?: string;
+    nftId?: string;
+    aliasId?: string;
+    /** Bech32 encoded account address */
+    address?: string;
+    minAmount?: number;
+    maxAmount?: number;
+    isSpent?: boolean;
+    /**
+     * Requires a request to the node to get the current time, locked and
+     * ignored outputs aren't claimable
+     */
+    claimableNow?: boolean;
+}
+
+export interface OutputQuery {
+    filter?: OutputFilter;
+    sortBy?: OutputSortBy;
+    sort?: SortOrder;
+    /** The nextCursor of the previous page */
+    cursor?: string;
+    limit?: number;
+}
+
+export interface OutputPage {
+    outputs: OutputData[];
+    nextCursor?: string;
+}
+
+/** An nft owned by the account with its IRC27 metadata */
+export interface NftWithMetadata {
+    nftId: string;
+    /** The id of the output that currently holds the nft */
+    outputId: string;
+    metadata?: Irc27Metadata;
+    /** Set if the immutable metadata is not valid IRC27 metadata */
+    metadataError?: string;
+}
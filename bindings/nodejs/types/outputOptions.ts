@@ -33,3 +33,23 @@ export enum ReturnStrategy {
     Return = 'Return',
     Gift = 'Gift',
 }
+
+/** A payment request that can be shared as `iota:` URI */
+export interface PaymentRequest {
+    /** Bech32 encoded address of the recipient */
+    address: string;
+    amount?: string;
+    tokenId?: string;
+    /** Hex encoded amount of the native token */
+    tokenAmount?: string;
+    nftId?: string;
+    tag?: string;
+    metadata?: string;
+    /** Unix time in seconds after which the request shouldn't be paid anymore */
+    expirationUnixTime?: number;
+    /**
+     * Unix time in seconds for an expiration unlock condition on the output,
+     * after it the sender can claim the output back
+     */
+    outputExpirationUnixTime?: number;
+}
@@ -0,0 +1,138 @@
+import type { INativeToken } from '@iota/types';
+import type {
+    AddressNativeTokens,
+    AddressNftId,
+    AddressWithAmount,
+} from './address';
+import type { OutputOptions } from './outputOptions';
+import type { TransactionOptions } from './transactionOptions';
+
+/** A payment that will be sent once it's due */
+export interface ScheduledPayment {
+    id: number;
+    outputs: OutputOptions[];
+    /** Can't have an idempotency key, it's set by the scheduler */
+    options?: TransactionOptions;
+    trigger: ScheduledPaymentTrigger;
+    status: ScheduledPaymentStatus;
+    /** Number of times the payment got edited */
+    revision: number;
+    timestamp: string;
+}
+
+/**
+ * Unix time in seconds after which the payment will be sent or the id of an output with a timelock, the payment spends
+ * the output once the timelock expired
+ */
+export type ScheduledPaymentTrigger =
+    | { type: 'UnixTime'; value: number }
+    | { type: 'TimelockExpired'; value: string };
+
+/** `Executed` has the transaction id, `Failed` the error */
+export type ScheduledPaymentStatus =
+    | { type: 'Pending' }
+    | { type: 'Executed'; value: string }
+    | { type: 'Failed'; value: string }
+    | { type: 'Cancelled' };
+
+/** A payment that is sent repeatedly according to its schedule */
+export interface RecurringPayment {
+    id: number;
+    outputs: OutputOptions[];
+    /** Can't have an idempotency key, it's set for every run */
+    options?: TransactionOptions;
+    schedule: RecurringPaymentSchedule;
+    status: RecurringPaymentStatus;
+    nextDueTime?: number;
+    runs: RecurringPaymentRun[];
+}
+
+/** Defines when a recurring payment is sent, all times are unix times in seconds */
+export interface RecurringPaymentSchedule {
+    interval: RecurringPaymentInterval;
+    startTime: number;
+    endTime?: number;
+    maxCount?: number;
+    catchUpPolicy?: CatchUpPolicy;
+}
+
+/** Every n days or every month on a day between 1 and 28, all dates are in UTC */
+export type RecurringPaymentInterval =
+    | { type: 'Days'; value: number }
+    | { type: 'DayOfMonth'; value: number };
+
+/** How runs are handled which were missed, because the wallet wasn't running */
+export type CatchUpPolicy = 'ExecuteAll' | 'ExecuteLatest';
+
+export type RecurringPaymentStatus = 'Active' | 'Completed' | 'Cancelled';
+
+/** A failed run is tried again on the next execution, until it was tried three times */
+export interface RecurringPaymentRun {
+    dueTime: number;
+    executionTime: number;
+    status: RecurringPaymentRunStatus;
+    attempts: number;
+}
+
+/** `Executed` has the transaction id, `Failed` the error */
+export type RecurringPaymentRunStatus =
+    | { type: 'Executed'; value: string }
+    | { type: 'Failed'; value: string }
+    | { type: 'Skipped' };
+
+/** A single payment for `sendPayments()`, payments to the same address are merged */
+export type Payment =
+    | { type: 'Amount'; data: AddressWithAmount }
+    | { type: 'NativeTokens'; data: AddressNativeTokens }
+    | { type: 'Nft'; data: AddressNftId };
+
+/**
+ * An invoice with a dedicated public address, incoming outputs to this
+ * address are matched to the invoice during syncing
+ */
+export interface Invoice {
+    id: number;
+    /** Bech32 encoded address that was generated for the invoice */
+    address: string;
+    keyIndex: number;
+    amount: string;
+    nativeTokens: INativeToken[];
+    /** Unix time in seconds until which the invoice has to be paid */
+    deadline: number;
+    description?: string;
+    status: InvoiceStatus;
+    payments: InvoicePayment[];
+    /** Creation time in milliseconds */
+    timestamp: string;
+}
+
+export interface InvoiceOptions {
+    /** Can be 0 if native tokens are requested */
+    amount: string;
+    nativeTokens?: INativeToken[];
+    /** Unix time in seconds until which the invoice has to be paid */
+    deadline: number;
+    description?: string;
+}
+
+export enum InvoiceStatus {
+    Open = 'Open',
+    PartiallyPaid = 'PartiallyPaid',
+    Paid = 'Paid',
+    Overpaid = 'Overpaid',
+    PaidLate = 'PaidLate',
+    /** Late payments are still matched to expired invoices */
+    Expired = 'Expired',
+    Cancelled = 'Cancelled',
+}
+
+/** An incoming output that was matched to an invoice */
+export interface InvoicePayment {
+    outputId: string;
+    /** Without a storage deposit that has to be returned */
+    amount: string;
+    nativeTokens: INativeToken[];
+    /** Milestone timestamp at which the output got booked */
+    timestamp: number;
+    late: boolean;
+}
@@ -1,4 +1,4 @@
-import type { ITransactionPayload } from '@iota/types';
+import type { ITaggedDataPayload, ITransactionPayload } from '@iota/types';
 
 export enum InclusionState {
     Pending = 'Pending',
@@ -15,9 +15,119 @@ export interface Transaction {
     transactionId: string;
     networkId: string;
     incoming: boolean;
+    idempotencyKey?: string;
+    /** Outputs of the transaction that expired and were claimed back, with the id of the claiming transaction */
+    returnedOutputs: { [outputId: string]: string };
 }
 
 export interface MintTokenTransaction {
     tokenId: string;
     transaction: Transaction;
-}
\ No newline at end of file
+}
+
+export interface CreateAliasTransaction {
+    aliasId: string;
+    transaction: Transaction;
+}
+
+export interface MintCollectionTransaction {
+    collectionId: string;
+    transaction: Transaction;
+}
+export enum AssetType {
+    BaseCoin = 'BaseCoin',
+    NativeToken = 'NativeToken',
+    Nft = 'Nft',
+    Alias = 'Alias',
+    Foundry = 'Foundry',
+}
+
+export enum SortOrder {
+    Ascending = 'Ascending',
+    Descending = 'Descending',
+}
+
+/** Only transactions that match all set fields are returned */
+export interface TransactionFilter {
+    incoming?: boolean;
+    inclusionStates?: InclusionState[];
+    /** Timestamp in milliseconds, inclusive */
+    fromTime?: number;
+    /** Timestamp in milliseconds, exclusive */
+    toTime?: number;
+    /** Bech32 encoded address of a recipient or sender */
+    address?: string;
+    assetType?: AssetType;
+    tokenId?: string;
+    /** Tag of the transaction or one of its outputs */
+    tag?: string;
+    /** Text the note contains, ignoring the case */
+    note?: string;
+}
+
+export interface TransactionQuery {
+    filter?: TransactionFilter;
+    sort?: SortOrder;
+    /** The nextCursor of the previous page */
+    cursor?: string;
+    limit?: number;
+}
+
+export interface TransactionPage {
+    transactions: Transaction[];
+    nextCursor?: string;
+}
+
+/** A transaction decoded into its effect on the account */
+export interface DecodedTransaction {
+    transactionId: string;
+    incoming: boolean;
+    inclusionState: InclusionState;
+    timestamp: string;
+    /** Net change of the base coin balance, negative if the balance decreased */
+    baseCoinChange: string;
+    nativeTokenChanges: NativeTokenChange[];
+    nftsGained: string[];
+    nftsLost: string[];
+    /** Bech32 encoded addresses of the inputs and sender features */
+    senders: string[];
+    recipients: TransactionRecipient[];
+    taggedData?: ITaggedDataPayload;
+}
+
+export interface NativeTokenChange {
+    tokenId: string;
+    amount: string;
+    isDecrease: boolean;
+}
+
+export interface TransactionRecipient {
+    outputIndex: number;
+    /** Bech32 encoded address, not set for alias and foundry outputs */
+    address?: string;
+    amount: string;
+    /**
+     * If the address unlock condition, or the state controller of an alias
+     * output, is an address of the account
+     */
+    ownAddress: boolean;
+}
+
+export enum ExportFormat {
+    Csv = 'Csv',
+    JsonLines = 'JsonLines',
+}
+
+export interface ExportOptions {
+    format: ExportFormat;
+    /** Timestamp in milliseconds, inclusive */
+    fromTime?: number;
+    /** Timestamp in milliseconds, exclusive */
+    toTime?: number;
+}
+
+/** Private note and tags of a transaction or output, only stored locally */
+export interface Annotation {
+    note?: string;
+    tags?: string[];
+}
@@ -4,6 +4,7 @@ export interface TransactionOptions {
     remainderValueStrategy?: RemainderValueStrategy;
     taggedDataPayload?: ITaggedDataPayload;
     customInputs?: string[];
+    idempotencyKey?: string;
 }
 
 export type RemainderValueStrategy =
@@ -33,6 +34,19 @@ export interface NativeTokenOptions {
     /** Hex encoded number */
     maximumSupply: string;
     foundryMetadata?: number[];
+    /** IRC30 metadata, can't be combined with foundryMetadata */
+    tokenMetadata?: Irc30Metadata;
+}
+
+export interface Irc30Metadata {
+    standard: 'IRC30';
+    name: string;
+    symbol: string;
+    decimals: number;
+    description?: string;
+    url?: string;
+    logoUrl?: string;
+    logo?: string;
 }
 
 export interface NftOptions {
@@ -42,4 +56,33 @@ export interface NftOptions {
     address?: string;
     immutableMetadata?: number[];
     metadata?: number[];
+    /** IRC27 metadata, can't be combined with immutableMetadata */
+    irc27Metadata?: Irc27Metadata;
+}
+
+export interface Irc27Metadata {
+    standard: 'IRC27';
+    version: 'v1.0';
+    /** MIME type of the file */
+    type: string;
+    uri: string;
+    name: string;
+    collectionName?: string;
+    /** Bech32 addresses with their share between 0 and 1 */
+    royalties?: { [address: string]: number };
+    issuerName?: string;
+    description?: string;
+    attributes?: { trait_type: string; value: any }[];
+}
+
+export interface AliasOptions {
+    /** Bech32 encoded address, default is the first address of the account */
+    stateControllerAddress?: string;
+    /** Bech32 encoded address, default is the first address of the account */
+    governorAddress?: string;
+    immutableMetadata?: number[];
+    /** Can be changed by the governor */
+    metadata?: number[];
+    /** Can be changed by the state controller */
+    stateMetadata?: number[];
 }
@@ -31,6 +31,7 @@ async fn main() -> Result<()> {
         circulating_supply: U256::from(100),
         maximum_supply: U256::from(100),
         foundry_metadata: None,
+        token_metadata: None,
     };
 
     let transaction = account.mint_native_token(native_token_options, None).await?;
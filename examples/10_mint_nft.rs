@@ -30,6 +30,7 @@ async fn main() -> Result<()> {
         address: Some("rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string()),
         immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
         metadata: Some(b"some nft metadata".to_vec()),
+        irc_27_metadata: None,
     }];
 
     let transaction = account.mint_nfts(nft_options, None).await?;
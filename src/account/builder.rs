@@ -23,7 +23,7 @@ use crate::{
     account::{
         handle::AccountHandle,
         types::{address::AddressWrapper, AccountAddress},
-        Account,
+        Account, ClaimPolicy,
     },
     ClientOptions, Error,
 };
@@ -182,7 +182,20 @@ impl AccountBuilder {
             unspent_outputs: HashMap::new(),
             transactions: HashMap::new(),
             pending_transactions: HashSet::new(),
+            idempotency_keys: HashMap::new(),
             incoming_transactions: HashMap::new(),
+            outbox: HashMap::new(),
+            scheduled_payments: HashMap::new(),
+            recurring_payments: HashMap::new(),
+            native_token_metadata: HashMap::new(),
+            claim_policy: ClaimPolicy::default(),
+            rejection_policy: None,
+            ignored_outputs: HashSet::new(),
+            balance_history: Vec::new(),
+            balance_history_resolution: 0,
+            transaction_annotations: HashMap::new(),
+            output_annotations: HashMap::new(),
+            invoices: HashMap::new(),
         };
 
         let account_handle = AccountHandle::new(
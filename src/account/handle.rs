@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{ops::Deref, sync::Arc};
+use std::{collections::HashSet, ops::Deref, sync::Arc};
 
 use iota_client::{
     bee_block::{
@@ -39,6 +39,11 @@ pub struct AccountHandle {
     // if the last synced time was < `MIN_SYNC_INTERVAL` second ago, we don't sync, but only calculate the balance
     // again, because sending transactions can change that
     pub(crate) last_synced: Arc<Mutex<u128>>,
+    // idempotency keys of transactions which are currently created, they're reserved while the account is write locked,
+    // so concurrent calls with the same key can't select inputs and send a second transaction
+    pub(crate) idempotency_keys_in_flight: Arc<Mutex<HashSet<String>>>,
+    // decimals of the base coin from the node info, cached so formatting amounts doesn't request it every time
+    pub(crate) base_coin_decimals: Arc<Mutex<Option<u32>>>,
     #[cfg(feature = "events")]
     pub(crate) event_emitter: Arc<Mutex<EventEmitter>>,
     #[cfg(feature = "storage")]
@@ -59,6 +64,8 @@ impl AccountHandle {
             client,
             secret_manager,
             last_synced: Default::default(),
+            idempotency_keys_in_flight: Default::default(),
+            base_coin_decimals: Default::default(),
             #[cfg(feature = "events")]
             event_emitter,
             #[cfg(feature = "storage")]
@@ -103,6 +110,17 @@ impl AccountHandle {
         account.transactions().get(transaction_id).cloned()
     }
 
+    /// Get the [`Transaction`] that was created with the provided idempotency key, see
+    /// [`TransactionOptions`](crate::account::TransactionOptions)
+    pub async fn get_transaction_by_idempotency_key(&self, idempotency_key: &str) -> Option<Transaction> {
+        let account = self.read().await;
+        account
+            .idempotency_keys()
+            .get(idempotency_key)
+            .and_then(|transaction_id| account.transactions().get(transaction_id))
+            .cloned()
+    }
+
     /// Get the transaction with inputs of an incoming transaction stored in the account
     /// List might not be complete, if the node pruned the data already
     pub async fn get_incoming_transaction_data(
@@ -19,7 +19,7 @@ use std::collections::{HashMap, HashSet};
 use getset::{Getters, Setters};
 use iota_client::{
     bee_block::{
-        output::OutputId,
+        output::{OutputId, TokenId},
         payload::{transaction::TransactionId, TransactionPayload},
     },
     bee_rest_api::types::responses::OutputResponse,
@@ -28,18 +28,41 @@ use serde::{Deserialize, Serialize};
 
 use self::types::{
     address::{AccountAddress, AddressWithUnspentOutputs},
-    AccountBalance, OutputData,
+    AccountBalance, Irc30Metadata, OutboxEntry, OutputData,
 };
 pub use self::{
     handle::AccountHandle,
     operations::{
         address_generation::AddressGenerationOptions,
-        output_claiming::OutputsToClaim,
+        amount_formatting::{format_amount, parse_amount, AmountFormatOptions, RoundingMode},
+        annotations::Annotation,
+        balance_history::{BalanceSnapshot, NativeTokenTotal},
+        invoices::{
+            Invoice, InvoiceDto, InvoiceOptions, InvoiceOptionsDto, InvoicePayment, InvoicePaymentDto, InvoiceStatus,
+        },
+        nfts::{NftWithMetadata, NftWithMetadataDto},
+        output_claiming::{ClaimPolicy, OutputsToClaim},
+        output_query::{OutputFilter, OutputPage, OutputPageDto, OutputQuery, OutputSortBy, UnlockConditionType},
+        output_rejection::{RejectAction, RejectionPolicy},
+        payment_request::{PaymentRequest, PaymentRequestDto},
+        recurring_payments::{
+            CatchUpPolicy, RecurringPayment, RecurringPaymentDto, RecurringPaymentInterval, RecurringPaymentRun,
+            RecurringPaymentRunStatus, RecurringPaymentSchedule, RecurringPaymentStatus,
+        },
+        scheduled_payments::{ScheduledPayment, ScheduledPaymentDto, ScheduledPaymentStatus, ScheduledPaymentTrigger},
         syncing::SyncOptions,
         transaction::{
             prepare_output::{Assets, Features, OutputOptions, StorageDeposit, Unlocks},
             RemainderValueStrategy, TransactionOptions,
         },
+        transaction_decoding::{
+            DecodedTransaction, DecodedTransactionDto, NativeTokenChange, NativeTokenChangeDto, TransactionRecipient,
+            TransactionRecipientDto,
+        },
+        transaction_export::{ExportDirection, ExportFormat, ExportOptions, ExportRecord},
+        transaction_query::{
+            AssetType, SortOrder, TransactionFilter, TransactionPage, TransactionPageDto, TransactionQuery,
+        },
     },
     types::OutputDataDto,
 };
@@ -85,8 +108,49 @@ pub struct Account {
     // Maybe pending transactions even additionally separated?
     #[serde(rename = "pendingTransactions")]
     pending_transactions: HashSet<TransactionId>,
+    /// Idempotency keys of sent transactions, so they can be looked up without iterating over all transactions
+    #[serde(rename = "idempotencyKeys", default)]
+    idempotency_keys: HashMap<String, TransactionId>,
     /// Transaction payloads for received outputs with inputs when not pruned before syncing, can be used to determine
     /// the sender address/es
     #[serde(rename = "incomingTransactions")]
     incoming_transactions: HashMap<TransactionId, (TransactionPayload, Vec<OutputResponse>)>,
+    /// Transactions which are not finished yet, with the hex encoded essence hash as key. Every stage is persisted, so
+    /// they can be resumed or reconciled after a crash
+    #[serde(default)]
+    outbox: HashMap<String, OutboxEntry>,
+    /// Payments that will be sent once they're due
+    #[serde(rename = "scheduledPayments", default)]
+    scheduled_payments: HashMap<u32, ScheduledPayment>,
+    /// Payments that are sent repeatedly according to their schedule
+    #[serde(rename = "recurringPayments", default)]
+    recurring_payments: HashMap<u32, RecurringPayment>,
+    /// IRC30 metadata of the native tokens in the account, decoded from their foundries. None if the foundry has no
+    /// valid metadata
+    #[serde(rename = "nativeTokenMetadata", default)]
+    native_token_metadata: HashMap<TokenId, Option<Irc30Metadata>>,
+    /// Defines which outputs with additional unlock conditions are claimed automatically by the background syncing
+    #[serde(rename = "claimPolicy", default)]
+    claim_policy: ClaimPolicy,
+    /// Defines which incoming outputs are rejected automatically by the background syncing
+    #[serde(rename = "rejectionPolicy", default)]
+    rejection_policy: Option<RejectionPolicy>,
+    /// Unspent outputs that were rejected and are excluded from the balance, input selection and claiming
+    #[serde(rename = "ignoredOutputs", default)]
+    ignored_outputs: HashSet<OutputId>,
+    /// Balance snapshots taken after syncs that changed the balance, sorted by their time
+    #[serde(rename = "balanceHistory", default)]
+    balance_history: Vec<BalanceSnapshot>,
+    /// Length of the time slots in milliseconds of which only the latest balance snapshot is kept
+    #[serde(rename = "balanceHistoryResolution", default)]
+    balance_history_resolution: u64,
+    /// Private notes and tags of sent and incoming transactions
+    #[serde(rename = "transactionAnnotations", default)]
+    transaction_annotations: HashMap<TransactionId, Annotation>,
+    /// Private notes and tags of outputs
+    #[serde(rename = "outputAnnotations", default)]
+    output_annotations: HashMap<OutputId, Annotation>,
+    /// Invoices with their dedicated receiving addresses and the payments matched to them
+    #[serde(default)]
+    invoices: HashMap<u32, Invoice>,
 }
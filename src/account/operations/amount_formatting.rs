@@ -0,0 +1,264 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::output::TokenId;
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{account::handle::AccountHandle, Error};
+
+// U256::MAX has 78 digits, so 10^77 is the largest power of ten it can hold
+const MAX_DECIMALS: u32 = 77;
+
+/// Options for [`format_amount()`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AmountFormatOptions {
+    /// Maximum number of fraction digits, all decimals are shown if not set
+    #[serde(rename = "maxFractionDigits", default)]
+    pub max_fraction_digits: Option<u32>,
+    /// How the amount is rounded if it has more fraction digits than shown
+    #[serde(default)]
+    pub rounding: RoundingMode,
+    /// Keep trailing zeros of the fraction, so all amounts have the same number of fraction digits
+    #[serde(rename = "keepTrailingZeros", default)]
+    pub keep_trailing_zeros: bool,
+}
+
+/// Rounding modes for [`format_amount()`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RoundingMode {
+    /// Round towards zero, a formatted balance is never higher than the actual balance
+    Down,
+    /// Round away from zero
+    Up,
+    /// Round to the nearest value, ties away from zero
+    HalfUp,
+    /// Round to the nearest value, ties to the even neighbour
+    HalfEven,
+}
+
+impl Default for RoundingMode {
+    fn default() -> Self {
+        Self::Down
+    }
+}
+
+/// Format an amount in the smallest unit with the number of decimals of the token. The output doesn't depend on the
+/// locale, it has no digit grouping and uses `.` as decimal separator, for example `1234.5`
+pub fn format_amount(amount: U256, decimals: u32, options: &AmountFormatOptions) -> crate::Result<String> {
+    check_decimals(decimals)?;
+    let fraction_digits = options
+        .max_fraction_digits
+        .map_or(decimals, |max_fraction_digits| max_fraction_digits.min(decimals));
+
+    // Remove the fraction digits that aren't shown and round the remaining value
+    let divisor = U256::exp10((decimals - fraction_digits) as usize);
+    let (mut value, remainder) = amount.div_mod(divisor);
+    let round_up = !remainder.is_zero()
+        && match options.rounding {
+            RoundingMode::Down => false,
+            RoundingMode::Up => true,
+            // Compare the remainder with the rest to the divisor, so the remainder doesn't need to be doubled
+            RoundingMode::HalfUp => remainder >= divisor - remainder,
+            RoundingMode::HalfEven => {
+                let rest = divisor - remainder;
+                remainder > rest || (remainder == rest && value.bit(0))
+            }
+        };
+    if round_up {
+        // Can't overflow, since the divisor is at least 10 if there is a remainder
+        value += U256::one();
+    }
+
+    let digits = value.to_string();
+    let digits = format!("{digits:0>width$}", width = fraction_digits as usize + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - fraction_digits as usize);
+    let fraction = if options.keep_trailing_zeros {
+        fraction
+    } else {
+        fraction.trim_end_matches('0')
+    };
+
+    if fraction.is_empty() {
+        Ok(integer.to_string())
+    } else {
+        Ok(format!("{integer}.{fraction}"))
+    }
+}
+
+/// Parse a human-readable amount into the smallest unit with the number of decimals of the token. Only digits and an
+/// optional `.` as decimal separator are accepted, amounts with more significant fraction digits than decimals are
+/// rejected instead of rounded
+pub fn parse_amount(amount: &str, decimals: u32) -> crate::Result<U256> {
+    check_decimals(decimals)?;
+    let invalid_amount = || Error::from(iota_client::Error::InvalidAmount(amount.to_string()));
+    let trimmed = amount.trim();
+    let (integer, fraction) = match trimmed.split_once('.') {
+        // Trailing zeros don't change the value, so "1.0" is also valid for a token without decimals
+        Some((integer, fraction)) if !fraction.is_empty() => (integer, fraction.trim_end_matches('0')),
+        Some(_) => return Err(invalid_amount()),
+        None => (trimmed, ""),
+    };
+    if integer.is_empty()
+        || !integer.bytes().all(|byte| byte.is_ascii_digit())
+        || !fraction.bytes().all(|byte| byte.is_ascii_digit())
+        || fraction.len() > decimals as usize
+    {
+        return Err(invalid_amount());
+    }
+
+    let integer = U256::from_dec_str(integer).map_err(|_| invalid_amount())?;
+    let fraction = if fraction.is_empty() {
+        U256::zero()
+    } else {
+        let fraction = format!("{fraction:0<width$}", width = decimals as usize);
+        U256::from_dec_str(&fraction).map_err(|_| invalid_amount())?
+    };
+    integer
+        .checked_mul(U256::exp10(decimals as usize))
+        .and_then(|integer| integer.checked_add(fraction))
+        .ok_or_else(invalid_amount)
+}
+
+fn check_decimals(decimals: u32) -> crate::Result<()> {
+    if decimals > MAX_DECIMALS {
+        return Err(Error::InvalidDecimals(decimals));
+    }
+    Ok(())
+}
+
+impl AccountHandle {
+    /// Format an amount of the base coin with the decimals from the node info
+    /// ```ignore
+    /// // "1.5" for a base coin with 6 decimals
+    /// let formatted = account_handle.format_base_coin_amount(1_500_000, None).await?;
+    /// ```
+    pub async fn format_base_coin_amount(
+        &self,
+        amount: u64,
+        options: Option<AmountFormatOptions>,
+    ) -> crate::Result<String> {
+        let decimals = self.base_coin_decimals().await?;
+        format_amount(U256::from(amount), decimals, &options.unwrap_or_default())
+    }
+
+    /// Parse a human-readable amount of the base coin with the decimals from the node info
+    pub async fn parse_base_coin_amount(&self, amount: &str) -> crate::Result<u64> {
+        let decimals = self.base_coin_decimals().await?;
+        let parsed_amount = parse_amount(amount, decimals)?;
+        if parsed_amount > U256::from(u64::MAX) {
+            return Err(iota_client::Error::InvalidAmount(amount.to_string()).into());
+        }
+        Ok(parsed_amount.as_u64())
+    }
+
+    /// Format an amount of a native token with the decimals from its IRC30 metadata, which is loaded during syncing
+    pub async fn format_native_token_amount(
+        &self,
+        token_id: &TokenId,
+        amount: U256,
+        options: Option<AmountFormatOptions>,
+    ) -> crate::Result<String> {
+        let decimals = self.native_token_decimals(token_id).await?;
+        format_amount(amount, decimals, &options.unwrap_or_default())
+    }
+
+    /// Parse a human-readable amount of a native token with the decimals from its IRC30 metadata
+    pub async fn parse_native_token_amount(&self, token_id: &TokenId, amount: &str) -> crate::Result<U256> {
+        let decimals = self.native_token_decimals(token_id).await?;
+        parse_amount(amount, decimals)
+    }
+
+    async fn base_coin_decimals(&self) -> crate::Result<u32> {
+        let mut base_coin_decimals = self.base_coin_decimals.lock().await;
+        if let Some(decimals) = *base_coin_decimals {
+            return Ok(decimals);
+        }
+        let node_info = self.client.get_info().await?;
+        let decimals = u32::from(node_info.node_info.base_token.decimals);
+        *base_coin_decimals = Some(decimals);
+        Ok(decimals)
+    }
+
+    async fn native_token_decimals(&self, token_id: &TokenId) -> crate::Result<u32> {
+        let account = self.read().await;
+        account
+            .native_token_metadata
+            .get(token_id)
+            .and_then(|metadata| metadata.as_ref())
+            .map(|metadata| metadata.decimals)
+            .ok_or(Error::MissingTokenDecimals(*token_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format() {
+        let options = AmountFormatOptions::default();
+        assert_eq!(format_amount(U256::from(1_500_000u64), 6, &options).unwrap(), "1.5");
+        assert_eq!(format_amount(U256::from(1u64), 6, &options).unwrap(), "0.000001");
+        assert_eq!(format_amount(U256::zero(), 6, &options).unwrap(), "0");
+        assert_eq!(format_amount(U256::from(42u64), 0, &options).unwrap(), "42");
+        assert_eq!(format_amount(U256::MAX, 77, &options).unwrap().len(), 79);
+        assert!(format_amount(U256::one(), MAX_DECIMALS + 1, &options).is_err());
+
+        let options = AmountFormatOptions {
+            keep_trailing_zeros: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            format_amount(U256::from(1_500_000u64), 6, &options).unwrap(),
+            "1.500000"
+        );
+    }
+
+    #[test]
+    fn format_rounding() {
+        let format = |amount: u64, rounding| {
+            let options = AmountFormatOptions {
+                max_fraction_digits: Some(1),
+                rounding,
+                keep_trailing_zeros: false,
+            };
+            format_amount(U256::from(amount), 2, &options).unwrap()
+        };
+
+        assert_eq!(format(125, RoundingMode::Down), "1.2");
+        assert_eq!(format(125, RoundingMode::Up), "1.3");
+        assert_eq!(format(125, RoundingMode::HalfUp), "1.3");
+        assert_eq!(format(125, RoundingMode::HalfEven), "1.2");
+        assert_eq!(format(135, RoundingMode::HalfEven), "1.4");
+        assert_eq!(format(124, RoundingMode::HalfUp), "1.2");
+        assert_eq!(format(126, RoundingMode::HalfEven), "1.3");
+        assert_eq!(format(120, RoundingMode::Up), "1.2");
+        assert_eq!(format(199, RoundingMode::Up), "2");
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_amount("1.5", 6).unwrap(), U256::from(1_500_000u64));
+        assert_eq!(parse_amount(" 0.000001 ", 6).unwrap(), U256::one());
+        assert_eq!(parse_amount("42", 0).unwrap(), U256::from(42u64));
+        assert_eq!(parse_amount("1.0", 0).unwrap(), U256::one());
+        assert_eq!(parse_amount("1.1000000", 6).unwrap(), U256::from(1_100_000u64));
+
+        for invalid in ["", ".5", "1.", "1,5", "-1", "1.5e3", "0.0000001", "1.1.1"] {
+            assert!(parse_amount(invalid, 6).is_err(), "{invalid}");
+        }
+        assert!(parse_amount("1.5", 0).is_err());
+        assert!(parse_amount(&U256::MAX.to_string(), 1).is_err());
+        assert!(parse_amount("1", MAX_DECIMALS + 1).is_err());
+    }
+
+    #[test]
+    fn format_parse_roundtrip() {
+        let options = AmountFormatOptions::default();
+        for amount in [0u64, 1, 10, 1_000_000, 1_234_567, u64::MAX] {
+            let formatted = format_amount(U256::from(amount), 6, &options).unwrap();
+            assert_eq!(parse_amount(&formatted, 6).unwrap(), U256::from(amount));
+        }
+    }
+}
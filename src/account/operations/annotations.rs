@@ -0,0 +1,118 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use iota_client::bee_block::{output::OutputId, payload::transaction::TransactionId};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    account::{handle::AccountHandle, Account},
+    Error,
+};
+
+/// A private note and tags of a transaction or output, which are only stored locally
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    /// Free text note, for example "rent March"
+    pub note: Option<String>,
+    /// Free-form tags, for example "refund"
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl Annotation {
+    fn is_empty(&self) -> bool {
+        self.note.is_none() && self.tags.is_empty()
+    }
+}
+
+impl AccountHandle {
+    /// Set the [`Annotation`] of a sent or incoming transaction, an empty annotation removes it
+    pub async fn annotate_transaction(
+        &self,
+        transaction_id: TransactionId,
+        annotation: Annotation,
+    ) -> crate::Result<()> {
+        log::debug!("[ANNOTATIONS] annotate_transaction {}", transaction_id);
+        let mut account = self.write().await;
+        if !account.transactions.contains_key(&transaction_id)
+            && !account.incoming_transactions.contains_key(&transaction_id)
+        {
+            return Err(Error::TransactionNotFound(transaction_id));
+        }
+        if annotation.is_empty() {
+            account.transaction_annotations.remove(&transaction_id);
+        } else {
+            account.transaction_annotations.insert(transaction_id, annotation);
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+
+    /// Set the [`Annotation`] of an output of the account, an empty annotation removes it
+    pub async fn annotate_output(&self, output_id: OutputId, annotation: Annotation) -> crate::Result<()> {
+        log::debug!("[ANNOTATIONS] annotate_output {}", output_id);
+        let mut account = self.write().await;
+        if !account.outputs.contains_key(&output_id) {
+            return Err(Error::OutputNotFound(output_id));
+        }
+        if annotation.is_empty() {
+            account.output_annotations.remove(&output_id);
+        } else {
+            account.output_annotations.insert(output_id, annotation);
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+
+    /// Get the [`Annotation`] of a transaction
+    pub async fn get_transaction_annotation(&self, transaction_id: &TransactionId) -> Option<Annotation> {
+        self.read().await.transaction_annotations.get(transaction_id).cloned()
+    }
+
+    /// Get the [`Annotation`] of an output
+    pub async fn get_output_annotation(&self, output_id: &OutputId) -> Option<Annotation> {
+        self.read().await.output_annotations.get(output_id).cloned()
+    }
+}
+
+// The annotations of transactions and of the outputs they created. The output annotations are indexed by their
+// transaction once, so queries and exports don't scan all of them for every transaction
+pub(crate) struct TransactionAnnotations<'a> {
+    transaction_annotations: &'a HashMap<TransactionId, Annotation>,
+    output_annotations: HashMap<&'a TransactionId, Vec<(&'a OutputId, &'a Annotation)>>,
+}
+
+impl<'a> TransactionAnnotations<'a> {
+    pub(crate) fn new(account: &'a Account) -> Self {
+        let mut output_annotations: HashMap<&TransactionId, Vec<(&OutputId, &Annotation)>> = HashMap::new();
+        for (output_id, annotation) in &account.output_annotations {
+            output_annotations
+                .entry(output_id.transaction_id())
+                .or_default()
+                .push((output_id, annotation));
+        }
+        // Sorted by the output index, so notes are always joined in the same order
+        for annotations in output_annotations.values_mut() {
+            annotations.sort_by_key(|(output_id, _annotation)| output_id.index());
+        }
+        Self {
+            transaction_annotations: &account.transaction_annotations,
+            output_annotations,
+        }
+    }
+
+    // The annotations of a transaction and of the outputs it created
+    pub(crate) fn get(&self, transaction_id: &TransactionId) -> impl Iterator<Item = &'a Annotation> + '_ {
+        self.transaction_annotations.get(transaction_id).into_iter().chain(
+            self.output_annotations
+                .get(transaction_id)
+                .into_iter()
+                .flatten()
+                .map(|(_output_id, annotation)| *annotation),
+        )
+    }
+}
@@ -3,237 +3,515 @@
 
 use std::collections::HashMap;
 
-use iota_client::bee_block::output::{unlock_condition::UnlockCondition, ByteCost, NativeTokensBuilder, Output};
+use iota_client::bee_block::{
+    address::Address,
+    output::{
+        unlock_condition::UnlockCondition, AliasOutput, ByteCost, ByteCostConfig, NativeTokensBuilder, Output, OutputId,
+    },
+};
 use primitive_types::U256;
 
 use crate::account::{
     handle::AccountHandle,
     operations::helpers::time::can_output_be_unlocked_forever_from_now_on,
-    types::{AccountBalance, BaseCoinBalance, NativeTokensBalance},
-    OutputsToClaim,
+    types::{
+        AccountAddress, AccountBalance, AddressBalance, BaseCoinBalance, ChainAddressBalance, LockedBalance,
+        LockedOutput, NativeTokensBalance, OutputData, RequiredStorageDeposit,
+    },
+    Account, OutputsToClaim,
 };
 
 impl AccountHandle {
     /// Get the AccountBalance
     pub async fn balance(&self) -> crate::Result<AccountBalance> {
         log::debug!("[BALANCE] get balance");
-        let unlockable_outputs_with_multiple_unlock_conditions = self
-            .get_unlockable_outputs_with_additional_unlock_conditions(OutputsToClaim::All)
-            .await?;
+        let context = self.balance_context().await?;
+        let account = self.read().await;
+        let outputs = account.unspent_outputs.values().collect::<Vec<&OutputData>>();
+        let mut balance = balance_of_outputs(&account, &context, &outputs)?;
+        balance.chain_addresses = chain_address_balances(&account, context.network_id, &context.bech32_hrp)?;
+        Ok(balance)
+    }
 
-        let account_addresses = self.list_addresses().await?;
+    /// Get the balance of each public and internal address of the account, outputs that are locked to alias or nft
+    /// addresses are part of [`AccountBalance::chain_addresses`] instead
+    pub async fn address_balances(&self) -> crate::Result<Vec<AddressBalance>> {
+        log::debug!("[BALANCE] get address balances");
+        let context = self.balance_context().await?;
         let account = self.read().await;
 
-        let network_id = self.client.get_network_id().await?;
-        let byte_cost_config = self.client.get_byte_cost_config().await?;
+        // Group the unspent outputs by their address in a single pass
+        let mut outputs_by_address: HashMap<Address, Vec<&OutputData>> = HashMap::new();
+        for output_data in account.unspent_outputs.values() {
+            outputs_by_address
+                .entry(output_data.address)
+                .or_default()
+                .push(output_data);
+        }
 
-        let local_time = self.client.get_time_checked().await?;
+        let mut address_balances = Vec::new();
+        for account_address in &context.account_addresses {
+            // Addresses without unspent outputs have an empty balance, no need to calculate it
+            let balance = match outputs_by_address.get(&account_address.address.inner) {
+                Some(outputs) => balance_of_outputs(&account, &context, outputs)?,
+                None => AccountBalance::default(),
+            };
+            address_balances.push(AddressBalance {
+                address: account_address.address.to_bech32(),
+                key_index: account_address.key_index,
+                internal: account_address.internal,
+                base_coin: balance.base_coin,
+                required_storage_deposit: balance.required_storage_deposit,
+                native_tokens: balance.native_tokens,
+                nfts: balance.nfts,
+                aliases: balance.aliases,
+                foundries: balance.foundries,
+            });
+        }
 
-        let mut total_amount = 0;
-        let mut required_storage_deposit = 0;
-        let mut total_native_tokens = NativeTokensBuilder::new();
-        let mut potentially_locked_outputs = HashMap::new();
-        let mut aliases = Vec::new();
-        let mut foundries = Vec::new();
-        let mut nfts = Vec::new();
+        Ok(address_balances)
+    }
 
-        for output_data in account.unspent_outputs.values() {
-            // Check if output is from the network we're currently connected to
-            if output_data.network_id != network_id {
-                continue;
-            }
+    // Get the values from the node and the account that are needed to calculate balances
+    async fn balance_context(&self) -> crate::Result<BalanceContext> {
+        Ok(BalanceContext {
+            unlockable_outputs_with_multiple_unlock_conditions: self
+                .get_unlockable_outputs_with_additional_unlock_conditions(OutputsToClaim::All)
+                .await?,
+            account_addresses: self.list_addresses().await?,
+            network_id: self.client.get_network_id().await?,
+            byte_cost_config: self.client.get_byte_cost_config().await?,
+            local_time: self.client.get_time_checked().await?,
+            bech32_hrp: self.client.get_bech32_hrp().await?,
+        })
+    }
+}
 
-            // Add alias and foundry outputs here because they can't have a [`StorageDepositReturnUnlockCondition`]
-            // or time related unlock conditions
-            match &output_data.output {
-                Output::Alias(output) => {
-                    // Add amount
-                    total_amount += output_data.output.amount();
-                    // Add storage deposit
-                    required_storage_deposit += &output_data.output.byte_cost(&byte_cost_config);
-                    // Add native tokens
-                    if let Some(native_tokens) = output_data.output.native_tokens() {
-                        total_native_tokens.add_native_tokens(native_tokens.clone())?;
-                    }
-                    let alias_id = output.alias_id().or_from_output_id(output_data.output_id);
-                    aliases.push(alias_id);
+// Values for the balance calculation, requested once for all addresses
+struct BalanceContext {
+    unlockable_outputs_with_multiple_unlock_conditions: Vec<OutputId>,
+    account_addresses: Vec<AccountAddress>,
+    network_id: u64,
+    byte_cost_config: ByteCostConfig,
+    local_time: u32,
+    bech32_hrp: String,
+}
+
+// Calculate the balance of the outputs, without the balances of the chain addresses
+fn balance_of_outputs(
+    account: &Account,
+    context: &BalanceContext,
+    outputs: &[&OutputData],
+) -> crate::Result<AccountBalance> {
+    let mut total_amount = 0;
+    let mut required_storage_deposit = 0;
+    let mut total_native_tokens = NativeTokensBuilder::new();
+    let mut potentially_locked_outputs = HashMap::new();
+    let mut aliases = Vec::new();
+    let mut foundries = Vec::new();
+    let mut nfts = Vec::new();
+    let mut ignored_outputs = Vec::new();
+    let mut locked_balance = LockedBalance::default();
+
+    for output_data in outputs.iter().copied() {
+        // Check if output is from the network we're currently connected to
+        if output_data.network_id != context.network_id {
+            continue;
+        }
+
+        // Outputs of alias and nft addresses are only part of the balance of their chain address
+        if is_chain_address_output(output_data) {
+            continue;
+        }
+
+        // Rejected outputs are not part of the balance
+        if account.ignored_outputs.contains(&output_data.output_id) {
+            ignored_outputs.push(output_data.output_id);
+            locked_balance
+                .frozen
+                .push(new_locked_output(output_data, output_data.output.amount(), None));
+            continue;
+        }
+
+        // Add alias and foundry outputs here because they can't have a [`StorageDepositReturnUnlockCondition`]
+        // or time related unlock conditions
+        match &output_data.output {
+            Output::Alias(output) => {
+                let alias_id = output.alias_id().or_from_output_id(output_data.output_id);
+                aliases.push(alias_id);
+                // If the account is only the governor, the funds of the alias are not part of the balance
+                if !is_state_controller(output, &context.account_addresses) {
+                    continue;
+                }
+                // Add amount
+                total_amount += output_data.output.amount();
+                // Add storage deposit
+                let storage_deposit = output_data.output.byte_cost(&context.byte_cost_config);
+                required_storage_deposit += storage_deposit;
+                locked_balance.storage_deposit.alias += storage_deposit;
+                // Add native tokens
+                if let Some(native_tokens) = output_data.output.native_tokens() {
+                    total_native_tokens.add_native_tokens(native_tokens.clone())?;
+                }
+            }
+            Output::Foundry(output) => {
+                // Add amount
+                total_amount += output_data.output.amount();
+                // Add storage deposit
+                let storage_deposit = output_data.output.byte_cost(&context.byte_cost_config);
+                required_storage_deposit += storage_deposit;
+                locked_balance.storage_deposit.foundry += storage_deposit;
+                // Add native tokens
+                if let Some(native_tokens) = output_data.output.native_tokens() {
+                    total_native_tokens.add_native_tokens(native_tokens.clone())?;
                 }
-                Output::Foundry(output) => {
+                foundries.push(output.id())
+            }
+            _ => {
+                // If there is only an [AddressUnlockCondition], then we can spend the output at any time without
+                // restrictions
+                if let [UnlockCondition::Address(_)] = output_data
+                    .output
+                    .unlock_conditions()
+                    .expect("Output needs to have unlock conditions")
+                    .as_ref()
+                {
+                    // add nft_id for nft outputs
+                    if let Output::Nft(output) = &output_data.output {
+                        let nft_id = output.nft_id().or_from_output_id(output_data.output_id);
+                        nfts.push(nft_id);
+                    }
+
                     // Add amount
                     total_amount += output_data.output.amount();
                     // Add storage deposit
-                    required_storage_deposit += &output_data.output.byte_cost(&byte_cost_config);
+                    let storage_deposit = output_data.output.byte_cost(&context.byte_cost_config);
+                    required_storage_deposit += storage_deposit;
+                    add_storage_deposit(
+                        &mut locked_balance.storage_deposit,
+                        &output_data.output,
+                        storage_deposit,
+                    );
                     // Add native tokens
                     if let Some(native_tokens) = output_data.output.native_tokens() {
                         total_native_tokens.add_native_tokens(native_tokens.clone())?;
                     }
-                    foundries.push(output.id())
-                }
-                _ => {
-                    // If there is only an [AddressUnlockCondition], then we can spend the output at any time without
-                    // restrictions
-                    if let [UnlockCondition::Address(_)] = output_data
-                        .output
-                        .unlock_conditions()
-                        .expect("Output needs to have unlock conditions")
-                        .as_ref()
-                    {
-                        // add nft_id for nft outputs
-                        if let Output::Nft(output) = &output_data.output {
-                            let nft_id = output.nft_id().or_from_output_id(output_data.output_id);
-                            nfts.push(nft_id);
-                        }
+                } else {
+                    // if we have multiple unlock conditions for basic or nft outputs, then we might can't spend the
+                    // balance at the moment or in the future
 
-                        // Add amount
-                        total_amount += output_data.output.amount();
-                        // Add storage deposit
-                        required_storage_deposit += &output_data.output.byte_cost(&byte_cost_config);
-                        // Add native tokens
-                        if let Some(native_tokens) = output_data.output.native_tokens() {
-                            total_native_tokens.add_native_tokens(native_tokens.clone())?;
-                        }
-                    } else {
-                        // if we have multiple unlock conditions for basic or nft outputs, then we might can't spend the
-                        // balance at the moment or in the future
-
-                        let output_can_be_unlocked_now =
-                            unlockable_outputs_with_multiple_unlock_conditions.contains(&output_data.output_id);
-
-                        // For outputs that are expired or have a timelock unlock condition, but no expiration unlock
-                        // condition and we then can unlock them, then they can never be not available for us anymore
-                        // and should be added to the balance
-                        if output_can_be_unlocked_now {
-                            // check if output can be unlocked always from now on, in that case it should be added to
-                            // the total amount
-                            let output_can_be_unlocked_now_and_in_future = can_output_be_unlocked_forever_from_now_on(
-                                // We use the addresses with unspent outputs, because other addresses of the
-                                // account without unspent outputs can't be related to this output
-                                &account.addresses_with_unspent_outputs,
-                                output_data,
-                                local_time,
-                            );
+                    let output_can_be_unlocked_now = context
+                        .unlockable_outputs_with_multiple_unlock_conditions
+                        .contains(&output_data.output_id);
 
-                            if output_can_be_unlocked_now_and_in_future {
-                                // If output has a StorageDepositReturnUnlockCondition, the amount of it should be
-                                // subtracted, because this part needs to be sent back
-                                let amount = if let Some(unlock_conditions) = output_data.output.unlock_conditions() {
-                                    if let Some(sdr) = unlock_conditions.storage_deposit_return() {
-                                        if account_addresses
-                                            .iter()
-                                            .any(|a| a.address.inner == *sdr.return_address())
-                                        {
-                                            // sending to ourself, we get the full amount
-                                            output_data.output.amount()
-                                        } else {
-                                            // Sending to someone else
-                                            output_data.output.amount() - sdr.amount()
-                                        }
-                                    } else {
+                    // For outputs that are expired or have a timelock unlock condition, but no expiration unlock
+                    // condition and we then can unlock them, then they can never be not available for us anymore
+                    // and should be added to the balance
+                    if output_can_be_unlocked_now {
+                        // check if output can be unlocked always from now on, in that case it should be added to
+                        // the total amount
+                        let output_can_be_unlocked_now_and_in_future = can_output_be_unlocked_forever_from_now_on(
+                            // We use the addresses with unspent outputs, because other addresses of the
+                            // account without unspent outputs can't be related to this output
+                            &account.addresses_with_unspent_outputs,
+                            output_data,
+                            context.local_time,
+                        );
+
+                        if output_can_be_unlocked_now_and_in_future {
+                            // If output has a StorageDepositReturnUnlockCondition, the amount of it should be
+                            // subtracted, because this part needs to be sent back
+                            let amount = if let Some(unlock_conditions) = output_data.output.unlock_conditions() {
+                                if let Some(sdr) = unlock_conditions.storage_deposit_return() {
+                                    if context
+                                        .account_addresses
+                                        .iter()
+                                        .any(|a| a.address.inner == *sdr.return_address())
+                                    {
+                                        // sending to ourself, we get the full amount
                                         output_data.output.amount()
+                                    } else {
+                                        // Sending to someone else
+                                        locked_balance.storage_deposit_returns.push(new_locked_output(
+                                            output_data,
+                                            sdr.amount(),
+                                            None,
+                                        ));
+                                        output_data.output.amount() - sdr.amount()
                                     }
                                 } else {
                                     output_data.output.amount()
-                                };
-
-                                // add nft_id for nft outputs
-                                if let Output::Nft(output) = &output_data.output {
-                                    let nft_id = output.nft_id().or_from_output_id(output_data.output_id);
-                                    nfts.push(nft_id);
-                                }
-
-                                // Add amount
-                                total_amount += amount;
-                                // Add storage deposit
-                                required_storage_deposit += output_data.output.byte_cost(&byte_cost_config);
-                                // Add native tokens
-                                if let Some(native_tokens) = output_data.output.native_tokens() {
-                                    total_native_tokens.add_native_tokens(native_tokens.clone())?;
                                 }
                             } else {
-                                // only add outputs that can't be locked now and at any point in the future
-                                potentially_locked_outputs.insert(output_data.output_id, true);
+                                output_data.output.amount()
+                            };
+
+                            // add nft_id for nft outputs
+                            if let Output::Nft(output) = &output_data.output {
+                                let nft_id = output.nft_id().or_from_output_id(output_data.output_id);
+                                nfts.push(nft_id);
+                            }
+
+                            // Add amount
+                            total_amount += amount;
+                            // Add storage deposit
+                            let storage_deposit = output_data.output.byte_cost(&context.byte_cost_config);
+                            required_storage_deposit += storage_deposit;
+                            add_storage_deposit(
+                                &mut locked_balance.storage_deposit,
+                                &output_data.output,
+                                storage_deposit,
+                            );
+                            // Add native tokens
+                            if let Some(native_tokens) = output_data.output.native_tokens() {
+                                total_native_tokens.add_native_tokens(native_tokens.clone())?;
                             }
                         } else {
-                            // Don't add expired outputs that can't ever be unlocked by us
-                            if let Some(expiration) = output_data
+                            // only add outputs that can't be locked now and at any point in the future
+                            potentially_locked_outputs.insert(output_data.output_id, true);
+                            // The storage deposit needs to be returned if we claim it before it expires
+                            if let Some(sdr) = output_data
                                 .output
                                 .unlock_conditions()
-                                .expect("Output needs to have unlock conditions")
-                                .expiration()
+                                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
                             {
-                                // Not expired, could get unlockable when it's expired, so we insert it
-                                if local_time < expiration.timestamp() {
-                                    potentially_locked_outputs.insert(output_data.output_id, false);
-                                }
-                            } else {
+                                locked_balance.storage_deposit_returns.push(new_locked_output(
+                                    output_data,
+                                    sdr.amount(),
+                                    None,
+                                ));
+                            }
+                        }
+                    } else {
+                        let unlock_conditions = output_data
+                            .output
+                            .unlock_conditions()
+                            .expect("Output needs to have unlock conditions");
+                        // Don't add expired outputs that can't ever be unlocked by us
+                        if let Some(expiration) = unlock_conditions.expiration() {
+                            // Not expired, could get unlockable when it's expired, so we insert it
+                            if context.local_time < expiration.timestamp() {
                                 potentially_locked_outputs.insert(output_data.output_id, false);
+                                if let Some(timelock) = unlock_conditions
+                                    .timelock()
+                                    .filter(|timelock| context.local_time < timelock.timestamp())
+                                {
+                                    locked_balance.timelocked.push(new_locked_output(
+                                        output_data,
+                                        output_data.output.amount(),
+                                        Some(timelock.timestamp()),
+                                    ));
+                                } else if context
+                                    .account_addresses
+                                    .iter()
+                                    .any(|a| a.address.inner == *expiration.return_address())
+                                {
+                                    // Sent by us, we get it back if the recipient doesn't claim it
+                                    locked_balance.claimable_after_expiration.push(new_locked_output(
+                                        output_data,
+                                        output_data.output.amount(),
+                                        Some(expiration.timestamp()),
+                                    ));
+                                }
+                            }
+                        } else {
+                            potentially_locked_outputs.insert(output_data.output_id, false);
+                            if let Some(timelock) = unlock_conditions.timelock() {
+                                locked_balance.timelocked.push(new_locked_output(
+                                    output_data,
+                                    output_data.output.amount(),
+                                    Some(timelock.timestamp()),
+                                ));
                             }
                         }
                     }
                 }
             }
         }
+    }
 
-        // for `available` get locked_outputs, sum outputs amount and subtract from total_amount
-        log::debug!("[BALANCE] locked outputs: {:#?}", account.locked_outputs);
-        let mut locked_amount = 0;
-        let mut locked_native_tokens = NativeTokensBuilder::new();
+    // for `available` get locked_outputs, sum outputs amount and subtract from total_amount
+    log::debug!("[BALANCE] locked outputs: {:#?}", account.locked_outputs);
+    let mut locked_amount = 0;
+    let mut locked_native_tokens = NativeTokensBuilder::new();
 
-        for locked_output in &account.locked_outputs {
-            if let Some(output_data) = account.unspent_outputs.get(locked_output) {
-                // Only check outputs that are in this network
-                if output_data.network_id == network_id {
-                    locked_amount += output_data.output.amount();
-                    if let Some(native_tokens) = output_data.output.native_tokens() {
-                        locked_native_tokens.add_native_tokens(native_tokens.clone())?;
-                    }
-                }
+    for output_data in outputs.iter().copied() {
+        // Only check outputs that are in this network and part of the balance
+        if account.locked_outputs.contains(&output_data.output_id)
+            && output_data.network_id == context.network_id
+            && !account.ignored_outputs.contains(&output_data.output_id)
+            && !is_chain_address_output(output_data)
+            && match &output_data.output {
+                Output::Alias(alias_output) => is_state_controller(alias_output, &context.account_addresses),
+                _ => true,
+            }
+        {
+            locked_balance
+                .pending_transactions
+                .push(new_locked_output(output_data, output_data.output.amount(), None));
+            locked_amount += output_data.output.amount();
+            if let Some(native_tokens) = output_data.output.native_tokens() {
+                locked_native_tokens.add_native_tokens(native_tokens.clone())?;
             }
         }
-        log::debug!(
-            "[BALANCE] total_amount: {}, lockedbalance: {}",
-            total_amount,
-            locked_amount
-        );
-        if total_amount < locked_amount {
-            log::warn!("[BALANCE] total_balance is smaller than the available balance");
-            // It can happen that the locked_amount is greater than the available blance if a transaction wasn't
-            // confirmed when it got checked during syncing, but shortly after, when the outputs from the address were
-            // requested, so we just overwrite the locked_amount
-            locked_amount = total_amount;
-        };
+    }
+    log::debug!(
+        "[BALANCE] total_amount: {}, lockedbalance: {}",
+        total_amount,
+        locked_amount
+    );
+    if total_amount < locked_amount {
+        log::warn!("[BALANCE] total_balance is smaller than the available balance");
+        // It can happen that the locked_amount is greater than the available blance if a transaction wasn't
+        // confirmed when it got checked during syncing, but shortly after, when the outputs from the address were
+        // requested, so we just overwrite the locked_amount
+        locked_amount = total_amount;
+    };
+
+    let mut native_tokens_balance = Vec::new();
+
+    for native_token in total_native_tokens.finish()? {
+        // Check if some amount is currently locked
+        let locked_amount = locked_native_tokens.iter().find_map(|(id, amount)| {
+            if id == native_token.token_id() {
+                Some(amount)
+            } else {
+                None
+            }
+        });
+
+        native_tokens_balance.push(NativeTokensBalance {
+            token_id: *native_token.token_id(),
+            total: *native_token.amount(),
+            available: *native_token.amount() - *locked_amount.unwrap_or(&U256::from(0u8)),
+            metadata: account
+                .native_token_metadata
+                .get(native_token.token_id())
+                .cloned()
+                .flatten(),
+        })
+    }
 
-        let mut native_tokens_balance = Vec::new();
+    Ok(AccountBalance {
+        base_coin: BaseCoinBalance {
+            total: total_amount,
+            available: total_amount - locked_amount,
+        },
+        native_tokens: native_tokens_balance,
+        required_storage_deposit,
+        aliases,
+        foundries,
+        nfts,
+        potentially_locked_outputs,
+        chain_addresses: Vec::new(),
+        ignored_outputs,
+        locked_balance,
+    })
+}
+
+// Balances of the outputs that are locked to an alias or nft address owned by the account, ignored outputs are skipped,
+// because they're not part of any balance
+fn chain_address_balances(
+    account: &Account,
+    network_id: u64,
+    bech32_hrp: &str,
+) -> crate::Result<Vec<ChainAddressBalance>> {
+    let mut chain_addresses: Vec<ChainAddressBalance> = Vec::new();
+    for output_data in account.unspent_outputs.values() {
+        if output_data.network_id != network_id
+            || !is_chain_address_output(output_data)
+            || account.ignored_outputs.contains(&output_data.output_id)
+        {
+            continue;
+        }
+
+        let bech32_address = output_data.address.to_bech32(bech32_hrp);
+        let chain_address = match chain_addresses
+            .iter_mut()
+            .position(|chain_address| chain_address.address == bech32_address)
+        {
+            Some(index) => &mut chain_addresses[index],
+            None => {
+                chain_addresses.push(ChainAddressBalance {
+                    address: bech32_address,
+                    base_coin: BaseCoinBalance::default(),
+                    native_tokens: Vec::new(),
+                    nfts: Vec::new(),
+                    output_ids: Vec::new(),
+                });
+                chain_addresses.last_mut().expect("chain address was just added")
+            }
+        };
 
-        for native_token in total_native_tokens.finish()? {
-            // Check if some amount is currently locked
-            let locked_amount = locked_native_tokens.iter().find_map(|(id, amount)| {
-                if id == native_token.token_id() {
-                    Some(amount)
+        let is_locked = account.locked_outputs.contains(&output_data.output_id);
+        let amount = output_data.output.amount();
+        chain_address.base_coin.total += amount;
+        if !is_locked {
+            chain_address.base_coin.available += amount;
+        }
+        if let Some(native_tokens) = output_data.output.native_tokens() {
+            for native_token in native_tokens.iter() {
+                let available = if is_locked {
+                    U256::from(0u8)
                 } else {
-                    None
+                    *native_token.amount()
+                };
+                match chain_address
+                    .native_tokens
+                    .iter_mut()
+                    .find(|native_token_balance| native_token_balance.token_id == *native_token.token_id())
+                {
+                    Some(native_token_balance) => {
+                        native_token_balance.total += *native_token.amount();
+                        native_token_balance.available += available;
+                    }
+                    None => chain_address.native_tokens.push(NativeTokensBalance {
+                        token_id: *native_token.token_id(),
+                        total: *native_token.amount(),
+                        available,
+                        metadata: account
+                            .native_token_metadata
+                            .get(native_token.token_id())
+                            .cloned()
+                            .flatten(),
+                    }),
                 }
-            });
-
-            native_tokens_balance.push(NativeTokensBalance {
-                token_id: *native_token.token_id(),
-                total: *native_token.amount(),
-                available: *native_token.amount() - *locked_amount.unwrap_or(&U256::from(0u8)),
-            })
+            }
+        }
+        if let Output::Nft(nft_output) = &output_data.output {
+            chain_address
+                .nfts
+                .push(nft_output.nft_id().or_from_output_id(output_data.output_id));
         }
+        chain_address.output_ids.push(output_data.output_id);
+    }
+    Ok(chain_addresses)
+}
 
-        Ok(AccountBalance {
-            base_coin: BaseCoinBalance {
-                total: total_amount,
-                available: total_amount - locked_amount,
-            },
-            native_tokens: native_tokens_balance,
-            required_storage_deposit,
-            aliases,
-            foundries,
-            nfts,
-            potentially_locked_outputs,
-        })
+// Create the entry for the locked balance breakdown with the native tokens and nft id of the output
+fn new_locked_output(output_data: &OutputData, amount: u64, unlock_time: Option<u32>) -> LockedOutput {
+    LockedOutput {
+        output_id: output_data.output_id,
+        amount,
+        native_tokens: output_data
+            .output
+            .native_tokens()
+            .map(|native_tokens| native_tokens.iter().cloned().collect())
+            .unwrap_or_default(),
+        nft_id: match &output_data.output {
+            Output::Nft(nft_output) => Some(nft_output.nft_id().or_from_output_id(output_data.output_id)),
+            _ => None,
+        },
+        unlock_time,
+    }
+}
+
+// Add the storage deposit of a basic or nft output to the storage deposit of its kind
+fn add_storage_deposit(storage_deposit: &mut RequiredStorageDeposit, output: &Output, amount: u64) {
+    match output {
+        Output::Basic(_) => storage_deposit.basic += amount,
+        Output::Nft(_) => storage_deposit.nft += amount,
+        _ => {}
     }
 }
 
@@ -247,6 +525,13 @@ pub(crate) fn add_balances(balances: Vec<AccountBalance>) -> crate::Result<Accou
         total_balance.nfts.extend(balance.nfts.into_iter());
         total_balance.aliases.extend(balance.aliases.into_iter());
         total_balance.foundries.extend(balance.foundries.into_iter());
+        total_balance
+            .chain_addresses
+            .extend(balance.chain_addresses.into_iter());
+        total_balance
+            .ignored_outputs
+            .extend(balance.ignored_outputs.into_iter());
+        add_locked_balance(&mut total_balance.locked_balance, balance.locked_balance);
         for native_token_balance in &balance.native_tokens {
             if let Some(total_native_token_balance) = total_balance
                 .native_tokens
@@ -260,6 +545,7 @@ pub(crate) fn add_balances(balances: Vec<AccountBalance>) -> crate::Result<Accou
                     token_id: native_token_balance.token_id,
                     total: native_token_balance.total,
                     available: native_token_balance.available,
+                    metadata: native_token_balance.metadata.clone(),
                 })
             }
         }
@@ -267,3 +553,43 @@ pub(crate) fn add_balances(balances: Vec<AccountBalance>) -> crate::Result<Accou
 
     Ok(total_balance)
 }
+
+// Basic and nft outputs that are locked to an alias or nft address with only an address unlock condition, they can
+// only be spent together with the alias or nft, so they're not part of the account balance, but of the chain address
+// balance. Outputs with more unlock conditions are handled like the outputs of other addresses.
+fn is_chain_address_output(output_data: &OutputData) -> bool {
+    !matches!(output_data.address, Address::Ed25519(_))
+        && matches!(output_data.output, Output::Basic(_) | Output::Nft(_))
+        && matches!(
+            output_data
+                .output
+                .unlock_conditions()
+                .map(|unlock_conditions| unlock_conditions.as_ref()),
+            Some([UnlockCondition::Address(_)])
+        )
+}
+
+// Only the state controller can spend the funds of an alias
+fn is_state_controller(alias_output: &AliasOutput, account_addresses: &[AccountAddress]) -> bool {
+    account_addresses
+        .iter()
+        .any(|account_address| account_address.address.inner == *alias_output.state_controller_address())
+}
+
+fn add_locked_balance(total_locked_balance: &mut LockedBalance, locked_balance: LockedBalance) {
+    total_locked_balance.timelocked.extend(locked_balance.timelocked);
+    total_locked_balance
+        .claimable_after_expiration
+        .extend(locked_balance.claimable_after_expiration);
+    total_locked_balance
+        .storage_deposit_returns
+        .extend(locked_balance.storage_deposit_returns);
+    total_locked_balance
+        .pending_transactions
+        .extend(locked_balance.pending_transactions);
+    total_locked_balance.frozen.extend(locked_balance.frozen);
+    total_locked_balance.storage_deposit.alias += locked_balance.storage_deposit.alias;
+    total_locked_balance.storage_deposit.basic += locked_balance.storage_deposit.basic;
+    total_locked_balance.storage_deposit.foundry += locked_balance.storage_deposit.foundry;
+    total_locked_balance.storage_deposit.nft += locked_balance.storage_deposit.nft;
+}
@@ -0,0 +1,196 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::output::TokenId;
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::account::{
+    handle::AccountHandle,
+    types::{AccountBalance, BaseCoinBalance},
+};
+
+/// The maximum amount of balance snapshots an account keeps, the oldest snapshots get removed first
+pub(crate) const MAX_BALANCE_SNAPSHOTS: usize = 10_000;
+
+/// The balance of an account at a point in time, taken after a sync that changed the balance
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BalanceSnapshot {
+    /// Time of the sync in milliseconds
+    pub timestamp: u128,
+    /// Total and available amount of the base coin after the sync
+    #[serde(rename = "baseCoin")]
+    pub base_coin: BaseCoinBalance,
+    /// Total amounts of the native tokens after the sync
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokenTotal>,
+}
+
+/// Total amount of a native token in a [`BalanceSnapshot`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NativeTokenTotal {
+    /// Token id
+    #[serde(rename = "tokenId")]
+    pub token_id: TokenId,
+    /// Total amount
+    pub total: U256,
+}
+
+impl BalanceSnapshot {
+    fn new(balance: &AccountBalance, timestamp: u128) -> Self {
+        Self {
+            timestamp,
+            base_coin: balance.base_coin.clone(),
+            native_tokens: balance
+                .native_tokens
+                .iter()
+                .map(|native_token| NativeTokenTotal {
+                    token_id: native_token.token_id,
+                    total: native_token.total,
+                })
+                .collect(),
+        }
+    }
+
+    // Snapshots with the same amounts, independent of their time
+    fn has_same_balance(&self, other: &Self) -> bool {
+        self.base_coin == other.base_coin && self.native_tokens == other.native_tokens
+    }
+}
+
+impl AccountHandle {
+    /// Set the resolution of the balance history in milliseconds. Snapshots are grouped in time slots of this length
+    /// and only the latest balance of each slot is kept, 0 keeps every change
+    pub async fn set_balance_history_resolution(&self, resolution_in_milliseconds: u64) -> crate::Result<()> {
+        log::debug!(
+            "[BALANCE_HISTORY] set_balance_history_resolution {}",
+            resolution_in_milliseconds
+        );
+        let mut account = self.write().await;
+        account.balance_history_resolution = resolution_in_milliseconds;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+
+    /// Get the balance of the account at a point in time in milliseconds, which is the latest snapshot taken at or
+    /// before this time. Returns None if there is no snapshot from before this time
+    pub async fn get_balance_at(&self, timestamp: u128) -> Option<BalanceSnapshot> {
+        let account = self.read().await;
+        account
+            .balance_history
+            .iter()
+            .rev()
+            .find(|snapshot| snapshot.timestamp <= timestamp)
+            .cloned()
+    }
+
+    /// Get the balance snapshots taken within a time range in milliseconds, sorted by their time. The end of the range
+    /// is exclusive
+    pub async fn get_balance_history(&self, from_time: Option<u128>, to_time: Option<u128>) -> Vec<BalanceSnapshot> {
+        let account = self.read().await;
+        account
+            .balance_history
+            .iter()
+            .filter(|snapshot| {
+                from_time.map_or(true, |from_time| snapshot.timestamp >= from_time)
+                    && to_time.map_or(true, |to_time| snapshot.timestamp < to_time)
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Add a snapshot of the balance to the history if it changed since the latest snapshot, called after syncing
+    pub(crate) async fn record_balance_snapshot(&self, balance: &AccountBalance, timestamp: u128) -> crate::Result<()> {
+        let mut account = self.write().await;
+        let resolution = account.balance_history_resolution;
+        if !add_balance_snapshot(
+            &mut account.balance_history,
+            BalanceSnapshot::new(balance, timestamp),
+            resolution,
+        ) {
+            return Ok(());
+        }
+        log::debug!("[BALANCE_HISTORY] balance changed, recorded snapshot");
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+}
+
+// Add the snapshot if the balance changed, only the latest snapshot of a time slot and at most
+// `MAX_BALANCE_SNAPSHOTS` are kept. Returns if the history changed
+fn add_balance_snapshot(
+    balance_history: &mut Vec<BalanceSnapshot>,
+    snapshot: BalanceSnapshot,
+    resolution: u64,
+) -> bool {
+    if balance_history
+        .last()
+        .map_or(false, |latest_snapshot| latest_snapshot.has_same_balance(&snapshot))
+    {
+        return false;
+    }
+
+    let resolution = resolution as u128;
+    if resolution > 0
+        && balance_history.last().map_or(false, |latest_snapshot| {
+            latest_snapshot.timestamp / resolution == snapshot.timestamp / resolution
+        })
+    {
+        balance_history.pop();
+    }
+    balance_history.push(snapshot);
+    if balance_history.len() > MAX_BALANCE_SNAPSHOTS {
+        let excess = balance_history.len() - MAX_BALANCE_SNAPSHOTS;
+        balance_history.drain(..excess);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(timestamp: u128, total: u64) -> BalanceSnapshot {
+        BalanceSnapshot {
+            timestamp,
+            base_coin: BaseCoinBalance {
+                total,
+                available: total,
+            },
+            native_tokens: vec![NativeTokenTotal {
+                token_id: TokenId::new([1; 38]),
+                total: U256::from(total),
+            }],
+        }
+    }
+
+    #[test]
+    fn balance_snapshots() {
+        let mut balance_history = Vec::new();
+        assert!(add_balance_snapshot(&mut balance_history, snapshot(1_000, 10), 0));
+        // Unchanged balance
+        assert!(!add_balance_snapshot(&mut balance_history, snapshot(2_000, 10), 0));
+        assert!(add_balance_snapshot(&mut balance_history, snapshot(3_000, 20), 0));
+        assert_eq!(balance_history, vec![snapshot(1_000, 10), snapshot(3_000, 20)]);
+
+        // Only the latest snapshot of the time slot is kept
+        assert!(add_balance_snapshot(&mut balance_history, snapshot(3_500, 30), 1_000));
+        assert!(add_balance_snapshot(&mut balance_history, snapshot(4_000, 40), 1_000));
+        assert_eq!(
+            balance_history,
+            vec![snapshot(1_000, 10), snapshot(3_500, 30), snapshot(4_000, 40)]
+        );
+    }
+
+    #[test]
+    fn max_balance_snapshots() {
+        let mut balance_history = Vec::new();
+        for i in 0..MAX_BALANCE_SNAPSHOTS as u64 + 2 {
+            add_balance_snapshot(&mut balance_history, snapshot(i as u128, i), 0);
+        }
+        assert_eq!(balance_history.len(), MAX_BALANCE_SNAPSHOTS);
+        assert_eq!(balance_history[0], snapshot(2, 2));
+    }
+}
@@ -3,3 +3,5 @@
 
 /// The module for time related functions
 pub(crate) mod time;
+/// The module for the pagination of queries
+pub(crate) mod pagination;
@@ -0,0 +1,105 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use crate::{account::operations::transaction_query::SortOrder, Error};
+
+// A cursor is the sort value and the id of the last item of a page
+pub(crate) fn parse_cursor<V: FromStr, I: FromStr>(cursor: &str) -> crate::Result<(V, I)> {
+    let invalid_cursor = || Error::InvalidCursor(cursor.to_string());
+    let (value, id) = cursor.split_once(':').ok_or_else(invalid_cursor)?;
+    Ok((
+        value.parse().map_err(|_| invalid_cursor())?,
+        id.parse().map_err(|_| invalid_cursor())?,
+    ))
+}
+
+// Sort the items by their sort value and id, which makes the order unique, and keep at most `limit` items after the
+// cursor. Returns the cursor for the next page, None if there are no further items
+pub(crate) fn paginate<T, V, I>(
+    items: &mut Vec<T>,
+    sort_key: impl Fn(&T) -> (V, I),
+    sort: SortOrder,
+    cursor: Option<(V, I)>,
+    limit: usize,
+) -> Option<String>
+where
+    V: Ord + Display,
+    I: Ord + Display,
+{
+    let compare = |a: &(V, I), b: &(V, I)| match sort {
+        SortOrder::Ascending => a.cmp(b),
+        SortOrder::Descending => b.cmp(a),
+    };
+    items.sort_by(|a, b| compare(&sort_key(a), &sort_key(b)));
+    if let Some(cursor) = cursor {
+        items.retain(|item| compare(&sort_key(item), &cursor) == Ordering::Greater);
+    }
+
+    if items.len() > limit {
+        items.truncate(limit);
+        items.last().map(|item| {
+            let (value, id) = sort_key(item);
+            format!("{value}:{id}")
+        })
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_client::bee_block::{output::OutputId, payload::transaction::TransactionId};
+
+    use super::*;
+
+    fn pages(items: &[(u64, u32)], sort: SortOrder, limit: usize) -> Vec<Vec<(u64, u32)>> {
+        let mut pages = Vec::new();
+        let mut cursor = None;
+        loop {
+            let mut page = items.to_vec();
+            let next_cursor = paginate(&mut page, |item| *item, sort, cursor, limit);
+            pages.push(page);
+            match next_cursor {
+                Some(next_cursor) => cursor = Some(parse_cursor(&next_cursor).unwrap()),
+                None => return pages,
+            }
+        }
+    }
+
+    #[test]
+    fn pagination() {
+        // Same sort value, the id decides the order
+        let items = [(20, 1), (10, 2), (20, 0), (30, 3), (10, 1)];
+        assert_eq!(
+            pages(&items, SortOrder::Ascending, 2),
+            vec![vec![(10, 1), (10, 2)], vec![(20, 0), (20, 1)], vec![(30, 3)]]
+        );
+        assert_eq!(
+            pages(&items, SortOrder::Descending, 3),
+            vec![vec![(30, 3), (20, 1), (20, 0)], vec![(10, 2), (10, 1)]]
+        );
+        // No empty last page if the items fill the pages exactly
+        assert_eq!(pages(&items, SortOrder::Ascending, 5).len(), 1);
+        assert_eq!(pages(&[], SortOrder::Descending, 5), vec![Vec::new()]);
+    }
+
+    #[test]
+    fn cursor() {
+        let transaction_id = TransactionId::new([1; 32]);
+        let cursor = format!("1660000000000:{transaction_id}");
+        assert_eq!(
+            parse_cursor::<u128, TransactionId>(&cursor).unwrap(),
+            (1_660_000_000_000, transaction_id)
+        );
+        let output_id = OutputId::new(transaction_id, 1).unwrap();
+        let cursor = format!("1000000:{output_id}");
+        assert_eq!(parse_cursor::<u64, OutputId>(&cursor).unwrap(), (1_000_000, output_id));
+
+        assert!(parse_cursor::<u128, TransactionId>("1660000000000").is_err());
+        assert!(parse_cursor::<u128, TransactionId>(&format!("time:{transaction_id}")).is_err());
+        assert!(parse_cursor::<u128, TransactionId>("1660000000000:0x01").is_err());
+        assert!(parse_cursor::<u64, OutputId>(&format!("-1:{output_id}")).is_err());
+    }
+}
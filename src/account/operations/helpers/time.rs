@@ -26,7 +26,16 @@ pub(crate) fn can_output_be_unlocked_now(
     output_data: &OutputData,
     current_time: u32,
 ) -> bool {
-    if let Some(unlock_conditions) = output_data.output.unlock_conditions() {
+    can_be_unlocked_now(account_addresses, &output_data.output, current_time)
+}
+
+// Same as [`can_output_be_unlocked_now()`], for an output without metadata
+pub(crate) fn can_be_unlocked_now(
+    account_addresses: &[AddressWithUnspentOutputs],
+    output: &Output,
+    current_time: u32,
+) -> bool {
+    if let Some(unlock_conditions) = output.unlock_conditions() {
         if unlock_conditions.is_time_locked(current_time) {
             return false;
         }
@@ -0,0 +1,664 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use iota_client::bee_block::{
+    address::Address,
+    input::Input,
+    output::{dto::NativeTokenDto, NativeToken, Output, OutputId, TokenId},
+    payload::transaction::TransactionEssence,
+};
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::events::types::{InvoiceEvent, WalletEvent};
+use crate::{
+    account::{
+        handle::AccountHandle,
+        operations::helpers::time::can_be_unlocked_now,
+        types::{AddressWithUnspentOutputs, OutputData},
+        Account,
+    },
+    Error,
+};
+
+/// An invoice with a dedicated public address, incoming outputs to this address are matched to the invoice during
+/// syncing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invoice {
+    /// Id of the invoice, unique per account
+    pub id: u32,
+    /// Bech32 encoded address that was generated for the invoice
+    pub address: String,
+    /// Key index of the address
+    #[serde(rename = "keyIndex")]
+    pub key_index: u32,
+    /// Expected amount of the base coin
+    pub amount: u64,
+    /// Expected native tokens
+    #[serde(rename = "nativeTokens", default)]
+    pub native_tokens: Vec<NativeToken>,
+    /// Unix time in seconds until which the invoice has to be paid
+    pub deadline: u32,
+    /// Description of the invoice, for example an order number
+    pub description: Option<String>,
+    /// Current status of the invoice
+    pub status: InvoiceStatus,
+    /// The incoming outputs that were matched to the invoice
+    pub payments: Vec<InvoicePayment>,
+    /// Creation time in milliseconds
+    pub timestamp: u128,
+}
+
+/// Options for [`AccountHandle::create_invoice()`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvoiceOptions {
+    /// Expected amount of the base coin, can be 0 if native tokens are requested
+    pub amount: u64,
+    /// Expected native tokens
+    #[serde(rename = "nativeTokens", default)]
+    pub native_tokens: Vec<NativeToken>,
+    /// Unix time in seconds until which the invoice has to be paid
+    pub deadline: u32,
+    /// Description of the invoice
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// The status of an invoice
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum InvoiceStatus {
+    /// No payment received yet
+    Open,
+    /// Payments were received, but they don't cover the invoice yet
+    PartiallyPaid,
+    /// The exact amounts were received before the deadline
+    Paid,
+    /// More than the expected amounts were received, the invoice was covered before the deadline
+    Overpaid,
+    /// The invoice was only covered by payments received after the deadline
+    PaidLate,
+    /// The deadline passed without the invoice being covered, late payments are still matched
+    Expired,
+    /// Cancelled by the user, payments are no longer matched
+    Cancelled,
+}
+
+/// An incoming output that was matched to an invoice
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InvoicePayment {
+    /// The output id
+    #[serde(rename = "outputId")]
+    pub output_id: OutputId,
+    /// Amount of the base coin, without a storage deposit that has to be returned
+    pub amount: u64,
+    /// Native tokens of the output
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeToken>,
+    /// Milestone timestamp at which the output got booked
+    pub timestamp: u32,
+    /// If the output got booked after the deadline
+    pub late: bool,
+}
+
+/// Dto for an invoice
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvoiceDto {
+    /// Id of the invoice, unique per account
+    pub id: u32,
+    /// Bech32 encoded address that was generated for the invoice
+    pub address: String,
+    /// Key index of the address
+    #[serde(rename = "keyIndex")]
+    pub key_index: u32,
+    /// Expected amount of the base coin
+    pub amount: String,
+    /// Expected native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokenDto>,
+    /// Unix time in seconds until which the invoice has to be paid
+    pub deadline: u32,
+    /// Description of the invoice
+    pub description: Option<String>,
+    /// Current status of the invoice
+    pub status: InvoiceStatus,
+    /// The incoming outputs that were matched to the invoice
+    pub payments: Vec<InvoicePaymentDto>,
+    /// Creation time in milliseconds
+    pub timestamp: String,
+}
+
+impl From<&Invoice> for InvoiceDto {
+    fn from(value: &Invoice) -> Self {
+        Self {
+            id: value.id,
+            address: value.address.clone(),
+            key_index: value.key_index,
+            amount: value.amount.to_string(),
+            native_tokens: value.native_tokens.iter().map(NativeTokenDto::from).collect(),
+            deadline: value.deadline,
+            description: value.description.clone(),
+            status: value.status,
+            payments: value.payments.iter().map(InvoicePaymentDto::from).collect(),
+            timestamp: value.timestamp.to_string(),
+        }
+    }
+}
+
+/// Dto for an invoice payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvoicePaymentDto {
+    /// The output id
+    #[serde(rename = "outputId")]
+    pub output_id: OutputId,
+    /// Amount of the base coin
+    pub amount: String,
+    /// Native tokens of the output
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokenDto>,
+    /// Milestone timestamp at which the output got booked
+    pub timestamp: u32,
+    /// If the output got booked after the deadline
+    pub late: bool,
+}
+
+impl From<&InvoicePayment> for InvoicePaymentDto {
+    fn from(value: &InvoicePayment) -> Self {
+        Self {
+            output_id: value.output_id,
+            amount: value.amount.to_string(),
+            native_tokens: value.native_tokens.iter().map(NativeTokenDto::from).collect(),
+            timestamp: value.timestamp,
+            late: value.late,
+        }
+    }
+}
+
+/// Dto for [`InvoiceOptions`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvoiceOptionsDto {
+    /// Expected amount of the base coin
+    pub amount: String,
+    /// Expected native tokens
+    #[serde(rename = "nativeTokens", default)]
+    pub native_tokens: Vec<NativeTokenDto>,
+    /// Unix time in seconds until which the invoice has to be paid
+    pub deadline: u32,
+    /// Description of the invoice
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl TryFrom<&InvoiceOptionsDto> for InvoiceOptions {
+    type Error = crate::Error;
+
+    fn try_from(value: &InvoiceOptionsDto) -> crate::Result<Self> {
+        Ok(Self {
+            amount: u64::from_str(&value.amount)
+                .map_err(|_| iota_client::Error::InvalidAmount(value.amount.clone()))?,
+            native_tokens: value
+                .native_tokens
+                .iter()
+                .map(|native_token| Ok(NativeToken::try_from(native_token)?))
+                .collect::<crate::Result<Vec<NativeToken>>>()?,
+            deadline: value.deadline,
+            description: value.description.clone(),
+        })
+    }
+}
+
+impl InvoiceStatus {
+    /// Paid and cancelled invoices don't change anymore, no further payments are matched to them
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Paid | Self::Overpaid | Self::PaidLate | Self::Cancelled)
+    }
+}
+
+impl Invoice {
+    // Determine the status from the received payments, a cancelled invoice stays cancelled
+    fn current_status(&self, local_time: u32) -> InvoiceStatus {
+        if self.status == InvoiceStatus::Cancelled {
+            return InvoiceStatus::Cancelled;
+        }
+        let (received_amount, received_native_tokens) = sum_payments(self.payments.iter());
+        if self.is_covered_by(received_amount, &received_native_tokens) {
+            let (on_time_amount, on_time_native_tokens) =
+                sum_payments(self.payments.iter().filter(|payment| !payment.late));
+            if !self.is_covered_by(on_time_amount, &on_time_native_tokens) {
+                InvoiceStatus::PaidLate
+            } else if self.is_exceeded_by(received_amount, &received_native_tokens) {
+                InvoiceStatus::Overpaid
+            } else {
+                InvoiceStatus::Paid
+            }
+        } else if local_time > self.deadline {
+            InvoiceStatus::Expired
+        } else if self.payments.is_empty() {
+            InvoiceStatus::Open
+        } else {
+            InvoiceStatus::PartiallyPaid
+        }
+    }
+
+    fn is_covered_by(&self, amount: u64, native_tokens: &HashMap<TokenId, U256>) -> bool {
+        amount >= self.amount
+            && self.native_tokens.iter().all(|native_token| {
+                native_tokens
+                    .get(native_token.token_id())
+                    .map_or(false, |received| received >= native_token.amount())
+            })
+    }
+
+    fn is_exceeded_by(&self, amount: u64, native_tokens: &HashMap<TokenId, U256>) -> bool {
+        amount > self.amount
+            || native_tokens.iter().any(|(token_id, received)| {
+                self.native_tokens
+                    .iter()
+                    .find(|native_token| native_token.token_id() == token_id)
+                    .map_or(true, |native_token| received > native_token.amount())
+            })
+    }
+}
+
+impl AccountHandle {
+    /// Create an invoice, a new public address is generated for it. Payments to this address are matched to the
+    /// invoice during syncing
+    /// ```ignore
+    /// let invoice = account_handle
+    ///     .create_invoice(InvoiceOptions {
+    ///         amount: 1_000_000,
+    ///         native_tokens: Vec::new(),
+    ///         deadline: 1_700_000_000,
+    ///         description: Some("order 42".to_string()),
+    ///     })
+    ///     .await?;
+    /// println!("Pay to {}", invoice.address);
+    /// ```
+    pub async fn create_invoice(&self, options: InvoiceOptions) -> crate::Result<Invoice> {
+        log::debug!("[INVOICE] create_invoice");
+        if options.amount == 0 && options.native_tokens.is_empty() {
+            return Err(Error::InvalidInvoice(
+                "an amount or native tokens are required".to_string(),
+            ));
+        }
+        if options.deadline <= self.client.get_time_checked().await? {
+            return Err(Error::InvalidInvoice("deadline is in the past".to_string()));
+        }
+
+        let address = self
+            .generate_addresses(1, None)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or(Error::MissingParameter("address"))?;
+
+        let mut account = self.write().await;
+        let id = account.invoices.keys().max().map_or(0, |id| id + 1);
+        let invoice = Invoice {
+            id,
+            address: address.address().to_bech32(),
+            key_index: *address.key_index(),
+            amount: options.amount,
+            native_tokens: options.native_tokens,
+            deadline: options.deadline,
+            description: options.description,
+            status: InvoiceStatus::Open,
+            payments: Vec::new(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time went backwards")
+                .as_millis(),
+        };
+        account.invoices.insert(id, invoice.clone());
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+
+        Ok(invoice)
+    }
+
+    /// Get an invoice by its id
+    pub async fn get_invoice(&self, invoice_id: u32) -> Option<Invoice> {
+        let account = self.read().await;
+        account.invoices.get(&invoice_id).cloned()
+    }
+
+    /// Returns all invoices of the account with their payments, ordered by their id
+    pub async fn list_invoices(&self) -> Vec<Invoice> {
+        let account = self.read().await;
+        let mut invoices = account.invoices.values().cloned().collect::<Vec<_>>();
+        invoices.sort_by_key(|invoice| invoice.id);
+        invoices
+    }
+
+    /// Cancel an invoice that isn't covered yet, payments to its address will no longer be matched
+    pub async fn cancel_invoice(&self, invoice_id: u32) -> crate::Result<Invoice> {
+        log::debug!("[INVOICE] cancel_invoice {invoice_id}");
+        let mut account = self.write().await;
+        let invoice = account
+            .invoices
+            .get_mut(&invoice_id)
+            .ok_or(Error::InvoiceNotFound(invoice_id))?;
+        if !matches!(
+            invoice.status,
+            InvoiceStatus::Open | InvoiceStatus::PartiallyPaid | InvoiceStatus::Expired
+        ) {
+            return Err(Error::InvoiceNotCancellable(invoice_id));
+        }
+        invoice.status = InvoiceStatus::Cancelled;
+        let invoice = invoice.clone();
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        drop(account);
+
+        #[cfg(feature = "events")]
+        self.event_emitter.lock().await.emit(
+            self.read().await.index,
+            WalletEvent::Invoice(InvoiceEvent {
+                invoice_id,
+                status: InvoiceStatus::Cancelled,
+                output_ids: Vec::new(),
+            }),
+        );
+
+        Ok(invoice)
+    }
+
+    /// Match new incoming outputs to the invoices and update their status, called after the account got synced. Only
+    /// outputs that the account can unlock now or already spent are payments, so an output with a timelock is matched
+    /// once the timelock expired and an output with an expiration, like the ones with a storage deposit return from
+    /// [`AccountHandle::send_native_tokens()`], is matched before it expires
+    pub(crate) async fn update_invoices(&self) -> crate::Result<()> {
+        // Only request the time if there are invoices that can still change
+        let has_open_invoices = self
+            .read()
+            .await
+            .invoices
+            .values()
+            .any(|invoice| !invoice.status.is_final());
+        if !has_open_invoices {
+            return Ok(());
+        }
+        let local_time = self.client.get_time_checked().await?;
+
+        let mut account = self.write().await;
+        // The invoices whose status changed or that received payments, with the ids of the new outputs
+        let mut updated_invoices = Vec::new();
+        let Account {
+            invoices,
+            outputs,
+            transactions,
+            addresses_with_unspent_outputs,
+            ..
+        } = &mut *account;
+
+        // Outputs that were spent by the account, for example claimed outputs with a storage deposit return, are
+        // payments even if they would be expired by now
+        let spent_by_account = transactions
+            .values()
+            .filter(|transaction| !transaction.incoming)
+            .flat_map(|transaction| {
+                let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                essence.inputs().iter().filter_map(|input| match input {
+                    Input::Utxo(input) => Some(*input.output_id()),
+                    _ => None,
+                })
+            })
+            .collect::<HashSet<OutputId>>();
+
+        // Group the incoming outputs by their address once for all invoices. Outputs created by transactions of the
+        // account, like remainders, aren't payments
+        let mut incoming_outputs: HashMap<Address, Vec<&OutputData>> = HashMap::new();
+        for output_data in outputs.values() {
+            if !transactions.contains_key(output_data.output_id.transaction_id())
+                && (spent_by_account.contains(&output_data.output_id)
+                    || is_payment(addresses_with_unspent_outputs, &output_data.output, local_time))
+            {
+                incoming_outputs
+                    .entry(output_data.address)
+                    .or_default()
+                    .push(output_data);
+            }
+        }
+
+        for invoice in invoices.values_mut() {
+            if invoice.status.is_final() {
+                continue;
+            }
+            let (_hrp, invoice_address) = Address::try_from_bech32(&invoice.address)?;
+
+            let mut new_payments = incoming_outputs
+                .get(&invoice_address)
+                .into_iter()
+                .flatten()
+                .filter(|output_data| {
+                    !invoice
+                        .payments
+                        .iter()
+                        .any(|payment| payment.output_id == output_data.output_id)
+                })
+                .map(|output_data| invoice_payment(output_data, invoice.deadline))
+                .collect::<Vec<InvoicePayment>>();
+            new_payments.sort_by_key(|payment| (payment.timestamp, payment.output_id));
+            let output_ids = new_payments
+                .iter()
+                .map(|payment| payment.output_id)
+                .collect::<Vec<OutputId>>();
+            invoice.payments.extend(new_payments);
+
+            let status = invoice.current_status(local_time);
+            if status == invoice.status && output_ids.is_empty() {
+                continue;
+            }
+            log::debug!("[INVOICE] invoice {} is {:?}", invoice.id, status);
+            invoice.status = status;
+            updated_invoices.push((invoice.id, status, output_ids));
+        }
+        if updated_invoices.is_empty() {
+            return Ok(());
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        drop(account);
+
+        #[cfg(feature = "events")]
+        {
+            let account_index = self.read().await.index;
+            let event_emitter = self.event_emitter.lock().await;
+            for (invoice_id, status, output_ids) in updated_invoices {
+                event_emitter.emit(
+                    account_index,
+                    WalletEvent::Invoice(InvoiceEvent {
+                        invoice_id,
+                        status,
+                        output_ids,
+                    }),
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Check if an unspent incoming output is a payment. It needs to be unlockable by the account now, an output with an
+// expiration can only be unlocked until it expires
+fn is_payment(account_addresses: &[AddressWithUnspentOutputs], output: &Output, local_time: u32) -> bool {
+    matches!(output, Output::Basic(_) | Output::Nft(_)) && can_be_unlocked_now(account_addresses, output, local_time)
+}
+
+// The base coin amount of a payment, a storage deposit that has to be returned isn't part of it
+fn payment_amount(output: &Output) -> u64 {
+    let storage_deposit_return = output
+        .unlock_conditions()
+        .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+        .map_or(0, |sdr| sdr.amount());
+    output.amount().saturating_sub(storage_deposit_return)
+}
+
+// Create the payment of an incoming output
+fn invoice_payment(output_data: &OutputData, deadline: u32) -> InvoicePayment {
+    let output = &output_data.output;
+    let timestamp = output_data.metadata.milestone_timestamp_booked;
+    InvoicePayment {
+        output_id: output_data.output_id,
+        amount: payment_amount(output),
+        native_tokens: output
+            .native_tokens()
+            .map(|native_tokens| native_tokens.iter().cloned().collect())
+            .unwrap_or_default(),
+        timestamp,
+        late: timestamp > deadline,
+    }
+}
+
+// Sum of the base coin and native token amounts of the payments
+fn sum_payments<'a>(payments: impl Iterator<Item = &'a InvoicePayment>) -> (u64, HashMap<TokenId, U256>) {
+    let mut amount = 0u64;
+    let mut native_tokens = HashMap::new();
+    for payment in payments {
+        amount = amount.saturating_add(payment.amount);
+        for native_token in &payment.native_tokens {
+            let received = native_tokens.entry(*native_token.token_id()).or_insert_with(U256::zero);
+            *received = received.saturating_add(*native_token.amount());
+        }
+    }
+    (amount, native_tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_client::bee_block::{
+        address::Ed25519Address,
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition, UnlockCondition,
+            },
+            BasicOutputBuilder,
+        },
+        payload::transaction::TransactionId,
+    };
+
+    use super::*;
+    use crate::account::types::address::AddressWrapper;
+
+    const DEADLINE: u32 = 1_700_000_000;
+
+    fn invoice(payments: Vec<InvoicePayment>) -> Invoice {
+        Invoice {
+            id: 0,
+            address: String::new(),
+            key_index: 0,
+            amount: 1_000_000,
+            native_tokens: vec![NativeToken::new(TokenId::new([1; 38]), U256::from(100u8)).unwrap()],
+            deadline: DEADLINE,
+            description: None,
+            status: InvoiceStatus::Open,
+            payments,
+            timestamp: 0,
+        }
+    }
+
+    fn payment(index: u16, amount: u64, token_amount: u8, timestamp: u32) -> InvoicePayment {
+        InvoicePayment {
+            output_id: OutputId::new(TransactionId::new([0; 32]), index).unwrap(),
+            amount,
+            native_tokens: vec![NativeToken::new(TokenId::new([1; 38]), U256::from(token_amount)).unwrap()],
+            timestamp,
+            late: timestamp > DEADLINE,
+        }
+    }
+
+    #[test]
+    fn current_status() {
+        let on_time = DEADLINE - 1;
+        let late = DEADLINE + 1;
+        assert_eq!(invoice(Vec::new()).current_status(on_time), InvoiceStatus::Open);
+        assert_eq!(invoice(Vec::new()).current_status(late), InvoiceStatus::Expired);
+
+        let partial_payment = payment(0, 600_000, 60, on_time);
+        assert_eq!(
+            invoice(vec![partial_payment.clone()]).current_status(on_time),
+            InvoiceStatus::PartiallyPaid
+        );
+        assert_eq!(
+            invoice(vec![partial_payment.clone()]).current_status(late),
+            InvoiceStatus::Expired
+        );
+        // The base coin amount alone doesn't cover the invoice
+        assert_eq!(
+            invoice(vec![payment(0, 1_000_000, 99, on_time)]).current_status(on_time),
+            InvoiceStatus::PartiallyPaid
+        );
+
+        let paid = invoice(vec![partial_payment.clone(), payment(1, 400_000, 40, on_time)]);
+        assert_eq!(paid.current_status(on_time), InvoiceStatus::Paid);
+        // Stays paid after the deadline
+        assert_eq!(paid.current_status(late), InvoiceStatus::Paid);
+        assert_eq!(
+            invoice(vec![partial_payment.clone(), payment(1, 400_000, 41, on_time)]).current_status(on_time),
+            InvoiceStatus::Overpaid
+        );
+        assert_eq!(
+            invoice(vec![partial_payment, payment(1, 400_000, 40, late)]).current_status(late),
+            InvoiceStatus::PaidLate
+        );
+
+        let cancelled = Invoice {
+            status: InvoiceStatus::Cancelled,
+            ..paid
+        };
+        assert_eq!(cancelled.current_status(on_time), InvoiceStatus::Cancelled);
+    }
+
+    #[test]
+    fn final_status() {
+        assert!(!InvoiceStatus::Open.is_final());
+        assert!(!InvoiceStatus::PartiallyPaid.is_final());
+        // Late payments are still matched to expired invoices
+        assert!(!InvoiceStatus::Expired.is_final());
+        assert!(InvoiceStatus::Paid.is_final());
+        assert!(InvoiceStatus::Overpaid.is_final());
+        assert!(InvoiceStatus::PaidLate.is_final());
+        assert!(InvoiceStatus::Cancelled.is_final());
+    }
+
+    #[test]
+    fn storage_deposit_return_payment() {
+        let invoice_address = Address::Ed25519(Ed25519Address::new([0; 32]));
+        let sender_address = Address::Ed25519(Ed25519Address::new([1; 32]));
+        let account_addresses = vec![AddressWithUnspentOutputs {
+            address: AddressWrapper {
+                inner: invoice_address,
+                bech32_hrp: "rms".to_string(),
+            },
+            key_index: 0,
+            internal: false,
+            output_ids: Vec::new(),
+        }];
+        // Like an output from `send_native_tokens()`
+        let output = BasicOutputBuilder::new_with_amount(50_000)
+            .unwrap()
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(invoice_address)))
+            .add_unlock_condition(UnlockCondition::StorageDepositReturn(
+                StorageDepositReturnUnlockCondition::new(sender_address, 40_000).unwrap(),
+            ))
+            .add_unlock_condition(UnlockCondition::Expiration(
+                ExpirationUnlockCondition::new(sender_address, DEADLINE).unwrap(),
+            ))
+            .add_native_token(NativeToken::new(TokenId::new([1; 38]), U256::from(100u8)).unwrap())
+            .finish_output()
+            .unwrap();
+
+        assert!(is_payment(&account_addresses, &output, DEADLINE - 1));
+        // After the expiration the output belongs to the sender again
+        assert!(!is_payment(&account_addresses, &output, DEADLINE + 1));
+        assert!(!is_payment(&[], &output, DEADLINE - 1));
+        assert_eq!(payment_amount(&output), 10_000);
+    }
+}
@@ -3,10 +3,20 @@
 
 /// The module for the address generation
 pub(crate) mod address_generation;
+/// The module to format and parse human-readable amounts
+pub(crate) mod amount_formatting;
+/// The module for local notes and tags of transactions and outputs
+pub(crate) mod annotations;
 /// The module to get the accounts balance
 pub(crate) mod balance;
+/// The module for the history of balance snapshots
+pub(crate) mod balance_history;
 /// Helper functions
 pub(crate) mod helpers;
+/// The module for invoices with dedicated receiving addresses
+pub(crate) mod invoices;
+/// The module to query the nfts of an account
+pub(crate) mod nfts;
 /// The module for claiming of outputs with
 /// [`UnlockCondition`](iota_client::bee_block::output::UnlockCondition)s that aren't only
 /// [`AddressUnlockCondition`](iota_client::bee_block::output::unlock_condition::AddressUnlockCondition)
@@ -15,7 +25,23 @@ pub(crate) mod output_claiming;
 pub(crate) mod output_consolidation;
 /// The module to find additional addresses with unspent outputs
 pub(crate) mod output_finder;
+/// The module to query the outputs with filters, sorting and pagination
+pub(crate) mod output_query;
+/// The module to return or ignore unwanted incoming outputs
+pub(crate) mod output_rejection;
+/// The module to create and parse `iota:` payment request URIs
+pub(crate) mod payment_request;
+/// The module for payments which are sent repeatedly according to a schedule
+pub(crate) mod recurring_payments;
+/// The module for payments which are sent once they're due
+pub(crate) mod scheduled_payments;
 /// The module for synchronization of an account
 pub(crate) mod syncing;
 /// The module for transactions
 pub(crate) mod transaction;
+/// The module to decode transactions into their effect on the account
+pub(crate) mod transaction_decoding;
+/// The module to export the transaction history for accounting
+pub(crate) mod transaction_export;
+/// The module to query the transaction history with filters and pagination
+pub(crate) mod transaction_query;
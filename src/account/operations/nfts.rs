@@ -0,0 +1,104 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::{
+    address::Address,
+    output::{dto::NftIdDto, feature::Feature, NftId, NftOutput, Output, OutputId},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::account::{handle::AccountHandle, types::Irc27Metadata};
+
+/// An nft owned by the account together with its decoded IRC27 metadata
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NftWithMetadata {
+    /// The nft id
+    #[serde(rename = "nftId")]
+    pub nft_id: NftId,
+    /// The id of the output that currently holds the nft
+    #[serde(rename = "outputId")]
+    pub output_id: OutputId,
+    /// The decoded metadata, None if the nft has no immutable metadata or it's malformed
+    pub metadata: Option<Irc27Metadata>,
+    /// Set if the immutable metadata is not valid IRC27 metadata
+    #[serde(rename = "metadataError")]
+    pub metadata_error: Option<String>,
+}
+
+/// Dto for an nft with its IRC27 metadata
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NftWithMetadataDto {
+    /// The nft id
+    #[serde(rename = "nftId")]
+    pub nft_id: NftIdDto,
+    /// The id of the output that currently holds the nft
+    #[serde(rename = "outputId")]
+    pub output_id: OutputId,
+    /// The decoded metadata
+    pub metadata: Option<Irc27Metadata>,
+    /// Set if the immutable metadata is not valid IRC27 metadata
+    #[serde(rename = "metadataError")]
+    pub metadata_error: Option<String>,
+}
+
+impl From<&NftWithMetadata> for NftWithMetadataDto {
+    fn from(value: &NftWithMetadata) -> Self {
+        Self {
+            nft_id: NftIdDto::from(&value.nft_id),
+            output_id: value.output_id,
+            metadata: value.metadata.clone(),
+            metadata_error: value.metadata_error.clone(),
+        }
+    }
+}
+
+impl NftWithMetadata {
+    fn new(nft_output: &NftOutput, output_id: OutputId) -> Self {
+        let (metadata, metadata_error) = match Irc27Metadata::from_nft_output(nft_output) {
+            Some(Ok(metadata)) => (Some(metadata), None),
+            Some(Err(err)) => (None, Some(err.to_string())),
+            None => (None, None),
+        };
+        Self {
+            nft_id: nft_output.nft_id().or_from_output_id(output_id),
+            output_id,
+            metadata,
+            metadata_error,
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Returns the unspent nfts of the account with their IRC27 metadata. Malformed metadata is reported per nft with
+    /// `metadata_error`, so a single invalid nft doesn't fail the whole call.
+    pub async fn list_nfts_with_metadata(&self) -> Vec<NftWithMetadata> {
+        let account = self.read().await;
+        let mut nfts = Vec::new();
+        for output_data in account.unspent_outputs.values() {
+            if let Output::Nft(nft_output) = &output_data.output {
+                nfts.push(NftWithMetadata::new(nft_output, output_data.output_id));
+            }
+        }
+        nfts
+    }
+
+    /// Returns the unspent nfts of the account that were minted with the provided issuer, so the members of a
+    /// collection. Issuer needs to be a Bech32 encoded nft or alias address
+    pub async fn list_collection_nfts(&self, issuer: &str) -> crate::Result<Vec<NftWithMetadata>> {
+        let (_bech32_hrp, issuer_address) = Address::try_from_bech32(issuer)?;
+        let account = self.read().await;
+        let mut nfts = Vec::new();
+        for output_data in account.unspent_outputs.values() {
+            if let Output::Nft(nft_output) = &output_data.output {
+                let has_issuer = nft_output.immutable_features().iter().any(|feature| match feature {
+                    Feature::Issuer(issuer_feature) => *issuer_feature.address() == issuer_address,
+                    _ => false,
+                });
+                if has_issuer {
+                    nfts.push(NftWithMetadata::new(nft_output, output_data.output_id));
+                }
+            }
+        }
+        Ok(nfts)
+    }
+}
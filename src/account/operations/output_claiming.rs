@@ -5,18 +5,28 @@ use std::collections::HashSet;
 
 use iota_client::{
     api::input_selection::minimum_storage_deposit,
-    bee_block::output::{
-        unlock_condition::{AddressUnlockCondition, UnlockCondition},
-        BasicOutputBuilder, NativeTokensBuilder, NftOutputBuilder, Output, OutputId,
+    bee_block::{
+        address::Address,
+        input::Input,
+        output::{
+            unlock_condition::{AddressUnlockCondition, UnlockCondition},
+            BasicOutputBuilder, NativeTokensBuilder, NftOutputBuilder, Output, OutputId,
+        },
+        payload::transaction::{TransactionEssence, TransactionId},
     },
 };
 use serde::{Deserialize, Serialize};
 
-use crate::account::{
-    handle::AccountHandle,
-    operations::helpers::time::{can_output_be_unlocked_now, is_expired},
-    types::Transaction,
-    OutputData, TransactionOptions,
+#[cfg(feature = "events")]
+use crate::events::types::{OutputsClaimedEvent, WalletEvent};
+use crate::{
+    account::{
+        handle::AccountHandle,
+        operations::helpers::time::{can_output_be_unlocked_now, is_expired},
+        types::Transaction,
+        OutputData, TransactionOptions,
+    },
+    Error,
 };
 
 /// Enum to specify which outputs should be claimed
@@ -29,6 +39,71 @@ pub enum OutputsToClaim {
     All = 4,
 }
 
+/// Defines which outputs with additional unlock conditions are claimed automatically by the background syncing
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum ClaimPolicy {
+    /// Claim every output that can be unlocked
+    Always,
+    /// Don't claim outputs automatically
+    Never,
+    /// Only claim outputs with a storage deposit return amount below this amount, outputs without a storage deposit
+    /// return unlock condition are always claimed
+    ReturnAmountBelow(u64),
+    /// Only claim outputs from these Bech32 encoded addresses. The sender is taken from the sender feature, outputs
+    /// without sender feature are not claimed
+    AllowListedSenders(Vec<String>),
+}
+
+impl Default for ClaimPolicy {
+    fn default() -> Self {
+        Self::Never
+    }
+}
+
+impl ClaimPolicy {
+    fn validate(&self) -> crate::Result<()> {
+        if let Self::AllowListedSenders(senders) = self {
+            for sender in senders {
+                Address::try_from_bech32(sender)
+                    .map_err(|_| Error::InvalidClaimPolicy(format!("invalid sender address {sender}")))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if the output should be claimed according to the policy
+    fn allows(&self, output: &Output) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::ReturnAmountBelow(threshold) => match output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+            {
+                Some(sdr) => sdr.amount() < *threshold,
+                None => true,
+            },
+            Self::AllowListedSenders(senders) => match output_sender(output) {
+                Some(sender) => senders
+                    .iter()
+                    .filter_map(|allow_listed_sender| Address::try_from_bech32(allow_listed_sender).ok())
+                    .any(|(_bech32_hrp, allow_listed_sender)| allow_listed_sender == sender),
+                None => false,
+            },
+        }
+    }
+}
+
+// Get the address that sent the output from its sender feature, which is validated by the protocol. Return addresses
+// of unlock conditions can be set to any address, so an output without sender feature has an unknown sender
+pub(crate) fn output_sender(output: &Output) -> Option<Address> {
+    output
+        .features()
+        .and_then(|features| features.sender())
+        .map(|sender| *sender.address())
+}
+
 impl AccountHandle {
     /// Get basic and nft outputs that have [`ExpirationUnlockCondition`], [`StorageDepositReturnUnlockCondition`] or
     /// [`TimelockUnlockCondition`] and can be unlocked now and also get basic outputs with only an
@@ -45,8 +120,8 @@ impl AccountHandle {
         // Get outputs for the claim
         let mut output_ids_to_claim: HashSet<OutputId> = HashSet::new();
         for (output_id, output_data) in &account.unspent_outputs {
-            // Don't use outputs that are locked for other transactions
-            if !account.locked_outputs.contains(output_id) {
+            // Don't use outputs that are locked for other transactions or were rejected
+            if !account.locked_outputs.contains(output_id) && !account.ignored_outputs.contains(output_id) {
                 if let Some(output) = account.outputs.get(output_id) {
                     match &output.output {
                         Output::Basic(basic_output) => {
@@ -142,6 +217,121 @@ impl AccountHandle {
         self.claim_outputs_internal(output_ids_to_claim, basic_outputs).await
     }
 
+    /// Set the [`ClaimPolicy`] that is applied by the background syncing
+    pub async fn set_claim_policy(&self, claim_policy: ClaimPolicy) -> crate::Result<()> {
+        log::debug!("[OUTPUT_CLAIMING] set_claim_policy {:?}", claim_policy);
+        claim_policy.validate()?;
+        let mut account = self.write().await;
+        account.claim_policy = claim_policy;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+
+    /// Get the [`ClaimPolicy`] of the account
+    pub async fn get_claim_policy(&self) -> ClaimPolicy {
+        self.read().await.claim_policy.clone()
+    }
+
+    /// Claim the outputs with additional unlock conditions which are allowed by the [`ClaimPolicy`] of the account,
+    /// called by the background syncing after the account got synced. Every claiming transaction is reported with
+    /// an [`OutputsClaimed`](crate::events::types::WalletEvent::OutputsClaimed) event.
+    pub async fn apply_claim_policy(&self) -> crate::Result<Vec<Transaction>> {
+        let claim_policy = self.get_claim_policy().await;
+        if claim_policy == ClaimPolicy::Never {
+            return Ok(Vec::new());
+        }
+
+        let output_ids = self
+            .get_unlockable_outputs_with_additional_unlock_conditions(OutputsToClaim::All)
+            .await?;
+        let account = self.read().await;
+        let output_ids_to_claim = output_ids
+            .into_iter()
+            .filter(|output_id| {
+                account
+                    .unspent_outputs
+                    .get(output_id)
+                    .map_or(false, |output_data| claim_policy.allows(&output_data.output))
+            })
+            .collect::<Vec<OutputId>>();
+        drop(account);
+        if output_ids_to_claim.is_empty() {
+            return Ok(Vec::new());
+        }
+        log::debug!(
+            "[OUTPUT_CLAIMING] claiming {} outputs with {:?}",
+            output_ids_to_claim.len(),
+            claim_policy
+        );
+
+        let transactions = self.claim_outputs(output_ids_to_claim.clone()).await?;
+
+        #[cfg(feature = "events")]
+        {
+            let account_index = self.read().await.index;
+            let event_emitter = self.event_emitter.lock().await;
+            for transaction in &transactions {
+                let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                let output_ids = essence
+                    .inputs()
+                    .iter()
+                    .filter_map(|input| match input {
+                        Input::Utxo(input) if output_ids_to_claim.contains(input.output_id()) => {
+                            Some(*input.output_id())
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                event_emitter.emit(
+                    account_index,
+                    WalletEvent::OutputsClaimed(OutputsClaimedEvent {
+                        transaction_id: transaction.transaction_id,
+                        output_ids,
+                    }),
+                );
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// Get the outputs that were sent by the account with an [`ExpirationUnlockCondition`], but weren't claimed by the
+    /// recipient before they expired, so they can be unlocked by the account again
+    pub async fn get_expired_outgoing_outputs(&self) -> crate::Result<Vec<OutputId>> {
+        log::debug!("[OUTPUT_CLAIMING] get_expired_outgoing_outputs");
+        let local_time = self.client.get_time_checked().await?;
+        let account = self.read().await;
+
+        Ok(account
+            .unspent_outputs
+            .iter()
+            .filter(|(output_id, output_data)| {
+                !account.locked_outputs.contains(output_id)
+                    && account
+                        .transactions
+                        .get(output_id.transaction_id())
+                        .map_or(false, |transaction| !transaction.incoming)
+                    && is_expired(&output_data.output, local_time)
+                    && can_output_be_unlocked_now(&account.addresses_with_unspent_outputs, output_data, local_time)
+            })
+            .map(|(output_id, _)| *output_id)
+            .collect())
+    }
+
+    /// Claim back the outputs from
+    /// [AccountHandle.get_expired_outgoing_outputs()](crate::account::handle::AccountHandle.get_expired_outgoing_outputs)
+    /// into basic outputs with only an [AddressUnlockCondition], called by the background syncing after the account
+    /// got synced. The claimed outputs are added to the `returned_outputs` of the transactions which created them.
+    pub async fn reclaim_expired_outputs(&self) -> crate::Result<Vec<Transaction>> {
+        let output_ids = self.get_expired_outgoing_outputs().await?;
+        if output_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        log::debug!("[OUTPUT_CLAIMING] reclaiming {} expired outputs", output_ids.len());
+        self.claim_outputs(output_ids).await
+    }
+
     /// Get basic outputs that have only one unlock condition which is [AddressUnlockCondition], so they can be used as
     /// additional inputs
     pub async fn get_basic_outputs_for_additional_inputs(&self) -> crate::Result<Vec<OutputData>> {
@@ -151,8 +341,8 @@ impl AccountHandle {
         // Get basic outputs only with AddressUnlockCondition and no other unlock condition
         let mut basic_outputs: Vec<OutputData> = Vec::new();
         for (output_id, output_data) in &account.unspent_outputs {
-            // Don't use outputs that are locked for other transactions
-            if !account.locked_outputs.contains(output_id) {
+            // Don't use outputs that are locked for other transactions or were rejected
+            if !account.locked_outputs.contains(output_id) && !account.ignored_outputs.contains(output_id) {
                 if let Some(output) = account.outputs.get(output_id) {
                     if let Output::Basic(basic_output) = &output.output {
                         if basic_output.unlock_conditions().len() == 1 {
@@ -219,6 +409,8 @@ impl AccountHandle {
             // Amount we get with the storage deposit return amounts already subtracted
             let mut new_amount = 0;
             let mut new_native_tokens = NativeTokensBuilder::new();
+            // Expired outputs from our own transactions, which are returned to us
+            let mut returned_output_ids = Vec::new();
             // check native tokens
             for output_data in outputs {
                 if let Output::Nft(nft_output) = &output_data.output {
@@ -246,6 +438,7 @@ impl AccountHandle {
                 // if expired, we can send everything to us
                 // todo: check again if the expired address is in the account
                 if is_expired(&output_data.output, local_time) {
+                    returned_output_ids.push(output_data.output_id);
                     new_amount += output_data.output.amount();
                     if let Some(native_tokens) = output_data.output.native_tokens() {
                         new_native_tokens.add_native_tokens(native_tokens.clone())?;
@@ -357,6 +550,8 @@ impl AccountHandle {
                         tx.block_id,
                         tx.transaction_id
                     );
+                    self.link_returned_outputs(&returned_output_ids, tx.transaction_id)
+                        .await?;
                     claim_results.push(tx);
                 }
                 Err(e) => log::debug!("Output claim error: {}", e),
@@ -365,4 +560,85 @@ impl AccountHandle {
 
         Ok(claim_results)
     }
+
+    // Add the expired outputs to the returned outputs of the transactions which created them, if they were sent by us
+    async fn link_returned_outputs(
+        &self,
+        returned_output_ids: &[OutputId],
+        claiming_transaction_id: TransactionId,
+    ) -> crate::Result<()> {
+        if returned_output_ids.is_empty() {
+            return Ok(());
+        }
+        let mut account = self.write().await;
+        for output_id in returned_output_ids {
+            if let Some(transaction) = account.transactions.get_mut(output_id.transaction_id()) {
+                if !transaction.incoming {
+                    transaction.returned_outputs.insert(*output_id, claiming_transaction_id);
+                }
+            }
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_client::bee_block::{
+        address::Ed25519Address,
+        output::{
+            feature::{Feature, SenderFeature},
+            unlock_condition::{ExpirationUnlockCondition, StorageDepositReturnUnlockCondition},
+        },
+    };
+
+    use super::*;
+
+    fn output(sender: Option<Address>, return_address: Option<Address>, return_amount: u64) -> Output {
+        let mut builder = BasicOutputBuilder::new_with_amount(1_000_000)
+            .unwrap()
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(Address::Ed25519(
+                Ed25519Address::new([0; 32]),
+            ))));
+        if let Some(sender) = sender {
+            builder = builder.add_feature(Feature::Sender(SenderFeature::new(sender)));
+        }
+        if let Some(return_address) = return_address {
+            builder = builder
+                .add_unlock_condition(UnlockCondition::StorageDepositReturn(
+                    StorageDepositReturnUnlockCondition::new(return_address, return_amount).unwrap(),
+                ))
+                .add_unlock_condition(UnlockCondition::Expiration(
+                    ExpirationUnlockCondition::new(return_address, 1).unwrap(),
+                ));
+        }
+        builder.finish_output().unwrap()
+    }
+
+    #[test]
+    fn claim_policy() {
+        let sender = Address::Ed25519(Ed25519Address::new([1; 32]));
+        let other = Address::Ed25519(Ed25519Address::new([2; 32]));
+        let allow_listed_senders = ClaimPolicy::AllowListedSenders(vec![sender.to_bech32("rms")]);
+        assert!(allow_listed_senders.validate().is_ok());
+        assert!(ClaimPolicy::AllowListedSenders(vec!["rms1invalid".to_string()])
+            .validate()
+            .is_err());
+
+        assert!(allow_listed_senders.allows(&output(Some(sender), None, 0)));
+        assert!(!allow_listed_senders.allows(&output(Some(other), Some(sender), 50_000)));
+        // The return address doesn't prove who sent the output
+        assert!(!allow_listed_senders.allows(&output(None, Some(sender), 50_000)));
+        assert!(!allow_listed_senders.allows(&output(None, None, 0)));
+
+        let return_amount_below = ClaimPolicy::ReturnAmountBelow(100_000);
+        assert!(return_amount_below.allows(&output(None, Some(other), 50_000)));
+        assert!(!return_amount_below.allows(&output(None, Some(other), 100_000)));
+        assert!(return_amount_below.allows(&output(None, None, 0)));
+
+        assert!(ClaimPolicy::Always.allows(&output(None, Some(other), 100_000)));
+        assert!(!ClaimPolicy::Never.allows(&output(Some(sender), None, 0)));
+    }
 }
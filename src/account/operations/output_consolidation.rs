@@ -51,8 +51,8 @@ impl AccountHandle {
         for address in addresses_that_need_consolidation {
             let mut unspent_outputs = Vec::new();
             for output_id in &address.output_ids {
-                // Don't use outputs that are locked for other transactions
-                if !account.locked_outputs.contains(output_id) {
+                // Don't use outputs that are locked for other transactions or were rejected
+                if !account.locked_outputs.contains(output_id) && !account.ignored_outputs.contains(output_id) {
                     if let Some(output) = account.outputs.get(output_id) {
                         // Only consolidate basic outputs with no address unlock condition alone
                         if let Output::Basic(basic_output) = &output.output {
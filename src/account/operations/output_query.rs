@@ -0,0 +1,355 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::{
+    address::Address,
+    output::{unlock_condition::UnlockCondition, AliasId, NftId, Output, OutputId, TokenId},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::account::{
+    handle::AccountHandle,
+    operations::{
+        helpers::{
+            pagination::{paginate, parse_cursor},
+            time::can_output_be_unlocked_now,
+        },
+        transaction_query::SortOrder,
+    },
+    types::{OutputData, OutputDataDto, OutputKind},
+    Account,
+};
+
+/// The default amount of outputs per page
+pub(crate) const DEFAULT_OUTPUT_QUERY_LIMIT: usize = 20;
+
+/// Query for [`AccountHandle::query_outputs()`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OutputQuery {
+    /// Only outputs that match all set fields of the filter are returned
+    #[serde(default)]
+    pub filter: OutputFilter,
+    /// Field the outputs are sorted by, the booking time by default
+    #[serde(rename = "sortBy", default)]
+    pub sort_by: OutputSortBy,
+    /// Sort order, descending by default
+    #[serde(default)]
+    pub sort: SortOrder,
+    /// Cursor from a previous [`OutputPage`], the page will start after the last output of the previous page
+    pub cursor: Option<String>,
+    /// Maximum amount of outputs in the page, default is 20
+    pub limit: Option<usize>,
+}
+
+/// Filter for [`OutputQuery`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OutputFilter {
+    /// Only outputs of one of these kinds
+    #[serde(rename = "outputKinds", default)]
+    pub output_kinds: Vec<OutputKind>,
+    /// Only outputs that have all of these unlock conditions
+    #[serde(rename = "unlockConditionTypes", default)]
+    pub unlock_condition_types: Vec<UnlockConditionType>,
+    /// Only outputs holding this native token
+    #[serde(rename = "tokenId", default)]
+    pub token_id: Option<TokenId>,
+    /// Only the nft output with this nft id
+    #[serde(rename = "nftId", default)]
+    pub nft_id: Option<NftId>,
+    /// Only the alias output with this alias id
+    #[serde(rename = "aliasId", default)]
+    pub alias_id: Option<AliasId>,
+    /// Only outputs of this Bech32 encoded account address
+    #[serde(default)]
+    pub address: Option<String>,
+    /// Only outputs with at least this amount
+    #[serde(rename = "minAmount", default)]
+    pub min_amount: Option<u64>,
+    /// Only outputs with at most this amount
+    #[serde(rename = "maxAmount", default)]
+    pub max_amount: Option<u64>,
+    /// Only spent or only unspent outputs
+    #[serde(rename = "isSpent", default)]
+    pub is_spent: Option<bool>,
+    /// Only outputs that can or can't be unlocked by the account at the current time, requires a request to the node
+    /// to get the current time. Locked and ignored outputs aren't claimable
+    #[serde(rename = "claimableNow", default)]
+    pub claimable_now: Option<bool>,
+}
+
+/// Unlock condition types for [`OutputFilter`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum UnlockConditionType {
+    /// Address unlock condition
+    Address,
+    /// Storage deposit return unlock condition
+    StorageDepositReturn,
+    /// Timelock unlock condition
+    Timelock,
+    /// Expiration unlock condition
+    Expiration,
+    /// State controller address unlock condition
+    StateControllerAddress,
+    /// Governor address unlock condition
+    GovernorAddress,
+    /// Immutable alias address unlock condition
+    ImmutableAliasAddress,
+}
+
+impl UnlockConditionType {
+    fn matches(&self, unlock_condition: &UnlockCondition) -> bool {
+        matches!(
+            (self, unlock_condition),
+            (Self::Address, UnlockCondition::Address(_))
+                | (Self::StorageDepositReturn, UnlockCondition::StorageDepositReturn(_))
+                | (Self::Timelock, UnlockCondition::Timelock(_))
+                | (Self::Expiration, UnlockCondition::Expiration(_))
+                | (Self::StateControllerAddress, UnlockCondition::StateControllerAddress(_))
+                | (Self::GovernorAddress, UnlockCondition::GovernorAddress(_))
+                | (Self::ImmutableAliasAddress, UnlockCondition::ImmutableAliasAddress(_))
+        )
+    }
+}
+
+/// Fields the outputs of an [`OutputQuery`] can be sorted by
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum OutputSortBy {
+    /// Milestone timestamp at which the output got booked
+    Timestamp,
+    /// Amount of the output
+    Amount,
+}
+
+impl Default for OutputSortBy {
+    fn default() -> Self {
+        Self::Timestamp
+    }
+}
+
+/// A page of outputs returned by [`AccountHandle::query_outputs()`]
+#[derive(Debug, Clone, Serialize)]
+pub struct OutputPage {
+    /// The outputs of the page
+    pub outputs: Vec<OutputData>,
+    /// Cursor for the next page, None if there are no further outputs
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+}
+
+/// Dto for [`OutputPage`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutputPageDto {
+    /// The outputs of the page
+    pub outputs: Vec<OutputDataDto>,
+    /// Cursor for the next page, None if there are no further outputs
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+}
+
+impl From<&OutputPage> for OutputPageDto {
+    fn from(value: &OutputPage) -> Self {
+        Self {
+            outputs: value.outputs.iter().map(OutputDataDto::from).collect(),
+            next_cursor: value.next_cursor.clone(),
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Query the outputs of the account with a filter, sorted and paginated
+    /// ```ignore
+    /// let page = account_handle
+    ///     .query_outputs(OutputQuery {
+    ///         filter: OutputFilter {
+    ///             output_kinds: vec![OutputKind::Nft],
+    ///             is_spent: Some(false),
+    ///             ..Default::default()
+    ///         },
+    ///         sort_by: OutputSortBy::Amount,
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn query_outputs(&self, query: OutputQuery) -> crate::Result<OutputPage> {
+        log::debug!("[query_outputs]");
+        let address = match &query.filter.address {
+            Some(address) => Some(Address::try_from_bech32(address)?.1),
+            None => None,
+        };
+        let cursor = match &query.cursor {
+            Some(cursor) => Some(parse_cursor(cursor)?),
+            None => None,
+        };
+        let limit = query.limit.unwrap_or(DEFAULT_OUTPUT_QUERY_LIMIT);
+        // Only request the time if it's needed
+        let local_time = match query.filter.claimable_now {
+            Some(_) => Some(self.client.get_time_checked().await?),
+            None => None,
+        };
+
+        let account = self.read().await;
+        // Only references are filtered and sorted, the outputs of the page get cloned at the end
+        let mut outputs = account
+            .outputs
+            .values()
+            .filter(|output_data| matches_filter(&account, output_data, &query.filter, address.as_ref(), local_time))
+            .collect::<Vec<&OutputData>>();
+
+        let next_cursor = paginate(
+            &mut outputs,
+            |output_data| sort_key(output_data, query.sort_by),
+            query.sort,
+            cursor,
+            limit,
+        );
+
+        Ok(OutputPage {
+            outputs: outputs.into_iter().cloned().collect(),
+            next_cursor,
+        })
+    }
+}
+
+// Outputs are sorted by the selected field, the output id makes the order unique
+fn sort_key(output_data: &OutputData, sort_by: OutputSortBy) -> (u64, OutputId) {
+    let value = match sort_by {
+        OutputSortBy::Timestamp => output_data.metadata.milestone_timestamp_booked as u64,
+        OutputSortBy::Amount => output_data.output.amount(),
+    };
+    (value, output_data.output_id)
+}
+
+fn matches_filter(
+    account: &Account,
+    output_data: &OutputData,
+    filter: &OutputFilter,
+    address: Option<&Address>,
+    local_time: Option<u32>,
+) -> bool {
+    let output = &output_data.output;
+    if !filter.output_kinds.is_empty() && !filter.output_kinds.contains(&output_kind(output)) {
+        return false;
+    }
+    if !filter.unlock_condition_types.is_empty() && !has_unlock_conditions(output, &filter.unlock_condition_types) {
+        return false;
+    }
+    if let Some(token_id) = &filter.token_id {
+        if !output.native_tokens().map_or(false, |native_tokens| {
+            native_tokens
+                .iter()
+                .any(|native_token| native_token.token_id() == token_id)
+        }) {
+            return false;
+        }
+    }
+    if let Some(nft_id) = &filter.nft_id {
+        match output {
+            Output::Nft(nft_output) if nft_output.nft_id().or_from_output_id(output_data.output_id) == *nft_id => {}
+            _ => return false,
+        }
+    }
+    if let Some(alias_id) = &filter.alias_id {
+        match output {
+            Output::Alias(alias_output)
+                if alias_output.alias_id().or_from_output_id(output_data.output_id) == *alias_id => {}
+            _ => return false,
+        }
+    }
+    if address.map_or(false, |address| output_data.address != *address) {
+        return false;
+    }
+    if filter
+        .min_amount
+        .map_or(false, |min_amount| output.amount() < min_amount)
+        || filter
+            .max_amount
+            .map_or(false, |max_amount| output.amount() > max_amount)
+    {
+        return false;
+    }
+    if filter
+        .is_spent
+        .map_or(false, |is_spent| is_spent != output_data.is_spent)
+    {
+        return false;
+    }
+    if let (Some(claimable_now), Some(local_time)) = (filter.claimable_now, local_time) {
+        // Alias and foundry outputs don't have an address unlock condition, they're unlocked through their controller
+        let has_address_unlock_condition = output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.address())
+            .is_some();
+        let claimable = !output_data.is_spent
+            && !account.locked_outputs.contains(&output_data.output_id)
+            && !account.ignored_outputs.contains(&output_data.output_id)
+            && (!has_address_unlock_condition
+                || can_output_be_unlocked_now(&account.addresses_with_unspent_outputs, output_data, local_time));
+        if claimable != claimable_now {
+            return false;
+        }
+    }
+    true
+}
+
+// Check if the output has all of the unlock condition types
+fn has_unlock_conditions(output: &Output, unlock_condition_types: &[UnlockConditionType]) -> bool {
+    output.unlock_conditions().map_or(false, |unlock_conditions| {
+        unlock_condition_types.iter().all(|unlock_condition_type| {
+            unlock_conditions
+                .iter()
+                .any(|unlock_condition| unlock_condition_type.matches(unlock_condition))
+        })
+    })
+}
+
+fn output_kind(output: &Output) -> OutputKind {
+    match output {
+        Output::Alias(_) => OutputKind::Alias,
+        Output::Basic(_) => OutputKind::Basic,
+        Output::Foundry(_) => OutputKind::Foundry,
+        Output::Nft(_) => OutputKind::Nft,
+        Output::Treasury(_) => OutputKind::Treasury,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_client::bee_block::{
+        address::Ed25519Address,
+        output::{
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
+            BasicOutputBuilder,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn unlock_condition_types() {
+        let address = Address::Ed25519(Ed25519Address::new([0; 32]));
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .unwrap()
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(address)))
+            .add_unlock_condition(UnlockCondition::Expiration(
+                ExpirationUnlockCondition::new(address, 1).unwrap(),
+            ))
+            .finish_output()
+            .unwrap();
+        assert_eq!(output_kind(&output), OutputKind::Basic);
+
+        assert!(has_unlock_conditions(&output, &[UnlockConditionType::Address]));
+        assert!(has_unlock_conditions(
+            &output,
+            &[UnlockConditionType::Expiration, UnlockConditionType::Address]
+        ));
+        // All unlock condition types are required
+        assert!(!has_unlock_conditions(
+            &output,
+            &[UnlockConditionType::Address, UnlockConditionType::Timelock]
+        ));
+        assert!(!has_unlock_conditions(
+            &output,
+            &[UnlockConditionType::StorageDepositReturn]
+        ));
+    }
+}
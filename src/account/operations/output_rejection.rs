@@ -0,0 +1,438 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use iota_client::bee_block::{
+    address::Address,
+    output::{
+        feature::Feature,
+        unlock_condition::{AddressUnlockCondition, UnlockCondition},
+        BasicOutputBuilder, NftOutputBuilder, Output, OutputId, TokenId, OUTPUT_COUNT_MAX,
+    },
+    payload::transaction::TransactionId,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    account::{
+        handle::AccountHandle,
+        operations::{
+            helpers::time::{can_output_be_unlocked_now, is_expired},
+            output_claiming::output_sender,
+        },
+        types::{AddressWithUnspentOutputs, Transaction},
+        Account, TransactionOptions,
+    },
+    Error,
+};
+
+/// What happens with outputs that are rejected by the [`RejectionPolicy`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RejectAction {
+    /// Exclude the outputs from the balance, input selection and claiming
+    Ignore,
+    /// Send the outputs back to the return address of their storage deposit return unlock condition, outputs that
+    /// can't be returned are ignored
+    Return,
+}
+
+/// Defines which incoming outputs are rejected automatically by the background syncing, an output is rejected if
+/// any of the conditions matches
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RejectionPolicy {
+    /// What happens with the rejected outputs
+    pub action: RejectAction,
+    /// Reject outputs with a storage deposit return amount of at least this amount
+    #[serde(rename = "minReturnAmount", default)]
+    pub min_return_amount: Option<u64>,
+    /// Reject outputs from these Bech32 encoded addresses. The sender is taken from the sender feature, outputs without
+    /// sender feature have an unknown sender and are not rejected by this condition
+    #[serde(rename = "blockedSenders", default)]
+    pub blocked_senders: Vec<String>,
+    /// Reject outputs that hold any of these native tokens
+    #[serde(rename = "blockedTokenIds", default)]
+    pub blocked_token_ids: Vec<TokenId>,
+}
+
+impl RejectionPolicy {
+    fn validate(&self) -> crate::Result<()> {
+        for sender in &self.blocked_senders {
+            Address::try_from_bech32(sender)
+                .map_err(|_| Error::InvalidRejectionPolicy(format!("invalid sender address {sender}")))?;
+        }
+        Ok(())
+    }
+
+    /// Check if the output should be rejected according to the policy
+    fn rejects(&self, output: &Output) -> bool {
+        if let Some(min_return_amount) = self.min_return_amount {
+            if let Some(sdr) = output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+            {
+                if sdr.amount() >= min_return_amount {
+                    return true;
+                }
+            }
+        }
+        if let Some(sender) = output_sender(output) {
+            if self
+                .blocked_senders
+                .iter()
+                .filter_map(|blocked_sender| Address::try_from_bech32(blocked_sender).ok())
+                .any(|(_bech32_hrp, blocked_sender)| blocked_sender == sender)
+            {
+                return true;
+            }
+        }
+        if let Some(native_tokens) = output.native_tokens() {
+            if native_tokens
+                .iter()
+                .any(|native_token| self.blocked_token_ids.contains(native_token.token_id()))
+            {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl AccountHandle {
+    /// Set the [`RejectionPolicy`] that is applied by the background syncing, None disables the automatic rejection
+    pub async fn set_rejection_policy(&self, rejection_policy: Option<RejectionPolicy>) -> crate::Result<()> {
+        log::debug!("[OUTPUT_REJECTION] set_rejection_policy {:?}", rejection_policy);
+        if let Some(rejection_policy) = &rejection_policy {
+            rejection_policy.validate()?;
+        }
+        let mut account = self.write().await;
+        account.rejection_policy = rejection_policy;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+
+    /// Get the [`RejectionPolicy`] of the account
+    pub async fn get_rejection_policy(&self) -> Option<RejectionPolicy> {
+        self.read().await.rejection_policy.clone()
+    }
+
+    /// Ignore unspent outputs, so they're excluded from the balance, input selection and claiming. They can still be
+    /// claimed with [AccountHandle.claim_outputs()](crate::account::handle::AccountHandle.claim_outputs)
+    pub async fn ignore_outputs(&self, output_ids: Vec<OutputId>) -> crate::Result<()> {
+        log::debug!("[OUTPUT_REJECTION] ignore_outputs");
+        let mut account = self.write().await;
+        for output_id in &output_ids {
+            if !account.unspent_outputs.contains_key(output_id) {
+                return Err(Error::OutputNotFoundInUnspentOutputs(*output_id));
+            }
+        }
+        account.ignored_outputs.extend(output_ids);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+
+    /// Stop ignoring outputs, so they're part of the balance again
+    pub async fn unignore_outputs(&self, output_ids: Vec<OutputId>) -> crate::Result<()> {
+        log::debug!("[OUTPUT_REJECTION] unignore_outputs");
+        let mut account = self.write().await;
+        for output_id in &output_ids {
+            account.ignored_outputs.remove(output_id);
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+
+    /// Send outputs with a [`StorageDepositReturnUnlockCondition`] back to their return address before they expire.
+    /// The full amount, native tokens and nfts are sent back, so no funds of the account are used.
+    /// ```ignore
+    /// let transaction = account_handle.return_outputs(vec![output_id], None).await?;
+    /// ```
+    pub async fn return_outputs(
+        &self,
+        output_ids: Vec<OutputId>,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<Transaction> {
+        log::debug!("[OUTPUT_REJECTION] return_outputs");
+        if output_ids.is_empty() {
+            return Err(Error::MissingParameter("output_ids"));
+        }
+        if output_ids.len() > OUTPUT_COUNT_MAX as usize {
+            return Err(Error::TooManyOutputs(output_ids.len(), OUTPUT_COUNT_MAX));
+        }
+        let local_time = self.client.get_time_checked().await?;
+
+        let account = self.read().await;
+        let mut outputs = Vec::new();
+        for output_id in &output_ids {
+            if !is_returnable(&account, output_id, local_time) {
+                return Err(Error::OutputNotReturnable(*output_id));
+            }
+            let output_data = &account.unspent_outputs[output_id];
+            outputs.push(return_output(&output_data.output, *output_id)?);
+        }
+        drop(account);
+
+        let options = match options {
+            Some(mut options) => {
+                options.custom_inputs.replace(output_ids);
+                Some(options)
+            }
+            None => Some(TransactionOptions {
+                custom_inputs: Some(output_ids),
+                ..Default::default()
+            }),
+        };
+
+        self.send(outputs, options).await
+    }
+
+    /// Reject the unspent outputs that match the [`RejectionPolicy`] of the account, called by the background syncing
+    /// after the account got synced. Returned outputs are also ignored, until the returning transaction got confirmed.
+    pub async fn apply_rejection_policy(&self) -> crate::Result<Vec<Transaction>> {
+        let rejection_policy = match self.get_rejection_policy().await {
+            Some(rejection_policy) => rejection_policy,
+            None => return Ok(Vec::new()),
+        };
+        let local_time = self.client.get_time_checked().await?;
+
+        let account = self.read().await;
+        let mut output_ids_to_ignore = Vec::new();
+        let mut output_ids_to_return = Vec::new();
+        for (output_id, output_data) in &account.unspent_outputs {
+            if account.locked_outputs.contains(output_id)
+                || account.ignored_outputs.contains(output_id)
+                || !matches!(output_data.output, Output::Basic(_) | Output::Nft(_))
+                || !is_incoming(
+                    &account.transactions,
+                    &account.addresses_with_unspent_outputs,
+                    output_id,
+                    &output_data.output,
+                )
+                || !rejection_policy.rejects(&output_data.output)
+            {
+                continue;
+            }
+            if rejection_policy.action == RejectAction::Return && is_returnable(&account, output_id, local_time) {
+                output_ids_to_return.push(*output_id);
+            } else {
+                output_ids_to_ignore.push(*output_id);
+            }
+        }
+        drop(account);
+
+        if !output_ids_to_ignore.is_empty() {
+            log::debug!("[OUTPUT_REJECTION] ignoring {} outputs", output_ids_to_ignore.len());
+            self.ignore_outputs(output_ids_to_ignore).await?;
+        }
+
+        let mut transactions = Vec::new();
+        for output_ids in output_ids_to_return.chunks(OUTPUT_COUNT_MAX as usize) {
+            log::debug!("[OUTPUT_REJECTION] returning {} outputs", output_ids.len());
+            match self.return_outputs(output_ids.to_vec(), None).await {
+                Ok(transaction) => {
+                    self.ignore_outputs(output_ids.to_vec()).await?;
+                    transactions.push(transaction);
+                }
+                Err(err) => log::debug!("[OUTPUT_REJECTION] return error: {}", err),
+            }
+        }
+
+        Ok(transactions)
+    }
+}
+
+// Check if an output was sent to the account by someone else. Outputs sent by the account with a storage deposit return
+// or an expiration are also synced, because the return address belongs to the account, but they aren't incoming
+fn is_incoming(
+    transactions: &HashMap<TransactionId, Transaction>,
+    account_addresses: &[AddressWithUnspentOutputs],
+    output_id: &OutputId,
+    output: &Output,
+) -> bool {
+    transactions
+        .get(output_id.transaction_id())
+        .map_or(true, |transaction| transaction.incoming)
+        && output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.address())
+            .map_or(false, |address_unlock_condition| {
+                account_addresses
+                    .iter()
+                    .any(|address| address.address.inner == *address_unlock_condition.address())
+            })
+}
+
+// Check if the output is unspent, not used in another transaction and can be sent back to the return address of its
+// storage deposit return unlock condition
+fn is_returnable(account: &Account, output_id: &OutputId, local_time: u32) -> bool {
+    match account.unspent_outputs.get(output_id) {
+        Some(output_data) => {
+            !account.locked_outputs.contains(output_id)
+                && matches!(output_data.output, Output::Basic(_) | Output::Nft(_))
+                && output_data
+                    .output
+                    .unlock_conditions()
+                    .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+                    .is_some()
+                && !is_expired(&output_data.output, local_time)
+                && can_output_be_unlocked_now(&account.addresses_with_unspent_outputs, output_data, local_time)
+        }
+        None => false,
+    }
+}
+
+// Build the output that sends everything of the rejected output to the return address
+fn return_output(output: &Output, output_id: OutputId) -> crate::Result<Output> {
+    let return_address = *output
+        .unlock_conditions()
+        .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+        .ok_or(Error::OutputNotReturnable(output_id))?
+        .return_address();
+    let unlock_condition = UnlockCondition::Address(AddressUnlockCondition::new(return_address));
+
+    match output {
+        Output::Basic(basic_output) => Ok(BasicOutputBuilder::new_with_amount(basic_output.amount())?
+            .with_native_tokens(basic_output.native_tokens().clone())
+            .add_unlock_condition(unlock_condition)
+            .finish_output()?),
+        Output::Nft(nft_output) => {
+            // The sender feature can't be kept, because the sender address isn't unlocked in the transaction
+            let features = nft_output
+                .features()
+                .iter()
+                .filter(|feature| !matches!(feature, Feature::Sender(_)))
+                .cloned()
+                .collect::<Vec<Feature>>();
+            Ok(NftOutputBuilder::from(nft_output)
+                .with_nft_id(nft_output.nft_id().or_from_output_id(output_id))
+                .with_features(features)
+                .with_unlock_conditions(vec![unlock_condition])
+                .finish_output()?)
+        }
+        _ => Err(Error::OutputNotReturnable(output_id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_client::bee_block::{
+        address::Ed25519Address,
+        output::{
+            feature::SenderFeature,
+            unlock_condition::{ExpirationUnlockCondition, StorageDepositReturnUnlockCondition},
+            NativeToken,
+        },
+    };
+    use primitive_types::U256;
+
+    use super::*;
+    use crate::account::types::address::AddressWrapper;
+
+    fn output(sender: Option<Address>, return_amount: Option<u64>, token_id: Option<TokenId>) -> Output {
+        let return_address = Address::Ed25519(Ed25519Address::new([3; 32]));
+        let mut builder = BasicOutputBuilder::new_with_amount(1_000_000)
+            .unwrap()
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(Address::Ed25519(
+                Ed25519Address::new([0; 32]),
+            ))));
+        if let Some(sender) = sender {
+            builder = builder.add_feature(Feature::Sender(SenderFeature::new(sender)));
+        }
+        if let Some(return_amount) = return_amount {
+            builder = builder.add_unlock_condition(UnlockCondition::StorageDepositReturn(
+                StorageDepositReturnUnlockCondition::new(return_address, return_amount).unwrap(),
+            ));
+        }
+        if let Some(token_id) = token_id {
+            builder = builder.add_native_token(NativeToken::new(token_id, U256::from(10u8)).unwrap());
+        }
+        builder.finish_output().unwrap()
+    }
+
+    #[test]
+    fn rejection_policy() {
+        let blocked_sender = Address::Ed25519(Ed25519Address::new([1; 32]));
+        let token_id = TokenId::new([1; 38]);
+        let rejection_policy = RejectionPolicy {
+            action: RejectAction::Ignore,
+            min_return_amount: Some(100_000),
+            blocked_senders: vec![blocked_sender.to_bech32("rms")],
+            blocked_token_ids: vec![token_id],
+        };
+        assert!(rejection_policy.validate().is_ok());
+
+        assert!(rejection_policy.rejects(&output(Some(blocked_sender), None, None)));
+        assert!(rejection_policy.rejects(&output(None, Some(100_000), None)));
+        assert!(!rejection_policy.rejects(&output(None, Some(99_999), None)));
+        assert!(rejection_policy.rejects(&output(None, None, Some(token_id))));
+        // Without sender feature the sender is unknown, also if the return address is blocked
+        let blocked_return_address = RejectionPolicy {
+            blocked_senders: vec![Address::Ed25519(Ed25519Address::new([3; 32])).to_bech32("rms")],
+            ..rejection_policy.clone()
+        };
+        assert!(!blocked_return_address.rejects(&output(None, Some(1), None)));
+        let other_sender = Address::Ed25519(Ed25519Address::new([2; 32]));
+        assert!(!rejection_policy.rejects(&output(Some(other_sender), None, None)));
+
+        let invalid_policy = RejectionPolicy {
+            blocked_senders: vec!["invalid".to_string()],
+            ..rejection_policy
+        };
+        assert!(invalid_policy.validate().is_err());
+    }
+
+    #[test]
+    fn incoming_outputs() {
+        let account_address = Address::Ed25519(Ed25519Address::new([0; 32]));
+        let other_address = Address::Ed25519(Ed25519Address::new([1; 32]));
+        let account_addresses = vec![AddressWithUnspentOutputs {
+            address: AddressWrapper {
+                inner: account_address,
+                bech32_hrp: "rms".to_string(),
+            },
+            key_index: 0,
+            internal: false,
+            output_ids: Vec::new(),
+        }];
+        let output_id = OutputId::new(TransactionId::new([1; 32]), 0).unwrap();
+
+        let incoming_output = output(Some(other_address), Some(100_000), None);
+        assert!(is_incoming(
+            &HashMap::new(),
+            &account_addresses,
+            &output_id,
+            &incoming_output
+        ));
+
+        // An output the account sent with a storage deposit return and expiration, it's synced because the return
+        // address belongs to the account
+        let outgoing_output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .unwrap()
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(other_address)))
+            .add_unlock_condition(UnlockCondition::StorageDepositReturn(
+                StorageDepositReturnUnlockCondition::new(account_address, 100_000).unwrap(),
+            ))
+            .add_unlock_condition(UnlockCondition::Expiration(
+                ExpirationUnlockCondition::new(account_address, 1).unwrap(),
+            ))
+            .finish_output()
+            .unwrap();
+        assert!(!is_incoming(
+            &HashMap::new(),
+            &account_addresses,
+            &output_id,
+            &outgoing_output
+        ));
+        let rejection_policy = RejectionPolicy {
+            action: RejectAction::Ignore,
+            min_return_amount: Some(100_000),
+            blocked_senders: Vec::new(),
+            blocked_token_ids: Vec::new(),
+        };
+        // The policy alone would reject it
+        assert!(rejection_policy.rejects(&outgoing_output));
+    }
+}
@@ -0,0 +1,459 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use iota_client::bee_block::{
+    address::Address,
+    dto::U256Dto,
+    output::{NativeToken, NftId, TokenId},
+};
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    account::{
+        handle::AccountHandle,
+        operations::transaction::prepare_output::{Assets, Features, OutputOptions, Unlocks},
+    },
+    Error,
+};
+
+/// The scheme of payment request URIs
+pub(crate) const PAYMENT_REQUEST_SCHEME: &str = "iota";
+
+/// A request for a payment, which can be shared as `iota:` URI, for example in a QR code
+/// ```text
+/// iota:rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy?amount=1000000&tag=order%2042
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PaymentRequest {
+    /// Bech32 encoded address of the recipient
+    pub address: String,
+    /// Amount of the base coin, if not set the minimum storage deposit will be sent and returned
+    pub amount: Option<u64>,
+    /// Id of a requested native token
+    #[serde(rename = "tokenId")]
+    pub token_id: Option<TokenId>,
+    /// Amount of the requested native token
+    #[serde(rename = "tokenAmount")]
+    pub token_amount: Option<U256>,
+    /// Id of a requested nft
+    #[serde(rename = "nftId")]
+    pub nft_id: Option<NftId>,
+    /// Tag feature of the output
+    pub tag: Option<String>,
+    /// Metadata feature of the output
+    pub metadata: Option<String>,
+    /// Unix time in seconds after which the request shouldn't be paid anymore, it's not part of the output
+    #[serde(rename = "expirationUnixTime")]
+    pub expiration_unix_time: Option<u32>,
+    /// Unix time in seconds for an expiration unlock condition on the output, until it the payment can be claimed by
+    /// the recipient, after it the sender can claim it back
+    #[serde(rename = "outputExpirationUnixTime")]
+    pub output_expiration_unix_time: Option<u32>,
+}
+
+/// Dto for [`PaymentRequest`]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PaymentRequestDto {
+    /// Bech32 encoded address of the recipient
+    pub address: String,
+    /// Amount of the base coin
+    #[serde(default)]
+    pub amount: Option<String>,
+    /// Id of a requested native token
+    #[serde(rename = "tokenId", default)]
+    pub token_id: Option<TokenId>,
+    /// Amount of the requested native token
+    #[serde(rename = "tokenAmount", default)]
+    pub token_amount: Option<U256Dto>,
+    /// Id of a requested nft
+    #[serde(rename = "nftId", default)]
+    pub nft_id: Option<NftId>,
+    /// Tag feature of the output
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Metadata feature of the output
+    #[serde(default)]
+    pub metadata: Option<String>,
+    /// Unix time in seconds after which the request shouldn't be paid anymore
+    #[serde(rename = "expirationUnixTime", default)]
+    pub expiration_unix_time: Option<u32>,
+    /// Unix time in seconds for an expiration unlock condition on the output
+    #[serde(rename = "outputExpirationUnixTime", default)]
+    pub output_expiration_unix_time: Option<u32>,
+}
+
+impl TryFrom<&PaymentRequestDto> for PaymentRequest {
+    type Error = crate::Error;
+
+    fn try_from(value: &PaymentRequestDto) -> crate::Result<Self> {
+        Ok(Self {
+            address: value.address.clone(),
+            amount: match &value.amount {
+                Some(amount) => {
+                    Some(u64::from_str(amount).map_err(|_| iota_client::Error::InvalidAmount(amount.clone()))?)
+                }
+                None => None,
+            },
+            token_id: value.token_id,
+            token_amount: match &value.token_amount {
+                Some(token_amount) => Some(U256::try_from(token_amount)?),
+                None => None,
+            },
+            nft_id: value.nft_id,
+            tag: value.tag.clone(),
+            metadata: value.metadata.clone(),
+            expiration_unix_time: value.expiration_unix_time,
+            output_expiration_unix_time: value.output_expiration_unix_time,
+        })
+    }
+}
+
+impl From<&PaymentRequest> for PaymentRequestDto {
+    fn from(value: &PaymentRequest) -> Self {
+        Self {
+            address: value.address.clone(),
+            amount: value.amount.map(|amount| amount.to_string()),
+            token_id: value.token_id,
+            token_amount: value.token_amount.as_ref().map(U256Dto::from),
+            nft_id: value.nft_id,
+            tag: value.tag.clone(),
+            metadata: value.metadata.clone(),
+            expiration_unix_time: value.expiration_unix_time,
+            output_expiration_unix_time: value.output_expiration_unix_time,
+        }
+    }
+}
+
+impl PaymentRequest {
+    /// Encode the payment request as `iota:` URI, text parameters are percent-encoded
+    pub fn to_uri(&self) -> String {
+        let mut parameters = Vec::new();
+        if let Some(amount) = self.amount {
+            parameters.push(format!("amount={amount}"));
+        }
+        if let Some(token_id) = self.token_id {
+            parameters.push(format!("tokenId={token_id}"));
+        }
+        if let Some(token_amount) = self.token_amount {
+            parameters.push(format!("tokenAmount={token_amount}"));
+        }
+        if let Some(nft_id) = self.nft_id {
+            parameters.push(format!("nftId={nft_id}"));
+        }
+        if let Some(tag) = &self.tag {
+            parameters.push(format!("tag={}", percent_encode(tag)));
+        }
+        if let Some(metadata) = &self.metadata {
+            parameters.push(format!("metadata={}", percent_encode(metadata)));
+        }
+        if let Some(expiration_unix_time) = self.expiration_unix_time {
+            parameters.push(format!("expiration={expiration_unix_time}"));
+        }
+        if let Some(output_expiration_unix_time) = self.output_expiration_unix_time {
+            parameters.push(format!("outputExpiration={output_expiration_unix_time}"));
+        }
+
+        let mut uri = format!("{PAYMENT_REQUEST_SCHEME}:{}", self.address);
+        if !parameters.is_empty() {
+            uri.push('?');
+            uri.push_str(&parameters.join("&"));
+        }
+        uri
+    }
+
+    /// Decode a payment request from an `iota:` URI, unknown parameters are ignored
+    pub fn from_uri(uri: &str) -> crate::Result<Self> {
+        let invalid = |reason: &str| Error::InvalidPaymentRequest(reason.to_string());
+        let (scheme, rest) = uri.split_once(':').ok_or_else(|| invalid("missing scheme"))?;
+        if !scheme.eq_ignore_ascii_case(PAYMENT_REQUEST_SCHEME) {
+            return Err(Error::InvalidPaymentRequest(format!("unsupported scheme {scheme}")));
+        }
+        let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+        if address.is_empty() {
+            return Err(invalid("missing address"));
+        }
+
+        let mut payment_request = Self {
+            address: address.to_string(),
+            amount: None,
+            token_id: None,
+            token_amount: None,
+            nft_id: None,
+            tag: None,
+            metadata: None,
+            expiration_unix_time: None,
+            output_expiration_unix_time: None,
+        };
+        let mut keys = Vec::new();
+        for parameter in query.split('&').filter(|parameter| !parameter.is_empty()) {
+            let (key, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+            if keys.contains(&key) {
+                return Err(Error::InvalidPaymentRequest(format!("duplicate parameter {key}")));
+            }
+            keys.push(key);
+            let invalid_value = || Error::InvalidPaymentRequest(format!("invalid value for {key}: {value}"));
+            match key {
+                "amount" => payment_request.amount = Some(value.parse().map_err(|_| invalid_value())?),
+                "tokenId" => payment_request.token_id = Some(TokenId::from_str(value).map_err(|_| invalid_value())?),
+                "tokenAmount" => {
+                    payment_request.token_amount = Some(U256::from_dec_str(value).map_err(|_| invalid_value())?)
+                }
+                "nftId" => payment_request.nft_id = Some(NftId::from_str(value).map_err(|_| invalid_value())?),
+                "tag" => payment_request.tag = Some(percent_decode(value).ok_or_else(invalid_value)?),
+                "metadata" => payment_request.metadata = Some(percent_decode(value).ok_or_else(invalid_value)?),
+                "expiration" => {
+                    payment_request.expiration_unix_time = Some(value.parse().map_err(|_| invalid_value())?)
+                }
+                "outputExpiration" => {
+                    payment_request.output_expiration_unix_time = Some(value.parse().map_err(|_| invalid_value())?)
+                }
+                _ => log::debug!("[PAYMENT_REQUEST] ignoring unknown parameter {key}"),
+            }
+        }
+
+        if payment_request.token_id.is_some() != payment_request.token_amount.is_some() {
+            return Err(invalid("tokenId and tokenAmount have to be provided together"));
+        }
+
+        Ok(payment_request)
+    }
+
+    /// Convert the payment request into [`OutputOptions`] for
+    /// [`AccountHandle::prepare_output()`](crate::account::handle::AccountHandle::prepare_output), only the output
+    /// expiration becomes an expiration unlock condition
+    pub fn to_output_options(&self) -> crate::Result<OutputOptions> {
+        let native_tokens = match (self.token_id, self.token_amount) {
+            (Some(token_id), Some(token_amount)) => Some(vec![NativeToken::new(token_id, token_amount)?]),
+            _ => None,
+        };
+        let assets = if native_tokens.is_some() || self.nft_id.is_some() {
+            Some(Assets {
+                native_tokens,
+                nft_id: self.nft_id,
+            })
+        } else {
+            None
+        };
+        let features = if self.tag.is_some() || self.metadata.is_some() {
+            Some(Features {
+                tag: self.tag.clone(),
+                metadata: self.metadata.clone(),
+            })
+        } else {
+            None
+        };
+
+        Ok(OutputOptions {
+            recipient_address: self.address.clone(),
+            amount: self.amount.unwrap_or_default(),
+            assets,
+            features,
+            unlocks: self.output_expiration_unix_time.map(|expiration_unix_time| Unlocks {
+                expiration_unix_time: Some(expiration_unix_time),
+                timelock_unix_time: None,
+            }),
+            storage_deposit: None,
+        })
+    }
+}
+
+impl AccountHandle {
+    /// Create an `iota:` URI for a payment request, the address has to be of the network the account is connected to
+    /// ```ignore
+    /// let uri = account_handle
+    ///     .create_payment_request_uri(PaymentRequest {
+    ///         address: account_handle.generate_addresses(1, None).await?[0].address().to_bech32(),
+    ///         amount: Some(1_000_000),
+    ///         token_id: None,
+    ///         token_amount: None,
+    ///         nft_id: None,
+    ///         tag: Some("order 42".to_string()),
+    ///         metadata: None,
+    ///         expiration_unix_time: None,
+    ///         output_expiration_unix_time: None,
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn create_payment_request_uri(&self, payment_request: PaymentRequest) -> crate::Result<String> {
+        log::debug!("[PAYMENT_REQUEST] create_payment_request_uri");
+        self.validate_payment_request(&payment_request).await?;
+        Ok(payment_request.to_uri())
+    }
+
+    /// Parse an `iota:` URI into [`OutputOptions`] that can be passed to
+    /// [`AccountHandle::prepare_output()`](crate::account::handle::AccountHandle::prepare_output) or
+    /// [`AccountHandle::send()`](crate::account::handle::AccountHandle::send). Fails if the address is not of the
+    /// network the account is connected to or if the request is expired
+    pub async fn parse_payment_request_uri(&self, uri: &str) -> crate::Result<OutputOptions> {
+        log::debug!("[PAYMENT_REQUEST] parse_payment_request_uri {uri}");
+        let payment_request = PaymentRequest::from_uri(uri)?;
+        self.validate_payment_request(&payment_request).await?;
+        if let Some(expiration_unix_time) = payment_request.expiration_unix_time {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("time went backwards")
+                .as_secs();
+            if u64::from(expiration_unix_time) <= now {
+                return Err(Error::InvalidPaymentRequest(format!(
+                    "payment request expired at {expiration_unix_time}"
+                )));
+            }
+        }
+        payment_request.to_output_options()
+    }
+
+    // Check that the address is a valid Bech32 address with the HRP of the current network
+    async fn validate_payment_request(&self, payment_request: &PaymentRequest) -> crate::Result<()> {
+        let (hrp, _address) = Address::try_from_bech32(&payment_request.address)?;
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        if hrp != bech32_hrp {
+            return Err(Error::InvalidPaymentRequest(format!(
+                "address {} has the HRP {hrp}, expected {bech32_hrp}",
+                payment_request.address
+            )));
+        }
+        Ok(())
+    }
+}
+
+// Percent-encode all bytes except unreserved characters (RFC 3986)
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+// Decode percent-encoded bytes, returns None for invalid escapes or invalid UTF-8
+fn percent_decode(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = value.get(index + 1..index + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+
+    fn payment_request() -> PaymentRequest {
+        PaymentRequest {
+            address: ADDRESS.to_string(),
+            amount: None,
+            token_id: None,
+            token_amount: None,
+            nft_id: None,
+            tag: None,
+            metadata: None,
+            expiration_unix_time: None,
+            output_expiration_unix_time: None,
+        }
+    }
+
+    #[test]
+    fn uri() {
+        let payment_request = PaymentRequest {
+            amount: Some(1_000_000),
+            token_id: Some(TokenId::new([1; 38])),
+            token_amount: Some(U256::from(42u8)),
+            tag: Some("order 42".to_string()),
+            metadata: Some("Grüße & more=1?".to_string()),
+            expiration_unix_time: Some(1_700_000_000),
+            output_expiration_unix_time: Some(1_800_000_000),
+            ..payment_request()
+        };
+        let uri = payment_request.to_uri();
+        assert!(uri.starts_with(&format!("iota:{ADDRESS}?amount=1000000&tokenId=0x0101")));
+        assert!(uri.contains("&tag=order%2042&"));
+        assert!(uri.contains("&metadata=Gr%C3%BC%C3%9Fe%20%26%20more%3D1%3F&"));
+        assert!(uri.ends_with("&expiration=1700000000&outputExpiration=1800000000"));
+        assert_eq!(PaymentRequest::from_uri(&uri).unwrap(), payment_request);
+
+        assert_eq!(
+            PaymentRequest::from_uri(&format!("IOTA:{ADDRESS}")).unwrap(),
+            payment_request()
+        );
+        // Unknown parameters are ignored
+        assert_eq!(
+            PaymentRequest::from_uri(&format!("iota:{ADDRESS}?label=shop&amount=1")).unwrap(),
+            PaymentRequest {
+                amount: Some(1),
+                ..payment_request()
+            }
+        );
+
+        assert!(PaymentRequest::from_uri(ADDRESS).is_err());
+        assert!(PaymentRequest::from_uri(&format!("bitcoin:{ADDRESS}")).is_err());
+        assert!(PaymentRequest::from_uri("iota:?amount=1").is_err());
+        assert!(PaymentRequest::from_uri(&format!("iota:{ADDRESS}?amount=1&amount=2")).is_err());
+        assert!(PaymentRequest::from_uri(&format!("iota:{ADDRESS}?amount=-1")).is_err());
+        assert!(PaymentRequest::from_uri(&format!("iota:{ADDRESS}?tokenAmount=42")).is_err());
+    }
+
+    #[test]
+    fn percent_decoding() {
+        assert_eq!(percent_decode("order%2042").as_deref(), Some("order 42"));
+        assert_eq!(percent_decode("Gr%C3%BC%c3%9Fe").as_deref(), Some("Grüße"));
+        // '+' is not a space in URIs
+        assert_eq!(percent_decode("a+b").as_deref(), Some("a+b"));
+        assert_eq!(percent_decode("%"), None);
+        assert_eq!(percent_decode("%4"), None);
+        assert_eq!(percent_decode("%zz"), None);
+        // Invalid UTF-8
+        assert_eq!(percent_decode("%C3"), None);
+
+        let value = "~unreserved-._ and reserved :/?#[]@!$&'()*+,;=";
+        assert_eq!(percent_decode(&percent_encode(value)).as_deref(), Some(value));
+    }
+
+    #[test]
+    fn output_options() {
+        let payment_request = PaymentRequest {
+            amount: Some(1_000_000),
+            expiration_unix_time: Some(1_700_000_000),
+            ..payment_request()
+        };
+        // The expiration of the request doesn't become an expiration unlock condition
+        let output_options = payment_request.to_output_options().unwrap();
+        assert_eq!(output_options.amount, 1_000_000);
+        assert_eq!(output_options.unlocks, None);
+        assert_eq!(output_options.assets, None);
+
+        let output_options = PaymentRequest {
+            output_expiration_unix_time: Some(1_800_000_000),
+            ..payment_request
+        }
+        .to_output_options()
+        .unwrap();
+        assert_eq!(
+            output_options.unlocks,
+            Some(Unlocks {
+                expiration_unix_time: Some(1_800_000_000),
+                timelock_unix_time: None,
+            })
+        );
+    }
+}
@@ -0,0 +1,566 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::payload::transaction::TransactionId;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::events::types::{RecurringPaymentEvent, WalletEvent};
+use crate::{
+    account::{
+        handle::AccountHandle,
+        operations::transaction::{
+            prepare_output::{OutputOptions, OutputOptionsDto},
+            RESERVED_IDEMPOTENCY_KEY_PREFIX,
+        },
+        types::Transaction,
+        TransactionOptions,
+    },
+    Error,
+};
+
+const SECONDS_PER_DAY: u64 = 86400;
+// How often sending a run is tried before it's given up and the next run is scheduled
+const MAX_RUN_ATTEMPTS: u32 = 3;
+
+/// A payment that is sent repeatedly according to its schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringPayment {
+    /// Id of the recurring payment, unique per account
+    pub id: u32,
+    /// The outputs that will be sent on every run
+    pub outputs: Vec<OutputOptions>,
+    /// Options for the transactions, without idempotency key, because it's set for every run
+    #[serde(default)]
+    pub options: Option<TransactionOptions>,
+    /// When the payment should be sent
+    pub schedule: RecurringPaymentSchedule,
+    /// Current status of the recurring payment
+    pub status: RecurringPaymentStatus,
+    /// Unix time in seconds of the next run, None if there will be no further runs
+    #[serde(rename = "nextDueTime")]
+    pub next_due_time: Option<u32>,
+    /// All runs of the recurring payment
+    pub runs: Vec<RecurringPaymentRun>,
+}
+
+/// Defines when a recurring payment is sent
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecurringPaymentSchedule {
+    /// The interval between the runs
+    pub interval: RecurringPaymentInterval,
+    /// Unix time in seconds from which on the payment will be sent, the time of the day is used for all runs
+    #[serde(rename = "startTime")]
+    pub start_time: u32,
+    /// Unix time in seconds after which no further runs will happen
+    #[serde(rename = "endTime", default)]
+    pub end_time: Option<u32>,
+    /// Maximum amount of executed runs
+    #[serde(rename = "maxCount", default)]
+    pub max_count: Option<u32>,
+    /// How runs are handled which were missed, because the wallet wasn't running
+    #[serde(rename = "catchUpPolicy", default)]
+    pub catch_up_policy: CatchUpPolicy,
+}
+
+/// The interval of a recurring payment, all dates are in UTC
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum RecurringPaymentInterval {
+    /// Every n days
+    Days(u32),
+    /// Every month on the provided day of the month, has to be between 1 and 28, so it exists in every month
+    DayOfMonth(u8),
+}
+
+/// Defines what should happen with runs that were missed
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CatchUpPolicy {
+    /// Execute every missed run
+    ExecuteAll,
+    /// Only execute the latest due run, older missed runs are recorded as skipped
+    ExecuteLatest,
+}
+
+impl Default for CatchUpPolicy {
+    fn default() -> Self {
+        Self::ExecuteLatest
+    }
+}
+
+/// The status of a recurring payment
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum RecurringPaymentStatus {
+    /// Runs will be executed when they're due
+    Active,
+    /// The end time or the maximum count was reached
+    Completed,
+    /// Cancelled by the user
+    Cancelled,
+}
+
+/// A single run of a recurring payment
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecurringPaymentRun {
+    /// Unix time in seconds when the run was due
+    #[serde(rename = "dueTime")]
+    pub due_time: u32,
+    /// Unix time in seconds when the run was processed
+    #[serde(rename = "executionTime")]
+    pub execution_time: u32,
+    /// Result of the run
+    pub status: RecurringPaymentRunStatus,
+    /// How often sending the run was tried, a failed run is tried again on the next execution until it was tried
+    /// three times
+    #[serde(default)]
+    pub attempts: u32,
+}
+
+/// The result of a run of a recurring payment
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum RecurringPaymentRunStatus {
+    /// Sent in the transaction with this id
+    Executed(TransactionId),
+    /// Sending failed with this error
+    Failed(String),
+    /// Skipped because of the catch up policy
+    Skipped,
+}
+
+/// Dto for a recurring payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringPaymentDto {
+    /// Id of the recurring payment, unique per account
+    pub id: u32,
+    /// The outputs that will be sent on every run
+    pub outputs: Vec<OutputOptionsDto>,
+    /// Options for the transactions
+    pub options: Option<TransactionOptions>,
+    /// When the payment should be sent
+    pub schedule: RecurringPaymentSchedule,
+    /// Current status of the recurring payment
+    pub status: RecurringPaymentStatus,
+    /// Unix time in seconds of the next run
+    #[serde(rename = "nextDueTime")]
+    pub next_due_time: Option<u32>,
+    /// All runs of the recurring payment
+    pub runs: Vec<RecurringPaymentRun>,
+}
+
+impl From<&RecurringPayment> for RecurringPaymentDto {
+    fn from(value: &RecurringPayment) -> Self {
+        Self {
+            id: value.id,
+            outputs: value.outputs.iter().map(OutputOptionsDto::from).collect(),
+            options: value.options.clone(),
+            schedule: value.schedule,
+            status: value.status,
+            next_due_time: value.next_due_time,
+            runs: value.runs.clone(),
+        }
+    }
+}
+
+impl RecurringPaymentRun {
+    // A failed run is sent again on the next execution, until the maximum attempts are reached
+    fn will_be_retried(&self) -> bool {
+        matches!(self.status, RecurringPaymentRunStatus::Failed(_)) && self.attempts < MAX_RUN_ATTEMPTS
+    }
+}
+
+impl RecurringPaymentSchedule {
+    /// Returns the first run at or after the start time
+    fn first_due_time(&self) -> Option<u32> {
+        let first_due_time = match self.interval {
+            RecurringPaymentInterval::Days(_) => Some(self.start_time),
+            RecurringPaymentInterval::DayOfMonth(day) => {
+                let start_time = self.start_time as u64;
+                let (year, month, _) = civil_from_days((start_time / SECONDS_PER_DAY) as i64);
+                let candidate = unix_time_of_day(year, month, day, start_time % SECONDS_PER_DAY);
+                if candidate >= start_time {
+                    u32::try_from(candidate).ok()
+                } else {
+                    self.next_due_time_after(self.start_time)
+                }
+            }
+        };
+        first_due_time.filter(|due_time| self.end_time.map_or(true, |end_time| *due_time <= end_time))
+    }
+
+    /// Returns the run following the run at `due_time`
+    fn next_due_time_after(&self, due_time: u32) -> Option<u32> {
+        let due_time = due_time as u64;
+        let next_due_time = match self.interval {
+            RecurringPaymentInterval::Days(days) => due_time + days as u64 * SECONDS_PER_DAY,
+            RecurringPaymentInterval::DayOfMonth(day) => {
+                let (year, month, _) = civil_from_days((due_time / SECONDS_PER_DAY) as i64);
+                let (year, month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+                unix_time_of_day(year, month, day, due_time % SECONDS_PER_DAY)
+            }
+        };
+        u32::try_from(next_due_time)
+            .ok()
+            .filter(|due_time| self.end_time.map_or(true, |end_time| *due_time <= end_time))
+    }
+
+    fn validate(&self) -> crate::Result<()> {
+        match self.interval {
+            RecurringPaymentInterval::Days(0) => Err(Error::InvalidRecurringPayment(
+                "interval needs to be at least one day".to_string(),
+            )),
+            RecurringPaymentInterval::DayOfMonth(day) if !(1..=28).contains(&day) => Err(
+                Error::InvalidRecurringPayment(format!("day of month {day} is not between 1 and 28")),
+            ),
+            _ => {
+                if self.max_count == Some(0) {
+                    return Err(Error::InvalidRecurringPayment(
+                        "max count needs to be at least one".to_string(),
+                    ));
+                }
+                if self.first_due_time().is_none() {
+                    return Err(Error::InvalidRecurringPayment(
+                        "end time is before the first run".to_string(),
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Create a recurring payment, its runs will be sent by the background syncing or
+    /// [AccountHandle.execute_due_recurring_payments()](crate::account::handle::AccountHandle.execute_due_recurring_payments)
+    /// ```ignore
+    /// let schedule = RecurringPaymentSchedule {
+    ///     interval: RecurringPaymentInterval::DayOfMonth(1),
+    ///     start_time: 1_700_000_000,
+    ///     end_time: None,
+    ///     max_count: Some(12),
+    ///     catch_up_policy: CatchUpPolicy::ExecuteAll,
+    /// };
+    ///
+    /// let recurring_payment = account_handle.create_recurring_payment(outputs, schedule, None).await?;
+    /// println!("Next run: {:?}", recurring_payment.next_due_time);
+    /// ```
+    pub async fn create_recurring_payment(
+        &self,
+        outputs: Vec<OutputOptions>,
+        schedule: RecurringPaymentSchedule,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<RecurringPayment> {
+        log::debug!("[RECURRING_PAYMENT] create_recurring_payment");
+        if outputs.is_empty() {
+            return Err(Error::MissingParameter("outputs"));
+        }
+        if options
+            .as_ref()
+            .map_or(false, |options| options.idempotency_key.is_some())
+        {
+            return Err(Error::InvalidRecurringPayment(
+                "the idempotency key is set for every run and can't be provided in the options".to_string(),
+            ));
+        }
+        schedule.validate()?;
+
+        let mut account = self.write().await;
+        let id = account.recurring_payments.keys().max().map_or(0, |id| id + 1);
+        let recurring_payment = RecurringPayment {
+            id,
+            outputs,
+            options,
+            schedule,
+            status: RecurringPaymentStatus::Active,
+            next_due_time: schedule.first_due_time(),
+            runs: Vec::new(),
+        };
+        account.recurring_payments.insert(id, recurring_payment.clone());
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+
+        Ok(recurring_payment)
+    }
+
+    /// Get a recurring payment by its id
+    pub async fn get_recurring_payment(&self, payment_id: u32) -> Option<RecurringPayment> {
+        let account = self.read().await;
+        account.recurring_payments.get(&payment_id).cloned()
+    }
+
+    /// Returns all recurring payments of the account with their runs, ordered by their id
+    pub async fn list_recurring_payments(&self) -> Vec<RecurringPayment> {
+        let account = self.read().await;
+        let mut recurring_payments = account.recurring_payments.values().cloned().collect::<Vec<_>>();
+        recurring_payments.sort_by_key(|recurring_payment| recurring_payment.id);
+        recurring_payments
+    }
+
+    /// Cancel an active recurring payment, no further runs will be executed
+    pub async fn cancel_recurring_payment(&self, payment_id: u32) -> crate::Result<RecurringPayment> {
+        log::debug!("[RECURRING_PAYMENT] cancel_recurring_payment {payment_id}");
+        let mut account = self.write().await;
+        let recurring_payment = account
+            .recurring_payments
+            .get_mut(&payment_id)
+            .ok_or(Error::RecurringPaymentNotFound(payment_id))?;
+        if recurring_payment.status != RecurringPaymentStatus::Active {
+            return Err(Error::RecurringPaymentNotActive(payment_id));
+        }
+        recurring_payment.status = RecurringPaymentStatus::Cancelled;
+        recurring_payment.next_due_time = None;
+        let recurring_payment = recurring_payment.clone();
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+
+        Ok(recurring_payment)
+    }
+
+    /// Execute all due runs of the active recurring payments, called by the background syncing after the account got
+    /// synced. Runs which were missed are handled according to the [`CatchUpPolicy`] of the payment. A failed run is
+    /// tried again on the next execution, until it failed three times, later runs wait until then.
+    pub async fn execute_due_recurring_payments(&self) -> crate::Result<Vec<Transaction>> {
+        let local_time = self.client.get_time_checked().await?;
+
+        let account = self.read().await;
+        let mut recurring_payments = account
+            .recurring_payments
+            .values()
+            .filter(|recurring_payment| recurring_payment.status == RecurringPaymentStatus::Active)
+            .cloned()
+            .collect::<Vec<_>>();
+        drop(account);
+        recurring_payments.sort_by_key(|recurring_payment| recurring_payment.id);
+
+        let mut transactions = Vec::new();
+        for recurring_payment in recurring_payments {
+            let mut due_times = Vec::new();
+            let mut next_due_time = recurring_payment.next_due_time;
+            while let Some(due_time) = next_due_time {
+                if due_time > local_time {
+                    break;
+                }
+                due_times.push(due_time);
+                next_due_time = recurring_payment.schedule.next_due_time_after(due_time);
+            }
+
+            // All missed runs are recorded together, so they're saved only once
+            if recurring_payment.schedule.catch_up_policy == CatchUpPolicy::ExecuteLatest && due_times.len() > 1 {
+                let latest_due_time = due_times.split_off(due_times.len() - 1);
+                let skipped_runs = due_times
+                    .into_iter()
+                    .map(|due_time| RecurringPaymentRun {
+                        due_time,
+                        execution_time: local_time,
+                        status: RecurringPaymentRunStatus::Skipped,
+                        attempts: 0,
+                    })
+                    .collect();
+                due_times = latest_due_time;
+                if self
+                    .add_recurring_payment_runs(recurring_payment.id, skipped_runs)
+                    .await?
+                    != RecurringPaymentStatus::Active
+                {
+                    continue;
+                }
+            }
+
+            for due_time in due_times {
+                log::debug!(
+                    "[RECURRING_PAYMENT] executing run {due_time} of recurring payment {}",
+                    recurring_payment.id
+                );
+                let previous_attempts = recurring_payment
+                    .runs
+                    .iter()
+                    .find(|run| run.due_time == due_time && matches!(run.status, RecurringPaymentRunStatus::Failed(_)))
+                    .map_or(0, |run| run.attempts);
+                // The idempotency key is unique for every run, so a run can't be sent twice, also not when it's retried
+                let status = match self
+                    .send_output_options(
+                        &recurring_payment.outputs,
+                        &recurring_payment.options,
+                        format!(
+                            "{RESERVED_IDEMPOTENCY_KEY_PREFIX}recurring-payment-{}-{due_time}",
+                            recurring_payment.id
+                        ),
+                    )
+                    .await
+                {
+                    Ok(transaction) => {
+                        let status = RecurringPaymentRunStatus::Executed(transaction.transaction_id);
+                        transactions.push(transaction);
+                        status
+                    }
+                    Err(err) => RecurringPaymentRunStatus::Failed(err.to_string()),
+                };
+
+                let run = RecurringPaymentRun {
+                    due_time,
+                    execution_time: local_time,
+                    status,
+                    attempts: previous_attempts + 1,
+                };
+                let retry = run.will_be_retried();
+                if self.add_recurring_payment_runs(recurring_payment.id, vec![run]).await?
+                    != RecurringPaymentStatus::Active
+                    || retry
+                {
+                    break;
+                }
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// Store the runs and update the next due time and status with a single save, returns the new status. A failed run
+    /// replaces the previous attempt of the same run and the next due time stays until it's not retried anymore
+    async fn add_recurring_payment_runs(
+        &self,
+        payment_id: u32,
+        runs: Vec<RecurringPaymentRun>,
+    ) -> crate::Result<RecurringPaymentStatus> {
+        let mut account = self.write().await;
+        let recurring_payment = account
+            .recurring_payments
+            .get_mut(&payment_id)
+            .ok_or(Error::RecurringPaymentNotFound(payment_id))?;
+
+        for run in &runs {
+            recurring_payment
+                .runs
+                .retain(|existing_run| existing_run.due_time != run.due_time);
+            recurring_payment.runs.push(run.clone());
+            // The payment could have been cancelled while the run was sent
+            if recurring_payment.status != RecurringPaymentStatus::Active {
+                continue;
+            }
+            if !run.will_be_retried() {
+                recurring_payment.next_due_time = recurring_payment.schedule.next_due_time_after(run.due_time);
+            }
+
+            let executed_runs = recurring_payment
+                .runs
+                .iter()
+                .filter(|run| matches!(run.status, RecurringPaymentRunStatus::Executed(_)))
+                .count();
+            let max_count_reached = recurring_payment
+                .schedule
+                .max_count
+                .map_or(false, |max_count| executed_runs >= max_count as usize);
+            if max_count_reached || recurring_payment.next_due_time.is_none() {
+                recurring_payment.status = RecurringPaymentStatus::Completed;
+                recurring_payment.next_due_time = None;
+            }
+        }
+        let status = recurring_payment.status;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        drop(account);
+
+        #[cfg(feature = "events")]
+        {
+            let account_index = self.read().await.index;
+            let mut event_emitter = self.event_emitter.lock().await;
+            for run in runs {
+                event_emitter.emit(
+                    account_index,
+                    WalletEvent::RecurringPayment(RecurringPaymentEvent { payment_id, run }),
+                );
+            }
+        }
+
+        Ok(status)
+    }
+}
+
+// Unix time of the day in the month with the provided seconds since midnight
+fn unix_time_of_day(year: i64, month: u32, day: u8, seconds_of_day: u64) -> u64 {
+    days_from_civil(year, month, day as u32) as u64 * SECONDS_PER_DAY + seconds_of_day
+}
+
+// Days since 1970-01-01 for a date in the proleptic Gregorian calendar
+// Algorithm from http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 };
+    let era = era / 400;
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+// Date in the proleptic Gregorian calendar for the days since 1970-01-01
+// Algorithm from http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = if days >= 0 { days } else { days - 146096 };
+    let era = era / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let month = month as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11017), (2000, 3, 1));
+        assert_eq!(civil_from_days(19782), (2024, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        for days in [-719468, -1, 0, 11017, 19706, 19782, 50000] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn next_due_time() {
+        let mut schedule = RecurringPaymentSchedule {
+            interval: RecurringPaymentInterval::DayOfMonth(15),
+            // 2023-12-15 08:30 UTC
+            start_time: 1_702_629_000,
+            end_time: None,
+            max_count: None,
+            catch_up_policy: CatchUpPolicy::ExecuteAll,
+        };
+        assert_eq!(schedule.first_due_time(), Some(1_702_629_000));
+        // The year changes and the time of the day is kept
+        assert_eq!(schedule.next_due_time_after(1_702_629_000), Some(1_705_307_400));
+
+        // A start time after the day in the month starts in the next month
+        schedule.start_time = 1_702_629_000 + SECONDS_PER_DAY as u32;
+        assert_eq!(schedule.first_due_time(), Some(1_705_307_400));
+
+        schedule.end_time = Some(1_705_307_399);
+        assert_eq!(schedule.first_due_time(), None);
+        assert!(schedule.validate().is_err());
+
+        schedule.interval = RecurringPaymentInterval::Days(2);
+        schedule.end_time = None;
+        assert_eq!(
+            schedule.next_due_time_after(schedule.start_time),
+            Some(schedule.start_time + 2 * SECONDS_PER_DAY as u32)
+        );
+
+        schedule.interval = RecurringPaymentInterval::DayOfMonth(29);
+        assert!(schedule.validate().is_err());
+    }
+}
@@ -0,0 +1,392 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use iota_client::bee_block::{output::OutputId, payload::transaction::TransactionId};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::events::types::{ScheduledPaymentEvent, WalletEvent};
+use crate::{
+    account::{
+        handle::AccountHandle,
+        operations::transaction::{
+            prepare_output::{OutputOptions, OutputOptionsDto},
+            RESERVED_IDEMPOTENCY_KEY_PREFIX,
+        },
+        types::Transaction,
+        TransactionOptions,
+    },
+    Error,
+};
+
+/// A payment that will be sent once it's due, see
+/// [AccountHandle.execute_due_scheduled_payments()](crate::account::handle::AccountHandle.execute_due_scheduled_payments)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledPayment {
+    /// Id of the payment, unique per account
+    pub id: u32,
+    /// The outputs that will be sent
+    pub outputs: Vec<OutputOptions>,
+    /// Options for the transaction, without idempotency key, because it's set by the scheduler
+    #[serde(default)]
+    pub options: Option<TransactionOptions>,
+    /// When the payment should be sent
+    pub trigger: ScheduledPaymentTrigger,
+    /// Current status of the payment
+    pub status: ScheduledPaymentStatus,
+    /// Number of times the payment got edited, part of the idempotency key, so an edited payment isn't mistaken for
+    /// a transaction of a previous attempt
+    #[serde(default)]
+    pub revision: u32,
+    /// Creation time in milliseconds
+    pub timestamp: u128,
+}
+
+/// Condition after which a scheduled payment is due
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum ScheduledPaymentTrigger {
+    /// Unix time in seconds after which the payment will be sent
+    UnixTime(u32),
+    /// Output of the account with a timelock, the payment will be sent once the timelock expired. The payment spends
+    /// the output, so its amount together with the custom inputs of the options needs to cover the payment
+    TimelockExpired(OutputId),
+}
+
+/// The status of a scheduled payment
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum ScheduledPaymentStatus {
+    /// Waiting to be sent
+    Pending,
+    /// Sent in the transaction with this id
+    Executed(TransactionId),
+    /// Sending failed with this error, editing the payment will schedule it again
+    Failed(String),
+    /// Cancelled by the user
+    Cancelled,
+}
+
+/// Dto for a scheduled payment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledPaymentDto {
+    /// Id of the payment, unique per account
+    pub id: u32,
+    /// The outputs that will be sent
+    pub outputs: Vec<OutputOptionsDto>,
+    /// Options for the transaction
+    pub options: Option<TransactionOptions>,
+    /// When the payment should be sent
+    pub trigger: ScheduledPaymentTrigger,
+    /// Current status of the payment
+    pub status: ScheduledPaymentStatus,
+    /// Number of times the payment got edited
+    pub revision: u32,
+    /// Creation time in milliseconds
+    pub timestamp: String,
+}
+
+impl From<&ScheduledPayment> for ScheduledPaymentDto {
+    fn from(value: &ScheduledPayment) -> Self {
+        Self {
+            id: value.id,
+            outputs: value.outputs.iter().map(OutputOptionsDto::from).collect(),
+            options: value.options.clone(),
+            trigger: value.trigger,
+            status: value.status.clone(),
+            revision: value.revision,
+            timestamp: value.timestamp.to_string(),
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Schedule a payment, it will be sent by the background syncing or
+    /// [AccountHandle.execute_due_scheduled_payments()](crate::account::handle::AccountHandle.execute_due_scheduled_payments)
+    /// once the trigger is reached
+    /// ```ignore
+    /// let outputs = vec![OutputOptions {
+    ///     recipient_address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+    ///     amount: 1_000_000,
+    ///     assets: None,
+    ///     features: None,
+    ///     unlocks: None,
+    ///     storage_deposit: None,
+    /// }];
+    ///
+    /// let payment = account_handle
+    ///     .schedule_payment(outputs, ScheduledPaymentTrigger::UnixTime(1_700_000_000), None)
+    ///     .await?;
+    /// println!("Scheduled payment: {}", payment.id);
+    /// ```
+    pub async fn schedule_payment(
+        &self,
+        outputs: Vec<OutputOptions>,
+        trigger: ScheduledPaymentTrigger,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<ScheduledPayment> {
+        log::debug!("[SCHEDULED_PAYMENT] schedule_payment");
+        self.validate_scheduled_payment(&outputs, &trigger, &options).await?;
+
+        let mut account = self.write().await;
+        let id = account.scheduled_payments.keys().max().map_or(0, |id| id + 1);
+        let scheduled_payment = ScheduledPayment {
+            id,
+            outputs,
+            options,
+            trigger,
+            status: ScheduledPaymentStatus::Pending,
+            revision: 0,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_millis(),
+        };
+        account.scheduled_payments.insert(id, scheduled_payment.clone());
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+
+        Ok(scheduled_payment)
+    }
+
+    /// Get a scheduled payment by its id
+    pub async fn get_scheduled_payment(&self, payment_id: u32) -> Option<ScheduledPayment> {
+        let account = self.read().await;
+        account.scheduled_payments.get(&payment_id).cloned()
+    }
+
+    /// Returns all scheduled payments of the account, ordered by their id
+    pub async fn list_scheduled_payments(&self) -> Vec<ScheduledPayment> {
+        let account = self.read().await;
+        let mut scheduled_payments = account.scheduled_payments.values().cloned().collect::<Vec<_>>();
+        scheduled_payments.sort_by_key(|scheduled_payment| scheduled_payment.id);
+        scheduled_payments
+    }
+
+    /// Replace the outputs, trigger and options of a pending or failed scheduled payment, a failed payment will be
+    /// pending again afterwards. The payment gets a new idempotency key, so it's not mistaken for a transaction that
+    /// was created before the edit
+    pub async fn edit_scheduled_payment(
+        &self,
+        payment_id: u32,
+        outputs: Vec<OutputOptions>,
+        trigger: ScheduledPaymentTrigger,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<ScheduledPayment> {
+        log::debug!("[SCHEDULED_PAYMENT] edit_scheduled_payment {payment_id}");
+        self.validate_scheduled_payment(&outputs, &trigger, &options).await?;
+
+        let mut account = self.write().await;
+        let scheduled_payment = account
+            .scheduled_payments
+            .get_mut(&payment_id)
+            .ok_or(Error::ScheduledPaymentNotFound(payment_id))?;
+        if !matches!(
+            scheduled_payment.status,
+            ScheduledPaymentStatus::Pending | ScheduledPaymentStatus::Failed(_)
+        ) {
+            return Err(Error::ScheduledPaymentNotPending(payment_id));
+        }
+        scheduled_payment.outputs = outputs;
+        scheduled_payment.trigger = trigger;
+        scheduled_payment.options = options;
+        scheduled_payment.status = ScheduledPaymentStatus::Pending;
+        scheduled_payment.revision += 1;
+        let scheduled_payment = scheduled_payment.clone();
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+
+        Ok(scheduled_payment)
+    }
+
+    /// Cancel a pending or failed scheduled payment, it stays in the list with the status `Cancelled`
+    pub async fn cancel_scheduled_payment(&self, payment_id: u32) -> crate::Result<ScheduledPayment> {
+        log::debug!("[SCHEDULED_PAYMENT] cancel_scheduled_payment {payment_id}");
+        let mut account = self.write().await;
+        let scheduled_payment = account
+            .scheduled_payments
+            .get_mut(&payment_id)
+            .ok_or(Error::ScheduledPaymentNotFound(payment_id))?;
+        if !matches!(
+            scheduled_payment.status,
+            ScheduledPaymentStatus::Pending | ScheduledPaymentStatus::Failed(_)
+        ) {
+            return Err(Error::ScheduledPaymentNotPending(payment_id));
+        }
+        scheduled_payment.status = ScheduledPaymentStatus::Cancelled;
+        let scheduled_payment = scheduled_payment.clone();
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+
+        Ok(scheduled_payment)
+    }
+
+    /// Send all pending scheduled payments which are due, called by the background syncing after the account got
+    /// synced. Failed payments get the status `Failed` and are not retried until they get edited.
+    pub async fn execute_due_scheduled_payments(&self) -> crate::Result<Vec<Transaction>> {
+        let local_time = self.client.get_time_checked().await?;
+
+        let account = self.read().await;
+        let mut due_payments = Vec::new();
+        for scheduled_payment in account.scheduled_payments.values() {
+            if scheduled_payment.status != ScheduledPaymentStatus::Pending {
+                continue;
+            }
+            match scheduled_payment.trigger {
+                ScheduledPaymentTrigger::UnixTime(unix_time) => {
+                    if unix_time <= local_time {
+                        due_payments.push((scheduled_payment.clone(), None));
+                    }
+                }
+                ScheduledPaymentTrigger::TimelockExpired(output_id) => match account.unspent_outputs.get(&output_id) {
+                    Some(output_data) => {
+                        let is_time_locked = output_data
+                            .output
+                            .unlock_conditions()
+                            .map_or(false, |unlock_conditions| unlock_conditions.is_time_locked(local_time));
+                        if !is_time_locked {
+                            due_payments.push((scheduled_payment.clone(), None));
+                        }
+                    }
+                    // The output got spent before the timelock expired, so we can't wait for it anymore
+                    None => due_payments.push((
+                        scheduled_payment.clone(),
+                        Some(format!("output {output_id} is not unspent anymore")),
+                    )),
+                },
+            }
+        }
+        drop(account);
+        due_payments.sort_by_key(|(scheduled_payment, _)| scheduled_payment.id);
+
+        let mut transactions = Vec::new();
+        for (scheduled_payment, error) in due_payments {
+            log::debug!(
+                "[SCHEDULED_PAYMENT] executing scheduled payment {}",
+                scheduled_payment.id
+            );
+            let options = match scheduled_payment.trigger {
+                // The timelocked output is spent by the payment, together with the custom inputs of the options
+                ScheduledPaymentTrigger::TimelockExpired(output_id) => {
+                    let mut options = scheduled_payment.options.clone().unwrap_or_default();
+                    let custom_inputs = options.custom_inputs.get_or_insert_with(Vec::new);
+                    if !custom_inputs.contains(&output_id) {
+                        custom_inputs.push(output_id);
+                    }
+                    Some(options)
+                }
+                ScheduledPaymentTrigger::UnixTime(_) => scheduled_payment.options.clone(),
+            };
+            let result = match error {
+                Some(error) => Err(error),
+                // A fixed idempotency key per revision prevents sending the payment twice if the process stops before
+                // the status is updated
+                None => self
+                    .send_output_options(
+                        &scheduled_payment.outputs,
+                        &options,
+                        format!(
+                            "{RESERVED_IDEMPOTENCY_KEY_PREFIX}scheduled-payment-{}-{}",
+                            scheduled_payment.id, scheduled_payment.revision
+                        ),
+                    )
+                    .await
+                    .map_err(|e| e.to_string()),
+            };
+            let status = match result {
+                Ok(transaction) => {
+                    let status = ScheduledPaymentStatus::Executed(transaction.transaction_id);
+                    transactions.push(transaction);
+                    status
+                }
+                Err(error) => {
+                    log::debug!(
+                        "[SCHEDULED_PAYMENT] scheduled payment {} failed: {error}",
+                        scheduled_payment.id
+                    );
+                    ScheduledPaymentStatus::Failed(error)
+                }
+            };
+            self.set_scheduled_payment_status(scheduled_payment.id, status).await?;
+        }
+
+        Ok(transactions)
+    }
+
+    /// Prepare the outputs and send them with the provided idempotency key
+    pub(crate) async fn send_output_options(
+        &self,
+        output_options: &[OutputOptions],
+        options: &Option<TransactionOptions>,
+        idempotency_key: String,
+    ) -> crate::Result<Transaction> {
+        // The key is passed separately, because reserved keys are rejected in the options. Scheduled payments can't have
+        // a key in their options, see [`AccountHandle::validate_scheduled_payment()`]
+        let options = Some(TransactionOptions {
+            idempotency_key: None,
+            ..options.clone().unwrap_or_default()
+        });
+
+        let mut outputs = Vec::new();
+        for output_options in output_options {
+            outputs.push(self.prepare_output(output_options.clone(), options.clone()).await?);
+        }
+
+        let prepare_transaction = self.prepare_transaction(outputs, options);
+        self.prepare_sign_and_submit_idempotent_transaction(Some(idempotency_key), prepare_transaction)
+            .await
+    }
+
+    async fn set_scheduled_payment_status(&self, payment_id: u32, status: ScheduledPaymentStatus) -> crate::Result<()> {
+        let mut account = self.write().await;
+        if let Some(scheduled_payment) = account.scheduled_payments.get_mut(&payment_id) {
+            scheduled_payment.status = status.clone();
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        drop(account);
+
+        #[cfg(feature = "events")]
+        self.event_emitter.lock().await.emit(
+            self.read().await.index,
+            WalletEvent::ScheduledPayment(ScheduledPaymentEvent { payment_id, status }),
+        );
+
+        Ok(())
+    }
+
+    async fn validate_scheduled_payment(
+        &self,
+        outputs: &[OutputOptions],
+        trigger: &ScheduledPaymentTrigger,
+        options: &Option<TransactionOptions>,
+    ) -> crate::Result<()> {
+        if outputs.is_empty() {
+            return Err(Error::MissingParameter("outputs"));
+        }
+        if options
+            .as_ref()
+            .map_or(false, |options| options.idempotency_key.is_some())
+        {
+            return Err(Error::InvalidScheduledPayment(
+                "the idempotency key is set by the scheduler and can't be provided in the options".to_string(),
+            ));
+        }
+        if let ScheduledPaymentTrigger::TimelockExpired(output_id) = trigger {
+            let account = self.read().await;
+            let has_timelock = account
+                .unspent_outputs
+                .get(output_id)
+                .and_then(|output_data| output_data.output.unlock_conditions())
+                .map_or(false, |unlock_conditions| unlock_conditions.timelock().is_some());
+            if !has_timelock {
+                return Err(Error::InvalidScheduledPayment(format!(
+                    "output {output_id} is not an unspent output with a timelock"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
@@ -274,10 +274,10 @@ impl AccountHandle {
                     tokio::spawn(async move {
                         let mut output_ids = Vec::new();
                         // Get nft outputs
-                        let nft_output_ids = client
+                        let owned_nft_output_ids = client
                             .nft_output_ids(vec![QueryParameter::Address(bech32_address.to_string())])
                             .await?;
-                        output_ids.extend(nft_output_ids.clone().into_iter());
+                        output_ids.extend(owned_nft_output_ids.clone().into_iter());
 
                         // Get outputs where the address is in the storage deposit return unlock condition
                         let nft_output_ids = client
@@ -293,13 +293,13 @@ impl AccountHandle {
                             .await?;
                         output_ids.extend(nft_output_ids.clone().into_iter());
 
-                        // get basic outputs that can be controlled by an nft output
+                        // get basic and nft outputs that can be controlled by an nft output we own
                         let (mut nft_output_responses, loaded_output_responses) =
-                            account_handle.get_outputs(nft_output_ids, false).await?;
+                            account_handle.get_outputs(owned_nft_output_ids, false).await?;
                         nft_output_responses.extend(loaded_output_responses.into_iter());
-                        let nft_basic_output_ids =
-                            get_basic_outputs_for_nft_outputs(&client, nft_output_responses, bech32_hrp).await?;
-                        output_ids.extend(nft_basic_output_ids.into_iter());
+                        let nft_address_output_ids =
+                            get_outputs_for_nft_outputs(&client, nft_output_responses, bech32_hrp).await?;
+                        output_ids.extend(nft_address_output_ids.into_iter());
                         Ok(output_ids)
                     })
                     .await
@@ -316,26 +316,27 @@ impl AccountHandle {
                     let client = client.clone();
                     tokio::spawn(async move {
                         let mut output_ids = Vec::new();
-                        // Get alias outputs
-                        let alias_output_ids = client
-                            .alias_output_ids(vec![
-                                QueryParameter::StateController(bech32_address.to_string()),
-                                QueryParameter::Governor(bech32_address.to_string()),
-                            ])
+                        // Get alias outputs, the address can be the state controller, the governor or both
+                        let mut alias_output_ids = client
+                            .alias_output_ids(vec![QueryParameter::StateController(bech32_address.to_string())])
                             .await?;
+                        for output_id in client
+                            .alias_output_ids(vec![QueryParameter::Governor(bech32_address.to_string())])
+                            .await?
+                        {
+                            if !alias_output_ids.contains(&output_id) {
+                                alias_output_ids.push(output_id);
+                            }
+                        }
                         output_ids.extend(alias_output_ids.clone().into_iter());
 
-                        // get possible foundries and basic outputs that can be controlled by an alias outputs
+                        // get possible foundries, basic and nft outputs that can be controlled by an alias outputs
                         let (mut alias_output_responses, loaded_output_responses) =
                             account_handle.get_outputs(alias_output_ids, false).await?;
                         alias_output_responses.extend(loaded_output_responses.into_iter());
-                        let alias_foundry_and_basic_output_ids = get_foundry_and_basic_outputs_for_alias_outputs(
-                            &client,
-                            alias_output_responses,
-                            bech32_hrp,
-                        )
-                        .await?;
-                        output_ids.extend(alias_foundry_and_basic_output_ids.into_iter());
+                        let alias_address_output_ids =
+                            get_outputs_for_alias_outputs(&client, alias_output_responses, bech32_hrp).await?;
+                        output_ids.extend(alias_address_output_ids.into_iter());
                         crate::Result::Ok(output_ids)
                     })
                     .await
@@ -356,38 +357,46 @@ impl AccountHandle {
     }
 }
 
-// Get basic outputs that have the [`NftAddress`] from nft outputs in their [`AddressUnlockCondition`]
-async fn get_basic_outputs_for_nft_outputs(
+// Get basic and nft outputs that have the [`NftAddress`] from nft outputs in their [`AddressUnlockCondition`]
+// Only one level is synced, outputs owned by an nft that is owned by another nft are not included
+async fn get_outputs_for_nft_outputs(
     client: &Client,
     nft_output_responses: Vec<OutputResponse>,
     bech32_hrp: String,
 ) -> crate::Result<Vec<OutputId>> {
-    let mut nft_basic_output_ids = Vec::new();
+    let mut nft_address_output_ids = Vec::new();
     for nft_output_response in nft_output_responses {
         let output = Output::try_from(&nft_output_response.output)?;
         if let Output::Nft(nft_output) = output {
             let transaction_id = TransactionId::from_str(&nft_output_response.metadata.transaction_id)?;
             let output_id = OutputId::new(transaction_id, nft_output_response.metadata.output_index)?;
             let nft_address = Address::Nft(NftAddress::new(nft_output.nft_id().or_from_output_id(output_id)));
-            nft_basic_output_ids.extend(
+            nft_address_output_ids.extend(
                 client
                     .basic_output_ids(vec![QueryParameter::Address(nft_address.to_bech32(bech32_hrp.clone()))])
                     .await?
                     .into_iter(),
             );
+            nft_address_output_ids.extend(
+                client
+                    .nft_output_ids(vec![QueryParameter::Address(nft_address.to_bech32(bech32_hrp.clone()))])
+                    .await?
+                    .into_iter(),
+            );
         }
     }
-    Ok(nft_basic_output_ids)
+    Ok(nft_address_output_ids)
 }
 
-// Get basic outputs that have the [`AliasAddress`] from alias outputs in their [`AddressUnlockCondition`]
-async fn get_foundry_and_basic_outputs_for_alias_outputs(
+// Get foundry outputs of alias outputs and basic and nft outputs that have the [`AliasAddress`] from alias outputs in
+// their [`AddressUnlockCondition`]
+async fn get_outputs_for_alias_outputs(
     client: &Client,
     alias_output_responses: Vec<OutputResponse>,
     bech32_hrp: String,
 ) -> crate::Result<Vec<OutputId>> {
     let mut foundry_output_ids = Vec::new();
-    let mut alias_basic_output_ids = Vec::new();
+    let mut alias_address_output_ids = Vec::new();
     for alias_output_response in alias_output_responses {
         let output = Output::try_from(&alias_output_response.output)?;
         if let Output::Alias(alias_output) = output {
@@ -403,7 +412,7 @@ async fn get_foundry_and_basic_outputs_for_alias_outputs(
                     .await?
                     .into_iter(),
             );
-            alias_basic_output_ids.extend(
+            alias_address_output_ids.extend(
                 client
                     .basic_output_ids(vec![QueryParameter::Address(
                         alias_address.to_bech32(bech32_hrp.clone()),
@@ -411,9 +420,17 @@ async fn get_foundry_and_basic_outputs_for_alias_outputs(
                     .await?
                     .into_iter(),
             );
+            alias_address_output_ids.extend(
+                client
+                    .nft_output_ids(vec![QueryParameter::Address(
+                        alias_address.to_bech32(bech32_hrp.clone()),
+                    )])
+                    .await?
+                    .into_iter(),
+            );
         }
     }
     // Add output ids together
-    foundry_output_ids.extend(alias_basic_output_ids.into_iter());
+    foundry_output_ids.extend(alias_address_output_ids.into_iter());
     Ok(foundry_output_ids)
 }
@@ -0,0 +1,51 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use iota_client::bee_block::output::Output;
+
+use crate::account::{handle::AccountHandle, types::Irc30Metadata};
+
+impl AccountHandle {
+    /// Get the foundries of native tokens in the account, for which we don't have the metadata yet, and cache their
+    /// IRC30 metadata. The metadata is an immutable feature, so it only needs to be requested once per token.
+    pub(crate) async fn sync_native_token_metadata(&self) -> crate::Result<()> {
+        log::debug!("[SYNC] sync native token metadata");
+        let account = self.read().await;
+        let mut token_ids = HashSet::new();
+        for output_data in account.unspent_outputs.values() {
+            if let Some(native_tokens) = output_data.output.native_tokens() {
+                for native_token in native_tokens.iter() {
+                    if !account.native_token_metadata.contains_key(native_token.token_id()) {
+                        token_ids.insert(*native_token.token_id());
+                    }
+                }
+            }
+        }
+        drop(account);
+
+        if token_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut native_token_metadata = HashMap::new();
+        for token_id in token_ids {
+            match self.get_foundry_output(token_id).await {
+                Ok(Output::Foundry(foundry_output)) => {
+                    native_token_metadata.insert(token_id, Irc30Metadata::from_foundry_output(&foundry_output));
+                }
+                Ok(_) => {}
+                // Not cached, so it will be tried again on the next sync
+                Err(err) => log::debug!("[SYNC] couldn't get foundry output for {}: {}", token_id, err),
+            }
+        }
+
+        let mut account = self.write().await;
+        account.native_token_metadata.extend(native_token_metadata);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod addresses;
+pub(crate) mod foundries;
 pub mod options;
 pub(crate) mod outputs;
 pub(crate) mod transactions;
@@ -97,12 +98,22 @@ impl AccountHandle {
             self.sync_pending_transactions().await?;
         };
 
+        // Get the IRC30 metadata of new native tokens, so the balance can show it
+        self.sync_native_token_metadata().await?;
+
+        // Match new incoming outputs to the invoices
+        self.update_invoices().await?;
+
         let account_balance = self.balance().await?;
         // update last_synced mutex
         let time_now = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards")
             .as_millis();
+        // The history is only informational, so the sync shouldn't fail because of it
+        if let Err(err) = self.record_balance_snapshot(&account_balance, time_now).await {
+            log::warn!("[SYNC] couldn't record the balance snapshot: {}", err);
+        }
         *last_synced = time_now;
         log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
         Ok(account_balance)
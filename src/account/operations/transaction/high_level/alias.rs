@@ -0,0 +1,337 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::{
+    address::Address,
+    output::{
+        feature::{Feature, MetadataFeature},
+        unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition, UnlockCondition},
+        AliasId, AliasOutput, AliasOutputBuilder, ByteCost, Output, OutputId,
+    },
+    payload::transaction::TransactionEssence,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    account::{
+        handle::AccountHandle,
+        types::{Transaction, TransactionDto},
+        TransactionOptions,
+    },
+    Error,
+};
+
+/// Options for [`AccountHandle::create_alias()`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AliasOptions {
+    /// Bech32 encoded address which will be the state controller. Default will use the first address of the account
+    #[serde(rename = "stateControllerAddress")]
+    pub state_controller_address: Option<String>,
+    /// Bech32 encoded address which will be the governor. Default will use the first address of the account
+    #[serde(rename = "governorAddress")]
+    pub governor_address: Option<String>,
+    /// Immutable alias metadata
+    #[serde(rename = "immutableMetadata")]
+    pub immutable_metadata: Option<Vec<u8>>,
+    /// Alias metadata, can be changed by the governor
+    pub metadata: Option<Vec<u8>>,
+    /// State metadata, can be changed by the state controller
+    #[serde(rename = "stateMetadata")]
+    pub state_metadata: Option<Vec<u8>>,
+}
+
+/// The result of creating an alias
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAliasTransaction {
+    pub alias_id: AliasId,
+    pub transaction: Transaction,
+}
+
+/// Dto for CreateAliasTransaction
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAliasTransactionDto {
+    pub alias_id: AliasId,
+    pub transaction: TransactionDto,
+}
+
+impl From<&CreateAliasTransaction> for CreateAliasTransactionDto {
+    fn from(value: &CreateAliasTransaction) -> Self {
+        Self {
+            alias_id: value.alias_id,
+            transaction: TransactionDto::from(&value.transaction),
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Function to create a new alias output.
+    /// Addresses need to be Bech32 encoded
+    /// ```ignore
+    /// let alias_options = AliasOptions {
+    ///     state_metadata: Some(b"some state".to_vec()),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let transaction = account_handle.create_alias(alias_options, None).await?;
+    /// println!("Alias id: {}", transaction.alias_id);
+    /// ```
+    pub async fn create_alias(
+        &self,
+        alias_options: AliasOptions,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<CreateAliasTransaction> {
+        log::debug!("[TRANSACTION] create_alias");
+        let byte_cost_config = self.client.get_byte_cost_config().await?;
+
+        let default_address = self
+            .list_addresses()
+            .await?
+            .first()
+            .ok_or(Error::FailedToGetRemainder)?
+            .address
+            .inner;
+        let state_controller_address = match alias_options.state_controller_address {
+            Some(address) => Address::try_from_bech32(&address)?.1,
+            None => default_address,
+        };
+        let governor_address = match alias_options.governor_address {
+            Some(address) => Address::try_from_bech32(&address)?.1,
+            None => default_address,
+        };
+
+        // AliasId needs to be set to 0 for the creation
+        let mut alias_builder =
+            AliasOutputBuilder::new_with_minimum_storage_deposit(byte_cost_config, AliasId::null())?
+                .with_state_index(0)
+                .with_foundry_counter(0)
+                .add_unlock_condition(UnlockCondition::StateControllerAddress(
+                    StateControllerAddressUnlockCondition::new(state_controller_address),
+                ))
+                .add_unlock_condition(UnlockCondition::GovernorAddress(GovernorAddressUnlockCondition::new(
+                    governor_address,
+                )));
+        if let Some(state_metadata) = alias_options.state_metadata {
+            alias_builder = alias_builder.with_state_metadata(state_metadata);
+        }
+        if let Some(metadata) = alias_options.metadata {
+            alias_builder = alias_builder.add_feature(Feature::Metadata(MetadataFeature::new(metadata)?));
+        }
+        if let Some(immutable_metadata) = alias_options.immutable_metadata {
+            alias_builder =
+                alias_builder.add_immutable_feature(Feature::Metadata(MetadataFeature::new(immutable_metadata)?));
+        }
+
+        let transaction = self.send(vec![alias_builder.finish_output()?], options).await?;
+
+        // The new alias is the only alias output with a null id, its id is derived from the output id
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let output_index = essence
+            .outputs()
+            .iter()
+            .position(|output| matches!(output, Output::Alias(alias_output) if alias_output.alias_id().is_null()))
+            .ok_or_else(|| Error::MintingFailed("No alias output in the transaction".to_string()))?;
+        let alias_id =
+            AliasId::null().or_from_output_id(OutputId::new(transaction.transaction_id, output_index as u16)?);
+
+        Ok(CreateAliasTransaction { alias_id, transaction })
+    }
+
+    /// Function to update the state metadata of an alias with a state transition, the state controller needs to be
+    /// an address of the account. If the new state metadata requires a higher storage deposit, the additional amount
+    /// will be taken from the account.
+    pub async fn update_alias_state_metadata(
+        &self,
+        alias_id: AliasId,
+        state_metadata: Vec<u8>,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<Transaction> {
+        log::debug!("[TRANSACTION] update_alias_state_metadata");
+        let alias_output = self.unspent_alias_output(alias_id).await?;
+        self.check_alias_controller(alias_output.state_controller_address())
+            .await?;
+
+        let byte_cost_config = self.client.get_byte_cost_config().await?;
+        let alias_output = AliasOutputBuilder::from(&alias_output)
+            .with_alias_id(alias_id)
+            .with_state_index(alias_output.state_index() + 1)
+            .with_state_metadata(state_metadata)
+            .finish()?;
+
+        // The amount can only change in a state transition
+        let minimum_storage_deposit = Output::Alias(alias_output.clone()).byte_cost(&byte_cost_config);
+        let alias_output = if alias_output.amount() < minimum_storage_deposit {
+            AliasOutputBuilder::from(&alias_output)
+                .with_amount(minimum_storage_deposit)?
+                .finish_output()?
+        } else {
+            Output::Alias(alias_output)
+        };
+
+        self.send(vec![alias_output], options).await
+    }
+
+    /// Function to set or remove the metadata feature of an alias with a governance transition, the governor needs to
+    /// be an address of the account. The amount of the alias can't change in a governance transition, so it needs to
+    /// cover the storage deposit for the new metadata already, otherwise an error is returned before signing.
+    pub async fn update_alias_governor_metadata(
+        &self,
+        alias_id: AliasId,
+        metadata: Option<Vec<u8>>,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<Transaction> {
+        log::debug!("[TRANSACTION] update_alias_governor_metadata");
+        let alias_output = self.unspent_alias_output(alias_id).await?;
+        self.check_alias_controller(alias_output.governor_address()).await?;
+
+        let mut features = alias_output
+            .features()
+            .iter()
+            .filter(|feature| !matches!(feature, Feature::Metadata(_)))
+            .cloned()
+            .collect::<Vec<Feature>>();
+        if let Some(metadata) = metadata {
+            features.push(Feature::Metadata(MetadataFeature::new(metadata)?));
+        }
+        let alias_output = AliasOutputBuilder::from(&alias_output)
+            .with_alias_id(alias_id)
+            .with_features(features)
+            .finish_output()?;
+        self.check_governance_storage_deposit(alias_id, &alias_output).await?;
+
+        self.send(vec![alias_output], options).await
+    }
+
+    /// Function to change the state controller of an alias with a governance transition, the governor needs to be an
+    /// address of the account.
+    /// Address needs to be Bech32 encoded
+    pub async fn set_alias_state_controller(
+        &self,
+        alias_id: AliasId,
+        address: String,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<Transaction> {
+        log::debug!("[TRANSACTION] set_alias_state_controller");
+        let (_bech32_hrp, address) = Address::try_from_bech32(&address)?;
+        let alias_output = self.unspent_alias_output(alias_id).await?;
+        self.set_alias_controllers(
+            alias_id,
+            &alias_output,
+            address,
+            *alias_output.governor_address(),
+            options,
+        )
+        .await
+    }
+
+    /// Function to change the governor of an alias with a governance transition, the current governor needs to be an
+    /// address of the account.
+    /// Address needs to be Bech32 encoded
+    pub async fn set_alias_governor(
+        &self,
+        alias_id: AliasId,
+        address: String,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<Transaction> {
+        log::debug!("[TRANSACTION] set_alias_governor");
+        let (_bech32_hrp, address) = Address::try_from_bech32(&address)?;
+        let alias_output = self.unspent_alias_output(alias_id).await?;
+        self.set_alias_controllers(
+            alias_id,
+            &alias_output,
+            *alias_output.state_controller_address(),
+            address,
+            options,
+        )
+        .await
+    }
+
+    /// Function to transfer an alias to another address, which will be the new state controller and governor. This is
+    /// a governance transition, so the governor needs to be an address of the account.
+    /// Address needs to be Bech32 encoded
+    pub async fn transfer_alias(
+        &self,
+        alias_id: AliasId,
+        address: String,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<Transaction> {
+        log::debug!("[TRANSACTION] transfer_alias");
+        let (_bech32_hrp, address) = Address::try_from_bech32(&address)?;
+        let alias_output = self.unspent_alias_output(alias_id).await?;
+        self.set_alias_controllers(alias_id, &alias_output, address, address, options)
+            .await
+    }
+
+    // Governance transition which replaces the state controller and governor address
+    async fn set_alias_controllers(
+        &self,
+        alias_id: AliasId,
+        alias_output: &AliasOutput,
+        state_controller_address: Address,
+        governor_address: Address,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<Transaction> {
+        self.check_alias_controller(alias_output.governor_address()).await?;
+
+        let alias_output = AliasOutputBuilder::from(alias_output)
+            .with_alias_id(alias_id)
+            .with_unlock_conditions(vec![
+                UnlockCondition::StateControllerAddress(StateControllerAddressUnlockCondition::new(
+                    state_controller_address,
+                )),
+                UnlockCondition::GovernorAddress(GovernorAddressUnlockCondition::new(governor_address)),
+            ])
+            .finish_output()?;
+        self.check_governance_storage_deposit(alias_id, &alias_output).await?;
+
+        self.send(vec![alias_output], options).await
+    }
+
+    // The amount can't change in a governance transition, so the existing amount needs to cover the storage deposit of
+    // the new output, otherwise the transaction would be rejected after signing
+    async fn check_governance_storage_deposit(&self, alias_id: AliasId, alias_output: &Output) -> crate::Result<()> {
+        let byte_cost_config = self.client.get_byte_cost_config().await?;
+        let required_storage_deposit = alias_output.byte_cost(&byte_cost_config);
+        if alias_output.amount() < required_storage_deposit {
+            return Err(Error::InsufficientStorageDeposit(
+                format!("alias {alias_id}"),
+                alias_output.amount(),
+                required_storage_deposit,
+            ));
+        }
+        Ok(())
+    }
+
+    // Get the current output of the alias
+    async fn unspent_alias_output(&self, alias_id: AliasId) -> crate::Result<AliasOutput> {
+        let account = self.read().await;
+        account
+            .unspent_outputs
+            .values()
+            .find_map(|output_data| match &output_data.output {
+                Output::Alias(alias_output)
+                    if alias_output.alias_id().or_from_output_id(output_data.output_id) == alias_id =>
+                {
+                    Some(alias_output.clone())
+                }
+                _ => None,
+            })
+            .ok_or(Error::AliasNotFoundInUnspentOutputs)
+    }
+
+    // Check that the state controller or governor needed for a transition is an address of the account
+    async fn check_alias_controller(&self, address: &Address) -> crate::Result<()> {
+        if self
+            .list_addresses()
+            .await?
+            .iter()
+            .any(|account_address| account_address.address.inner == *address)
+        {
+            Ok(())
+        } else {
+            let bech32_hrp = self.client.get_bech32_hrp().await?;
+            Err(Error::AddressNotFoundInAccount(address.to_bech32(bech32_hrp)))
+        }
+    }
+}
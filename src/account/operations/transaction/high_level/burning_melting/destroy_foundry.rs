@@ -87,7 +87,22 @@ impl AccountHandle {
         let mut transaction_ids = Vec::new();
         let foundries = foundry_ids.into_iter().collect::<Vec<_>>();
 
-        for foundry_ids in foundries.chunks(OUTPUT_COUNT_MAX as usize) {
+        for (chunk_index, foundry_ids) in foundries.chunks(OUTPUT_COUNT_MAX as usize).enumerate() {
+            // Every transaction gets its own idempotency key derived from the provided one
+            let options = options.clone().map(|mut options| {
+                options.idempotency_key = options
+                    .idempotency_key
+                    .map(|idempotency_key| format!("{idempotency_key}-{chunk_index}"));
+                options
+            });
+            // Foundries of an already sent chunk don't exist anymore, so the outputs can't be built again
+            if let Some(idempotency_key) = options.as_ref().and_then(|options| options.idempotency_key.as_ref()) {
+                if let Some(transaction) = self.get_transaction_by_idempotency_key(idempotency_key).await {
+                    transaction_ids.push(transaction.transaction_id);
+                    continue;
+                }
+            }
+
             let mut custom_inputs = Vec::new();
             let mut outputs = Vec::new();
             let mut included_aliases = HashSet::new();
@@ -131,7 +146,7 @@ impl AccountHandle {
                 custom_inputs.push(foundry_output_data.output_id);
             }
 
-            let options = match options.clone() {
+            let options = match options {
                 Some(mut options) => {
                     options.custom_inputs.replace(custom_inputs);
                     Some(options)
@@ -161,7 +176,7 @@ impl AccountHandle {
     }
 
     /// Find and return unspent `OutputData` for given `alias_id` and `foundry_id`
-    pub(super) async fn find_alias_and_foundry_output_data(
+    pub(crate) async fn find_alias_and_foundry_output_data(
         &self,
         alias_id: AliasId,
         foundry_id: FoundryId,
@@ -49,7 +49,7 @@ impl AccountHandle {
         Ok(address)
     }
 
-    async fn output_id_and_nft_output(&self, nft_id: NftId) -> crate::Result<(OutputId, NftOutput)> {
+    pub(crate) async fn output_id_and_nft_output(&self, nft_id: NftId) -> crate::Result<(OutputId, NftOutput)> {
         let account = self.read().await;
 
         let (output_id, nft_output) = account
@@ -70,7 +70,10 @@ impl AccountHandle {
         Ok((output_id, nft_output.clone()))
     }
 
-    async fn output_id_and_next_alias_output_state(&self, alias_id: AliasId) -> crate::Result<(OutputId, AliasOutput)> {
+    pub(crate) async fn output_id_and_next_alias_output_state(
+        &self,
+        alias_id: AliasId,
+    ) -> crate::Result<(OutputId, AliasOutput)> {
         let account = self.read().await;
 
         let (output_id, alias_output) = account
@@ -0,0 +1,127 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::{
+    address::Address,
+    output::{
+        unlock_condition::{AddressUnlockCondition, UnlockCondition},
+        AliasOutputBuilder, BasicOutputBuilder, FoundryId, FoundryOutputBuilder, NativeToken, Output,
+        SimpleTokenScheme, TokenId, TokenScheme,
+    },
+};
+use primitive_types::U256;
+
+use crate::{
+    account::{
+        handle::AccountHandle, operations::transaction::high_level::minting::mint_native_token::MintTokenTransaction,
+        TransactionOptions,
+    },
+    Error,
+};
+
+impl AccountHandle {
+    /// Function to mint additional native tokens with an existing foundry output, up to its maximum supply.
+    /// The controlling alias output and the foundry output are transitioned and the new tokens are sent to the
+    /// provided account address, or the first address of the account if none is provided.
+    /// Calls [AccountHandle.send()](crate::account::handle::AccountHandle.send) internally, the options can define the
+    /// RemainderValueStrategy.
+    /// Address needs to be Bech32 encoded
+    /// ```ignore
+    /// let token_id =
+    ///     TokenId::from_str("08e68f7616cd4948efebc6a77c4f93aed770ac53860100000000000000000000000000000000")?;
+    ///
+    /// let transaction = account_handle
+    ///     .increase_native_token_supply(token_id, U256::from(50), None, None)
+    ///     .await?;
+    /// println!("Transaction created: {}", transaction.transaction.transaction_id);
+    /// ```
+    pub async fn increase_native_token_supply(
+        &self,
+        token_id: TokenId,
+        increase_amount: U256,
+        account_address: Option<String>,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<MintTokenTransaction> {
+        log::debug!("[TRANSACTION] increase_native_token_supply");
+        if increase_amount.is_zero() {
+            return Err(Error::MintingFailed(
+                "Increase amount needs to be larger than 0".to_string(),
+            ));
+        }
+
+        let byte_cost_config = self.client.get_byte_cost_config().await?;
+
+        let account_addresses = self.list_addresses().await?;
+        // the address needs to be from the account, so the new tokens stay in the account
+        let receiving_address = match &account_address {
+            Some(bech32_address) => {
+                let (_bech32_hrp, address) = Address::try_from_bech32(&bech32_address)?;
+                if !account_addresses.iter().any(|addr| addr.address.inner == address) {
+                    return Err(Error::AddressNotFoundInAccount(bech32_address.to_string()));
+                }
+                address
+            }
+            None => {
+                account_addresses
+                    .first()
+                    .ok_or(Error::FailedToGetRemainder)?
+                    .address
+                    .inner
+            }
+        };
+
+        let foundry_id = FoundryId::from(token_id);
+        let alias_id = *foundry_id.alias_address().alias_id();
+
+        let (existing_alias_output_data, existing_foundry_output) = self
+            .find_alias_and_foundry_output_data(alias_id, foundry_id)
+            .await
+            .map(|(alias_data, foundry_data)| match foundry_data.output {
+                Output::Foundry(foundry_output) => (alias_data, foundry_output),
+                _ => unreachable!("We already checked it's a foundry output"),
+            })?;
+
+        let TokenScheme::Simple(token_scheme) = existing_foundry_output.token_scheme();
+        let new_minted_tokens = token_scheme
+            .minted_tokens()
+            .checked_add(increase_amount)
+            .ok_or_else(|| Error::MintingFailed("Minted tokens overflow".to_string()))?;
+        let new_circulating_supply = new_minted_tokens - token_scheme.melted_tokens();
+        if new_circulating_supply > *token_scheme.maximum_supply() {
+            return Err(Error::MintingFailed(format!(
+                "Circulating supply {} would exceed the maximum supply {}",
+                new_circulating_supply,
+                token_scheme.maximum_supply()
+            )));
+        }
+
+        if let Output::Alias(alias_output) = &existing_alias_output_data.output {
+            // Create the new alias output with the same features, just updated state_index
+            let alias_output = AliasOutputBuilder::from(alias_output)
+                .with_alias_id(alias_id)
+                .with_state_index(alias_output.state_index() + 1)
+                .finish_output()?;
+
+            let outputs = vec![
+                alias_output,
+                FoundryOutputBuilder::from(&existing_foundry_output)
+                    .with_token_scheme(TokenScheme::Simple(SimpleTokenScheme::new(
+                        new_minted_tokens,
+                        *token_scheme.melted_tokens(),
+                        *token_scheme.maximum_supply(),
+                    )?))
+                    .finish_output()?,
+                BasicOutputBuilder::new_with_minimum_storage_deposit(byte_cost_config)?
+                    .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(receiving_address)))
+                    .add_native_token(NativeToken::new(token_id, increase_amount)?)
+                    .finish_output()?,
+            ];
+            // Input selection will add the alias and foundry outputs as inputs, because outputs with the same ids exist
+            self.send(outputs, options)
+                .await
+                .map(|transaction| MintTokenTransaction { token_id, transaction })
+        } else {
+            unreachable!("We checked if it's an alias output before")
+        }
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::{
+    address::Address,
+    output::{AliasOutputBuilder, NftId, NftOutputBuilder, Output, OutputId, OUTPUT_COUNT_MAX},
+    payload::transaction::TransactionEssence,
+};
+use serde::Serialize;
+
+use crate::{
+    account::{
+        handle::AccountHandle,
+        operations::transaction::high_level::minting::mint_nfts::{build_nft_output, NftOptions},
+        types::{Transaction, TransactionDto},
+        SyncOptions, TransactionOptions,
+    },
+    Error,
+};
+
+/// The result of minting a collection nft
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintCollectionTransaction {
+    pub collection_id: NftId,
+    pub transaction: Transaction,
+}
+
+/// Dto for MintCollectionTransaction
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintCollectionTransactionDto {
+    pub collection_id: NftId,
+    pub transaction: TransactionDto,
+}
+
+impl From<&MintCollectionTransaction> for MintCollectionTransactionDto {
+    fn from(value: &MintCollectionTransaction) -> Self {
+        Self {
+            collection_id: value.collection_id,
+            transaction: TransactionDto::from(&value.transaction),
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Function to mint the nft that represents a collection, its nft address can then be used as issuer for the
+    /// nfts of the collection with
+    /// [AccountHandle.mint_collection_nfts()](crate::account::handle::AccountHandle.mint_collection_nfts)
+    /// ```ignore
+    /// let collection_options = NftOptions {
+    ///     address: None,
+    ///     immutable_metadata: None,
+    ///     metadata: None,
+    ///     irc_27_metadata: Some(Irc27Metadata::new("image/png", "https://example.com/collection.png", "My Collection")),
+    /// };
+    ///
+    /// let collection = account_handle.mint_collection(collection_options, None).await?;
+    /// println!("Collection id: {}", collection.collection_id);
+    /// ```
+    pub async fn mint_collection(
+        &self,
+        collection_options: NftOptions,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<MintCollectionTransaction> {
+        log::debug!("[TRANSACTION] mint_collection");
+        let transaction = self.mint_nfts(vec![collection_options], options).await?;
+
+        // The new nft is the only nft output with a null id, its id is derived from the output id
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let output_index = essence
+            .outputs()
+            .iter()
+            .position(|output| matches!(output, Output::Nft(nft_output) if *nft_output.nft_id() == NftId::null()))
+            .ok_or_else(|| Error::MintingFailed("No nft output in the transaction".to_string()))?;
+        let collection_id =
+            NftId::null().or_from_output_id(OutputId::new(transaction.transaction_id, output_index as u16)?);
+
+        Ok(MintCollectionTransaction {
+            collection_id,
+            transaction,
+        })
+    }
+
+    /// Function to mint nfts with an issuer feature of a collection nft or an alias of the account. The issuer output
+    /// is transitioned in every transaction, so the issuer feature gets validated. If the nfts don't fit into a
+    /// single transaction, they are split over multiple transactions, which are sent one after another. The
+    /// idempotency key from the options gets the index of the transaction appended in this case.
+    /// Issuer address needs to be a Bech32 encoded nft or alias address
+    /// ```ignore
+    /// let issuer = Address::Nft(NftAddress::new(collection.collection_id)).to_bech32("rms");
+    /// let transactions = account_handle.mint_collection_nfts(issuer, nfts_options, None).await?;
+    /// ```
+    pub async fn mint_collection_nfts(
+        &self,
+        issuer: String,
+        nfts_options: Vec<NftOptions>,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] mint_collection_nfts");
+        if nfts_options.is_empty() {
+            return Err(Error::MissingParameter("nfts_options"));
+        }
+        let (_bech32_hrp, issuer_address) = Address::try_from_bech32(&issuer)?;
+
+        let byte_cost_config = self.client.get_byte_cost_config().await?;
+        let account_addresses = self.list_addresses().await?;
+        let default_address = account_addresses
+            .first()
+            .ok_or(Error::FailedToGetRemainder)?
+            .address
+            .inner;
+
+        // One output is needed for the issuer and one for the remainder
+        let nfts_options_chunks = nfts_options.chunks(OUTPUT_COUNT_MAX as usize - 2).collect::<Vec<_>>();
+        let mut transactions = Vec::new();
+        for (chunk_index, nfts_options) in nfts_options_chunks.iter().enumerate() {
+            // With multiple transactions every transaction gets its own idempotency key derived from the provided one
+            let options = match nfts_options_chunks.len() {
+                1 => options.clone(),
+                _ => options.clone().map(|mut options| {
+                    options.idempotency_key = options
+                        .idempotency_key
+                        .map(|idempotency_key| format!("{idempotency_key}-{chunk_index}"));
+                    options
+                }),
+            };
+
+            let mut outputs = vec![self.collection_issuer_output(&issuer_address).await?];
+            for nft_options in nfts_options.iter() {
+                outputs.push(build_nft_output(
+                    byte_cost_config.clone(),
+                    default_address,
+                    nft_options.clone(),
+                    Some(issuer_address),
+                )?);
+            }
+
+            // Input selection will add the issuer output as input, because an output with the same id exists
+            let transaction = self.send(outputs, options).await?;
+            // The next transaction needs the new issuer output, so we wait until this one is confirmed
+            if chunk_index + 1 < nfts_options_chunks.len() {
+                match transaction.block_id {
+                    Some(block_id) => {
+                        let _ = self.client.retry_until_included(&block_id, None, None).await?;
+                        let sync_options = Some(SyncOptions {
+                            force_syncing: true,
+                            ..Default::default()
+                        });
+                        let _ = self.sync(sync_options).await?;
+                    }
+                    None => return Err(Error::MintingFailed("Could not mint collection nfts".to_string())),
+                }
+            }
+            transactions.push(transaction);
+        }
+
+        Ok(transactions)
+    }
+
+    /// Get the unchanged nft output or the alias output with an increased state index for the issuer
+    async fn collection_issuer_output(&self, issuer_address: &Address) -> crate::Result<Output> {
+        let account = self.read().await;
+        match issuer_address {
+            Address::Nft(nft_address) => {
+                let nft_id = *nft_address.nft_id();
+                account
+                    .unspent_outputs
+                    .values()
+                    .find_map(|output_data| match &output_data.output {
+                        Output::Nft(nft_output)
+                            if nft_output.nft_id().or_from_output_id(output_data.output_id) == nft_id =>
+                        {
+                            Some(NftOutputBuilder::from(nft_output).with_nft_id(nft_id).finish_output())
+                        }
+                        _ => None,
+                    })
+                    .ok_or(Error::NftNotFoundInUnspentOutputs)?
+                    .map_err(Into::into)
+            }
+            Address::Alias(alias_address) => {
+                let alias_id = *alias_address.alias_id();
+                account
+                    .unspent_outputs
+                    .values()
+                    .find_map(|output_data| match &output_data.output {
+                        Output::Alias(alias_output)
+                            if alias_output.alias_id().or_from_output_id(output_data.output_id) == alias_id =>
+                        {
+                            Some(
+                                AliasOutputBuilder::from(alias_output)
+                                    .with_alias_id(alias_id)
+                                    .with_state_index(alias_output.state_index() + 1)
+                                    .finish_output(),
+                            )
+                        }
+                        _ => None,
+                    })
+                    .ok_or_else(|| Error::MintingFailed("Alias output of the issuer not found".to_string()))?
+                    .map_err(Into::into)
+            }
+            Address::Ed25519(_) => Err(Error::MintingFailed(
+                "Collection issuer needs to be an nft or alias address".to_string(),
+            )),
+        }
+    }
+}
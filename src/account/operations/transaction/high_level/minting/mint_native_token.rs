@@ -12,6 +12,7 @@ use iota_client::bee_block::{
         AliasId, AliasOutputBuilder, BasicOutputBuilder, FoundryId, FoundryOutputBuilder, NativeToken, Output,
         SimpleTokenScheme, TokenId, TokenScheme,
     },
+    payload::transaction::TransactionEssence,
 };
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
@@ -19,7 +20,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     account::{
         handle::AccountHandle,
-        types::{Transaction, TransactionDto},
+        types::{Irc30Metadata, Transaction, TransactionDto},
         TransactionOptions,
     },
     Error,
@@ -40,6 +41,10 @@ pub struct NativeTokenOptions {
     /// Foundry metadata
     #[serde(rename = "foundryMetadata")]
     pub foundry_metadata: Option<Vec<u8>>,
+    /// IRC30 token metadata, will be validated and stored as foundry metadata. Can't be combined with
+    /// `foundry_metadata`
+    #[serde(rename = "tokenMetadata", default)]
+    pub token_metadata: Option<Irc30Metadata>,
 }
 
 /// The result of a minting native token transaction, block_id is an option because submitting the transaction could
@@ -81,7 +86,8 @@ impl AccountHandle {
     ///     account_address: None,
     ///     circulating_supply: U256::from(100),
     ///     maximum_supply: U256::from(100),
-    ///     foundry_metadata: None
+    ///     foundry_metadata: None,
+    ///     token_metadata: Some(Irc30Metadata::new("My Token", "MT", 6)),
     /// };
     ///
     /// let tx = account_handle.mint_native_token(native_token_options, None,).await?;
@@ -96,6 +102,19 @@ impl AccountHandle {
         options: Option<TransactionOptions>,
     ) -> crate::Result<MintTokenTransaction> {
         log::debug!("[TRANSACTION] mint_native_token");
+        let foundry_metadata = match (
+            native_token_options.token_metadata,
+            native_token_options.foundry_metadata,
+        ) {
+            (Some(_), Some(_)) => {
+                return Err(Error::MintingFailed(
+                    "Token metadata and foundry metadata can't be provided together".to_string(),
+                ));
+            }
+            (Some(token_metadata), None) => Some(token_metadata.to_bytes()?),
+            (None, foundry_metadata) => foundry_metadata,
+        };
+
         let byte_cost_config = self.client.get_byte_cost_config().await?;
 
         let account_addresses = self.list_addresses().await?;
@@ -119,7 +138,14 @@ impl AccountHandle {
         };
 
         let alias_id = self
-            .get_or_create_alias_output(controller_address, options.clone())
+            // The idempotency key belongs to the minting transaction, not to the alias creation
+            .get_or_create_alias_output(
+                controller_address,
+                options.clone().map(|options| TransactionOptions {
+                    idempotency_key: None,
+                    ..options
+                }),
+            )
             .await?;
 
         let account = self.read().await;
@@ -166,7 +192,7 @@ impl AccountHandle {
                         ImmutableAliasAddressUnlockCondition::new(AliasAddress::from(alias_id)),
                     ));
 
-                    if let Some(foundry_metadata) = native_token_options.foundry_metadata {
+                    if let Some(foundry_metadata) = foundry_metadata {
                         foundry_builder = foundry_builder
                             .add_immutable_feature(Feature::Metadata(MetadataFeature::new(foundry_metadata)?))
                     }
@@ -180,9 +206,19 @@ impl AccountHandle {
                     .add_native_token(NativeToken::new(token_id, native_token_options.circulating_supply)?)
                     .finish_output()?,
             ];
-            self.send(outputs, options)
-                .await
-                .map(|transaction| MintTokenTransaction { token_id, transaction })
+            let transaction = self.send(outputs, options).await?;
+            // With an idempotency key that was used before, the existing transaction is returned, which can have minted
+            // a different token than the one computed from the current alias output
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+            let token_id = essence
+                .outputs()
+                .iter()
+                .find_map(|output| match output {
+                    Output::Foundry(foundry_output) => Some(TokenId::from(foundry_output.id())),
+                    _ => None,
+                })
+                .unwrap_or(token_id);
+            Ok(MintTokenTransaction { token_id, transaction })
         } else {
             unreachable!("We checked if it's an alias output before")
         }
@@ -202,7 +238,15 @@ impl AccountHandle {
             .unspent_outputs()
             .values()
             .into_iter()
-            .find(|output_data| matches!(&output_data.output, Output::Alias(_output)));
+            .find(|output_data| match &output_data.output {
+                // Only aliases can be used for which the account is the state controller, aliases for which the
+                // account is only the governor are also synced
+                Output::Alias(alias_output) => account
+                    .addresses_with_unspent_outputs
+                    .iter()
+                    .any(|address| address.address.inner == *alias_output.state_controller_address()),
+                _ => false,
+            });
         match existing_alias_output {
             Some(output_data) => {
                 if let Output::Alias(alias_output) = &output_data.output {
@@ -6,16 +6,16 @@ use iota_client::{
     bee_block::{
         address::Address,
         output::{
-            feature::{Feature, MetadataFeature},
+            feature::{Feature, IssuerFeature, MetadataFeature},
             unlock_condition::{AddressUnlockCondition, UnlockCondition},
-            NftId, NftOutputBuilder,
+            ByteCostConfig, NftId, NftOutputBuilder, Output,
         },
     },
 };
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    account::{handle::AccountHandle, operations::transaction::Transaction, TransactionOptions},
+    account::{handle::AccountHandle, operations::transaction::Transaction, types::Irc27Metadata, TransactionOptions},
     Error,
 };
 
@@ -30,6 +30,10 @@ pub struct NftOptions {
     pub immutable_metadata: Option<Vec<u8>>,
     /// Nft metadata
     pub metadata: Option<Vec<u8>>,
+    /// IRC27 metadata, will be validated and stored as immutable metadata. Can't be combined with
+    /// `immutable_metadata`
+    #[serde(rename = "irc27Metadata", default)]
+    pub irc_27_metadata: Option<Irc27Metadata>,
 }
 
 impl AccountHandle {
@@ -46,6 +50,7 @@ impl AccountHandle {
     ///     address: Some("rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string()),
     ///     immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
     ///     metadata: Some(b"some nft metadata".to_vec()),
+    ///     irc_27_metadata: None,
     /// }];
     ///
     /// let transaction = account.mint_nfts(nft_options, None).await?;
@@ -60,8 +65,9 @@ impl AccountHandle {
         nfts_options: Vec<NftOptions>,
         options: Option<TransactionOptions>,
     ) -> crate::Result<Transaction> {
-        let prepared_trasacton = self.prepare_mint_nfts(nfts_options, options).await?;
-        self.sign_and_submit_transaction(prepared_trasacton).await
+        let prepare_transaction = self.prepare_mint_nfts(nfts_options, options.clone());
+        self.prepare_sign_and_submit_transaction(&options, prepare_transaction)
+            .await
     }
 
     /// Function to prepare the transaction for
@@ -76,44 +82,66 @@ impl AccountHandle {
 
         let account_addresses = self.list_addresses().await?;
 
+        let default_address = account_addresses
+            .first()
+            // todo other error message
+            .ok_or(Error::FailedToGetRemainder)?
+            .address
+            .inner;
+
         let mut outputs = Vec::new();
         for nft_options in nfts_options {
-            let address = match nft_options.address {
-                Some(address) => Address::try_from_bech32(&address)?.1,
-                // todo other error message
-                None => {
-                    account_addresses
-                        .first()
-                        .ok_or(Error::FailedToGetRemainder)?
-                        .address
-                        .inner
-                }
-            };
-            let immutable_metadata = if let Some(immutable_metadata) = nft_options.immutable_metadata {
-                Some(Feature::Metadata(MetadataFeature::new(immutable_metadata)?))
-            } else {
-                None
-            };
-            let metadata = if let Some(metadata) = nft_options.metadata {
-                Some(Feature::Metadata(MetadataFeature::new(metadata)?))
-            } else {
-                None
-            };
-
-            // NftId needs to be set to 0 for the creation
-            let mut nft_builder =
-                NftOutputBuilder::new_with_minimum_storage_deposit(byte_cost_config.clone(), NftId::null())?
-                    // Address which will own the nft
-                    .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(address)));
-            if let Some(immutable_metadata) = immutable_metadata {
-                nft_builder = nft_builder.add_immutable_feature(immutable_metadata);
-            }
-            if let Some(metadata) = metadata {
-                nft_builder = nft_builder.add_feature(metadata);
-            }
-            outputs.push(nft_builder.finish_output()?);
+            outputs.push(build_nft_output(
+                byte_cost_config.clone(),
+                default_address,
+                nft_options,
+                None,
+            )?);
         }
 
         self.prepare_transaction(outputs, options).await
     }
 }
+
+/// Build the output for a new nft, with an immutable issuer feature if an issuer is provided
+pub(crate) fn build_nft_output(
+    byte_cost_config: ByteCostConfig,
+    default_address: Address,
+    nft_options: NftOptions,
+    issuer: Option<Address>,
+) -> crate::Result<Output> {
+    let address = match nft_options.address {
+        Some(address) => Address::try_from_bech32(&address)?.1,
+        None => default_address,
+    };
+    let immutable_metadata = match (nft_options.irc_27_metadata, nft_options.immutable_metadata) {
+        (Some(_), Some(_)) => {
+            return Err(Error::InvalidNftMetadata(
+                "IRC27 metadata and immutable metadata can't be provided together".to_string(),
+            ));
+        }
+        (Some(irc_27_metadata), None) => Some(Feature::Metadata(MetadataFeature::new(irc_27_metadata.to_bytes()?)?)),
+        (None, Some(immutable_metadata)) => Some(Feature::Metadata(MetadataFeature::new(immutable_metadata)?)),
+        (None, None) => None,
+    };
+    let metadata = if let Some(metadata) = nft_options.metadata {
+        Some(Feature::Metadata(MetadataFeature::new(metadata)?))
+    } else {
+        None
+    };
+
+    // NftId needs to be set to 0 for the creation
+    let mut nft_builder = NftOutputBuilder::new_with_minimum_storage_deposit(byte_cost_config, NftId::null())?
+        // Address which will own the nft
+        .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(address)));
+    if let Some(issuer) = issuer {
+        nft_builder = nft_builder.add_immutable_feature(Feature::Issuer(IssuerFeature::new(issuer)));
+    }
+    if let Some(immutable_metadata) = immutable_metadata {
+        nft_builder = nft_builder.add_immutable_feature(immutable_metadata);
+    }
+    if let Some(metadata) = metadata {
+        nft_builder = nft_builder.add_feature(metadata);
+    }
+    Ok(nft_builder.finish_output()?)
+}
@@ -1,5 +1,7 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod increase_native_token_supply;
+pub(crate) mod mint_collection;
 pub(crate) mod mint_native_token;
 pub(crate) mod mint_nfts;
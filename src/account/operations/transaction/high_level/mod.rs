@@ -1,11 +1,14 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod alias;
 pub(crate) mod burning_melting;
 pub(crate) mod minting;
 
 pub(crate) mod minimum_storage_deposit;
 pub(crate) mod send_amount;
+pub(crate) mod send_from_chain_address;
 pub(crate) mod send_micro_transaction;
 pub(crate) mod send_native_tokens;
 pub(crate) mod send_nft;
+pub(crate) mod send_payments;
@@ -0,0 +1,168 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use iota_client::bee_block::{
+    address::Address,
+    output::{
+        unlock_condition::UnlockCondition, AliasOutputBuilder, NativeToken, NativeTokensBuilder, NftOutputBuilder,
+        Output, TokenId,
+    },
+};
+use primitive_types::U256;
+
+use crate::{
+    account::{handle::AccountHandle, operations::transaction::Transaction, TransactionOptions},
+    Error,
+};
+
+impl AccountHandle {
+    /// Function to send outputs with the funds of an alias or nft address of the account. The alias or nft output is
+    /// transitioned in the same transaction, so it can unlock the outputs locked to its address, an alias gets a state
+    /// transition. Only outputs without additional unlock conditions are used as inputs, nfts owned by the address
+    /// only if they're in the outputs. The amount and native tokens that are not sent are added to the alias or nft
+    /// output.
+    /// Address needs to be a Bech32 encoded alias or nft address
+    /// ```ignore
+    /// let outputs = vec![BasicOutputBuilder::new_with_amount(1_000_000)?
+    ///     .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(
+    ///         Address::try_from_bech32("rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu")?.1,
+    ///     )))
+    ///     .finish_output()?];
+    ///
+    /// let transaction = account_handle.send_from_chain_address(alias_address, outputs, None).await?;
+    /// ```
+    pub async fn send_from_chain_address(
+        &self,
+        chain_address: String,
+        outputs: Vec<Output>,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<Transaction> {
+        log::debug!("[TRANSACTION] send_from_chain_address");
+        let (_bech32_hrp, address) = Address::try_from_bech32(&chain_address)?;
+
+        let (chain_output_id, chain_output) = match address {
+            Address::Alias(alias_address) => {
+                let (output_id, alias_output) = self
+                    .output_id_and_next_alias_output_state(*alias_address.alias_id())
+                    .await?;
+                (output_id, Output::Alias(alias_output))
+            }
+            Address::Nft(nft_address) => {
+                let (output_id, nft_output) = self.output_id_and_nft_output(*nft_address.nft_id()).await?;
+                let nft_output = NftOutputBuilder::from(&nft_output)
+                    .with_nft_id(*nft_address.nft_id())
+                    .finish_output()?;
+                (output_id, nft_output)
+            }
+            Address::Ed25519(_) => {
+                return Err(Error::CustomInputError(format!(
+                    "{chain_address} is not an alias or nft address"
+                )));
+            }
+        };
+
+        // Collect the outputs locked to the address
+        let mut custom_inputs = vec![chain_output_id];
+        let mut input_amount = chain_output.amount();
+        let mut input_native_tokens = NativeTokensBuilder::new();
+        if let Some(native_tokens) = chain_output.native_tokens() {
+            input_native_tokens.add_native_tokens(native_tokens.clone())?;
+        }
+        {
+            let account = self.read().await;
+            for output_data in account.unspent_outputs.values() {
+                if output_data.address != address || account.locked_outputs.contains(&output_data.output_id) {
+                    continue;
+                }
+                // Outputs with additional unlock conditions might need a return output or can't be unlocked yet
+                match output_data.output.unlock_conditions() {
+                    Some(unlock_conditions) if matches!(unlock_conditions.as_ref(), [UnlockCondition::Address(_)]) => {}
+                    _ => continue,
+                }
+                match &output_data.output {
+                    Output::Basic(_) => {}
+                    // Only use nfts that are sent, so they don't get burned
+                    Output::Nft(nft_input) => {
+                        let nft_id = nft_input.nft_id().or_from_output_id(output_data.output_id);
+                        if !outputs
+                            .iter()
+                            .any(|output| matches!(output, Output::Nft(nft_output) if *nft_output.nft_id() == nft_id))
+                        {
+                            continue;
+                        }
+                    }
+                    _ => continue,
+                }
+                custom_inputs.push(output_data.output_id);
+                input_amount += output_data.output.amount();
+                if let Some(native_tokens) = output_data.output.native_tokens() {
+                    input_native_tokens.add_native_tokens(native_tokens.clone())?;
+                }
+            }
+        }
+
+        // Everything that isn't sent stays in the alias or nft output
+        let output_amount = outputs.iter().map(|output| output.amount()).sum::<u64>();
+        let remaining_amount = input_amount
+            .checked_sub(output_amount)
+            .ok_or(Error::InsufficientFunds(input_amount, output_amount))?;
+        let mut remaining_native_tokens: HashMap<TokenId, U256> = input_native_tokens
+            .finish()?
+            .iter()
+            .map(|native_token| (*native_token.token_id(), *native_token.amount()))
+            .collect();
+        for output in &outputs {
+            if let Some(native_tokens) = output.native_tokens() {
+                for native_token in native_tokens.iter() {
+                    let remaining_amount = remaining_native_tokens
+                        .get_mut(native_token.token_id())
+                        .filter(|remaining_amount| **remaining_amount >= *native_token.amount())
+                        .ok_or_else(|| {
+                            Error::CustomInputError(format!(
+                                "not enough native tokens {} on {chain_address}",
+                                native_token.token_id()
+                            ))
+                        })?;
+                    *remaining_amount -= *native_token.amount();
+                }
+            }
+        }
+        let mut remaining_native_tokens_builder = NativeTokensBuilder::new();
+        for (token_id, amount) in remaining_native_tokens {
+            if !amount.is_zero() {
+                remaining_native_tokens_builder.add_native_token(NativeToken::new(token_id, amount)?)?;
+            }
+        }
+        let remaining_native_tokens = remaining_native_tokens_builder.finish()?;
+
+        let chain_output = match &chain_output {
+            Output::Alias(alias_output) => AliasOutputBuilder::from(alias_output)
+                .with_amount(remaining_amount)?
+                .with_native_tokens(remaining_native_tokens)
+                .finish_output()?,
+            Output::Nft(nft_output) => NftOutputBuilder::from(nft_output)
+                .with_amount(remaining_amount)?
+                .with_native_tokens(remaining_native_tokens)
+                .finish_output()?,
+            _ => unreachable!("We only created alias or nft outputs before"),
+        };
+
+        let mut outputs = outputs;
+        outputs.insert(0, chain_output);
+
+        let options = match options {
+            Some(mut options) => {
+                options.custom_inputs.replace(custom_inputs);
+                Some(options)
+            }
+            None => Some(TransactionOptions {
+                custom_inputs: Some(custom_inputs),
+                ..Default::default()
+            }),
+        };
+
+        self.send(outputs, options).await
+    }
+}
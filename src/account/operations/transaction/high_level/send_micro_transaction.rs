@@ -69,10 +69,9 @@ impl AccountHandle {
         options: Option<TransactionOptions>,
     ) -> crate::Result<Transaction> {
         log::debug!("[TRANSACTION] send_micro_transaction");
-        let prepared_trasacton = self
-            .prepare_send_micro_transaction(addresses_with_micro_amount, options)
-            .await?;
-        self.sign_and_submit_transaction(prepared_trasacton).await
+        let prepare_transaction = self.prepare_send_micro_transaction(addresses_with_micro_amount, options.clone());
+        self.prepare_sign_and_submit_transaction(&options, prepare_transaction)
+            .await
     }
 
     /// Function to prepare the transaction for
@@ -71,10 +71,9 @@ impl AccountHandle {
         addresses_native_tokens: Vec<AddressNativeTokens>,
         options: Option<TransactionOptions>,
     ) -> crate::Result<Transaction> {
-        let prepared_trasacton = self
-            .prepare_send_native_tokens(addresses_native_tokens, options)
-            .await?;
-        self.sign_and_submit_transaction(prepared_trasacton).await
+        let prepare_transaction = self.prepare_send_native_tokens(addresses_native_tokens, options.clone());
+        self.prepare_sign_and_submit_transaction(&options, prepare_transaction)
+            .await
     }
 
     /// Function to prepare the transaction for
@@ -54,8 +54,9 @@ impl AccountHandle {
         addresses_nft_ids: Vec<AddressAndNftId>,
         options: Option<TransactionOptions>,
     ) -> crate::Result<Transaction> {
-        let prepared_trasacton = self.prepare_send_nft(addresses_nft_ids, options).await?;
-        self.sign_and_submit_transaction(prepared_trasacton).await
+        let prepare_transaction = self.prepare_send_nft(addresses_nft_ids, options.clone());
+        self.prepare_sign_and_submit_transaction(&options, prepare_transaction)
+            .await
     }
 
     /// Function to prepare the transaction for
@@ -0,0 +1,591 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::{
+    address::Address,
+    input::INPUT_COUNT_MAX,
+    output::{
+        unlock_condition::{
+            AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition, UnlockCondition,
+        },
+        BasicOutputBuilder, ByteCost, ByteCostConfig, NativeToken, NativeTokensBuilder, NftId, NftOutput,
+        NftOutputBuilder, Output, OUTPUT_COUNT_MAX,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    account::{
+        constants::DEFAULT_EXPIRATION_TIME,
+        handle::AccountHandle,
+        operations::transaction::{
+            high_level::minimum_storage_deposit::minimum_storage_deposit_basic_native_tokens, Transaction,
+        },
+        SyncOptions, TransactionOptions,
+    },
+    AddressAndNftId, AddressNativeTokens, AddressWithAmount, Error,
+};
+
+// Every nft output needs the nft as input, at most half of the inputs of a transaction are used for them, so enough
+// inputs are left for the base coins and native tokens
+const NFT_INPUTS_MAX: usize = INPUT_COUNT_MAX as usize / 2;
+
+/// A single payment for `send_payments()`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum Payment {
+    /// Send an amount of the base coin
+    Amount(AddressWithAmount),
+    /// Send native tokens
+    NativeTokens(AddressNativeTokens),
+    /// Send an nft
+    Nft(AddressAndNftId),
+}
+
+// All payments to the same address
+struct RecipientPayments {
+    bech32_address: String,
+    address: Address,
+    amount: u64,
+    native_tokens: Option<NativeTokensBuilder>,
+    return_address: Option<String>,
+    expiration: Option<u32>,
+    nft_ids: Vec<NftId>,
+}
+
+impl AccountHandle {
+    /// Function to send base coins, native tokens and nfts to multiple addresses at once. Payments to the same address
+    /// are merged, so every address gets as few outputs as possible: base coins and native tokens are added to an nft
+    /// output for the same address if its storage deposit allows it, native tokens are sent together with the base
+    /// coins if the amount covers the storage deposit and only otherwise with a
+    /// [`StorageDepositReturnUnlockCondition`] and [`ExpirationUnlockCondition`] like in
+    /// [AccountHandle.send_native_tokens()](crate::account::handle::AccountHandle.send_native_tokens), the base coins
+    /// then need to cover the storage deposit of their own output.
+    /// The outputs are sent in a single transaction if the maximum output and input counts allow it, otherwise they're
+    /// split over multiple transactions, which are sent one after another. The idempotency key from the options gets the
+    /// index of the transaction appended in this case.
+    /// Address needs to be Bech32 encoded
+    /// ```ignore
+    /// let payments = vec![
+    ///     Payment::Amount(AddressWithAmount {
+    ///         address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+    ///         amount: 1_000_000,
+    ///     }),
+    ///     Payment::Nft(AddressAndNftId {
+    ///         address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+    ///         nft_id: NftId::from_str("04f9b54d488d2e83a6c90db08ae4b39651bbba8a")?,
+    ///     }),
+    /// ];
+    ///
+    /// let transactions = account_handle.send_payments(payments, None).await?;
+    /// for transaction in transactions {
+    ///     println!("Transaction created: {}", transaction.transaction_id);
+    /// }
+    /// ```
+    pub async fn send_payments(
+        &self,
+        payments: Vec<Payment>,
+        options: Option<TransactionOptions>,
+    ) -> crate::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] send_payments");
+        if payments.is_empty() {
+            return Err(Error::MissingParameter("payments"));
+        }
+
+        let outputs = self.prepare_payment_outputs(payments).await?;
+
+        let output_chunks = chunk_outputs(outputs);
+        let mut transactions = Vec::new();
+        for (chunk_index, outputs) in output_chunks.iter().enumerate() {
+            // With multiple transactions every transaction gets its own idempotency key derived from the provided one
+            let options = match output_chunks.len() {
+                1 => options.clone(),
+                _ => options.clone().map(|mut options| {
+                    options.idempotency_key = options
+                        .idempotency_key
+                        .map(|idempotency_key| format!("{idempotency_key}-{chunk_index}"));
+                    options
+                }),
+            };
+
+            let transaction = self.send(outputs.clone(), options).await?;
+            // The next transaction might need the remainder of this one, so we wait until it's confirmed
+            if chunk_index + 1 < output_chunks.len() {
+                if let Some(block_id) = transaction.block_id {
+                    let _ = self.client.retry_until_included(&block_id, None, None).await?;
+                    let sync_options = Some(SyncOptions {
+                        force_syncing: true,
+                        ..Default::default()
+                    });
+                    let _ = self.sync(sync_options).await?;
+                }
+            }
+            transactions.push(transaction);
+        }
+
+        Ok(transactions)
+    }
+
+    /// Merge the payments per address and create the outputs for them
+    async fn prepare_payment_outputs(&self, payments: Vec<Payment>) -> crate::Result<Vec<Output>> {
+        log::debug!("[TRANSACTION] prepare_payment_outputs");
+        let byte_cost_config = self.client.get_byte_cost_config().await?;
+
+        let account_addresses = self.list_addresses().await?;
+        let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
+
+        let local_time = self.client.get_time_checked().await?;
+        let unspent_outputs = self.list_unspent_outputs().await?;
+
+        let mut outputs = Vec::new();
+        for recipient in merge_payments(payments)? {
+            // Find the nft outputs from the unspent outputs, the input selection will then add them to the inputs
+            let nft_outputs = recipient
+                .nft_ids
+                .iter()
+                .map(|nft_id| {
+                    unspent_outputs
+                        .iter()
+                        .find_map(|output_data| match &output_data.output {
+                            Output::Nft(nft_output)
+                                if nft_output.nft_id().or_from_output_id(output_data.output_id) == *nft_id =>
+                            {
+                                Some(nft_output)
+                            }
+                            _ => None,
+                        })
+                        .ok_or(Error::NftNotFoundInUnspentOutputs)
+                })
+                .collect::<crate::Result<Vec<&NftOutput>>>()?;
+
+            outputs.extend(recipient_outputs(
+                recipient,
+                &nft_outputs,
+                &byte_cost_config,
+                default_return_address.address.inner,
+                local_time,
+            )?);
+        }
+
+        Ok(outputs)
+    }
+}
+
+// Merge the payments per address, the recipients keep the order of the payments, so the outputs are created in the
+// same order
+fn merge_payments(payments: Vec<Payment>) -> crate::Result<Vec<RecipientPayments>> {
+    let mut recipients: Vec<RecipientPayments> = Vec::new();
+    for payment in payments {
+        let bech32_address = match &payment {
+            Payment::Amount(payment) => &payment.address,
+            Payment::NativeTokens(payment) => &payment.address,
+            Payment::Nft(payment) => &payment.address,
+        };
+        let (_bech32_hrp, address) = Address::try_from_bech32(bech32_address)?;
+        let recipient_index = match recipients.iter().position(|recipient| recipient.address == address) {
+            Some(index) => index,
+            None => {
+                recipients.push(RecipientPayments {
+                    bech32_address: bech32_address.clone(),
+                    address,
+                    amount: 0,
+                    native_tokens: None,
+                    return_address: None,
+                    expiration: None,
+                    nft_ids: Vec::new(),
+                });
+                recipients.len() - 1
+            }
+        };
+        let recipient = &mut recipients[recipient_index];
+
+        match payment {
+            Payment::Amount(payment) => {
+                recipient.amount = recipient.amount.checked_add(payment.amount).ok_or_else(|| {
+                    iota_client::Error::InvalidAmount(format!("{} + {}", recipient.amount, payment.amount))
+                })?;
+            }
+            Payment::NativeTokens(payment) => {
+                let native_tokens = recipient.native_tokens.get_or_insert_with(NativeTokensBuilder::new);
+                for (token_id, amount) in payment.native_tokens {
+                    native_tokens.add_native_token(NativeToken::new(token_id, amount)?)?;
+                }
+                // The first provided return address and expiration are used for the address
+                if recipient.return_address.is_none() {
+                    recipient.return_address = payment.return_address;
+                }
+                if recipient.expiration.is_none() {
+                    recipient.expiration = payment.expiration;
+                }
+            }
+            Payment::Nft(payment) => {
+                if !recipient.nft_ids.contains(&payment.nft_id) {
+                    recipient.nft_ids.push(payment.nft_id);
+                }
+            }
+        }
+    }
+
+    Ok(recipients)
+}
+
+// Create the outputs for all payments to an address, `nft_outputs` are the unspent outputs of the nfts of the recipient
+fn recipient_outputs(
+    recipient: RecipientPayments,
+    nft_outputs: &[&NftOutput],
+    byte_cost_config: &ByteCostConfig,
+    default_return_address: Address,
+    local_time: u32,
+) -> crate::Result<Vec<Output>> {
+    let mut outputs = Vec::new();
+    let address_unlock_condition = UnlockCondition::Address(AddressUnlockCondition::new(recipient.address));
+    let mut amount = recipient.amount;
+    let mut native_tokens = match recipient.native_tokens {
+        Some(native_tokens) => Some(native_tokens.finish()?),
+        None => None,
+    };
+
+    for (index, (nft_id, nft_output)) in recipient.nft_ids.iter().zip(nft_outputs).enumerate() {
+        let nft_builder = NftOutputBuilder::from(*nft_output)
+            .with_nft_id(*nft_id)
+            .with_unlock_conditions(vec![address_unlock_condition.clone()]);
+        if index > 0 {
+            outputs.push(nft_builder.finish_output()?);
+            continue;
+        }
+
+        // The base coins are added to the first nft output, so no extra output is needed for them
+        let nft_output = nft_builder.with_amount(nft_output.amount() + amount)?.finish()?;
+        amount = 0;
+
+        // Native tokens are also added if the amount of the nft output covers the higher storage deposit
+        if let Some(new_native_tokens) = &native_tokens {
+            let mut native_tokens_builder = NativeTokensBuilder::from(nft_output.native_tokens().clone());
+            native_tokens_builder.add_native_tokens(new_native_tokens.clone())?;
+            let nft_output_with_native_tokens = NftOutputBuilder::from(&nft_output)
+                .with_native_tokens(native_tokens_builder.finish()?)
+                .finish_output()?;
+            if nft_output_with_native_tokens.amount() >= nft_output_with_native_tokens.byte_cost(byte_cost_config) {
+                outputs.push(nft_output_with_native_tokens);
+                native_tokens = None;
+                continue;
+            }
+        }
+        outputs.push(Output::Nft(nft_output));
+    }
+
+    if let Some(native_tokens) = native_tokens {
+        if amount > 0 {
+            let basic_output = BasicOutputBuilder::new_with_amount(amount)?
+                .with_native_tokens(native_tokens.clone())
+                .add_unlock_condition(address_unlock_condition.clone())
+                .finish_output()?;
+            // Without storage deposit return the native tokens can be sent together with the base coins
+            if amount >= basic_output.byte_cost(byte_cost_config) {
+                outputs.push(basic_output);
+                return Ok(outputs);
+            }
+            let basic_output = BasicOutputBuilder::new_with_amount(amount)?
+                .add_unlock_condition(address_unlock_condition.clone())
+                .finish_output()?;
+            // The base coins are sent in their own output, which needs to cover its storage deposit too
+            let required_storage_deposit = basic_output.byte_cost(byte_cost_config);
+            if amount < required_storage_deposit {
+                return Err(Error::InsufficientStorageDeposit(
+                    recipient.bech32_address,
+                    amount,
+                    required_storage_deposit,
+                ));
+            }
+            outputs.push(basic_output);
+        }
+
+        let return_address = match &recipient.return_address {
+            Some(return_address) => Address::try_from_bech32(return_address)?.1,
+            None => default_return_address,
+        };
+        // get minimum required amount for such an output, so we don't lock more than required
+        let storage_deposit_amount = minimum_storage_deposit_basic_native_tokens(
+            byte_cost_config,
+            &recipient.address,
+            &return_address,
+            Some(
+                native_tokens
+                    .iter()
+                    .map(|native_token| (*native_token.token_id(), *native_token.amount()))
+                    .collect(),
+            ),
+        )?;
+        let expiration_time = local_time + recipient.expiration.unwrap_or(DEFAULT_EXPIRATION_TIME);
+
+        log::debug!(
+            "[TRANSACTION] sending native tokens to {} with storage deposit return",
+            recipient.bech32_address
+        );
+        outputs.push(
+            BasicOutputBuilder::new_with_amount(storage_deposit_amount)?
+                .with_native_tokens(native_tokens)
+                .add_unlock_condition(address_unlock_condition)
+                .add_unlock_condition(UnlockCondition::StorageDepositReturn(
+                    // We send the full storage_deposit_amount back to the sender, so only the native tokens are sent
+                    StorageDepositReturnUnlockCondition::new(return_address, storage_deposit_amount)?,
+                ))
+                // The output belongs to the sender again if the recipient doesn't claim it before it expires
+                .add_unlock_condition(UnlockCondition::Expiration(ExpirationUnlockCondition::new(
+                    return_address,
+                    expiration_time,
+                )?))
+                .finish_output()?,
+        );
+    } else if amount > 0 {
+        outputs.push(
+            BasicOutputBuilder::new_with_amount(amount)?
+                .add_unlock_condition(address_unlock_condition)
+                .finish_output()?,
+        );
+    }
+
+    Ok(outputs)
+}
+
+// Split the outputs over as few transactions as possible, one output is kept free for the remainder
+fn chunk_outputs(outputs: Vec<Output>) -> Vec<Vec<Output>> {
+    let mut chunks: Vec<Vec<Output>> = Vec::new();
+    let mut nft_inputs = 0;
+    for output in outputs {
+        let is_nft = matches!(output, Output::Nft(_));
+        match chunks.last_mut() {
+            Some(chunk) if chunk.len() < OUTPUT_COUNT_MAX as usize - 1 && (!is_nft || nft_inputs < NFT_INPUTS_MAX) => {
+                chunk.push(output)
+            }
+            _ => {
+                chunks.push(vec![output]);
+                nft_inputs = 0;
+            }
+        }
+        if is_nft {
+            nft_inputs += 1;
+        }
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_client::bee_block::{
+        address::Ed25519Address,
+        output::{ByteCostConfigBuilder, TokenId},
+    };
+    use primitive_types::U256;
+
+    use super::*;
+
+    const LOCAL_TIME: u32 = 1_700_000_000;
+
+    fn address(byte: u8) -> Address {
+        Address::Ed25519(Ed25519Address::new([byte; 32]))
+    }
+
+    fn amount_payment(byte: u8, amount: u64) -> Payment {
+        Payment::Amount(AddressWithAmount {
+            address: address(byte).to_bech32("rms"),
+            amount,
+        })
+    }
+
+    fn native_tokens_payment(byte: u8, token_amount: u64, return_address: Option<String>) -> Payment {
+        Payment::NativeTokens(AddressNativeTokens {
+            address: address(byte).to_bech32("rms"),
+            native_tokens: vec![(TokenId::new([1; 38]), U256::from(token_amount))],
+            return_address,
+            expiration: None,
+        })
+    }
+
+    fn nft_output(nft_id: NftId) -> NftOutput {
+        NftOutputBuilder::new_with_amount(100_000, nft_id)
+            .unwrap()
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(address(9))))
+            .finish()
+            .unwrap()
+    }
+
+    fn outputs(payments: Vec<Payment>, nft_outputs: &[&NftOutput]) -> crate::Result<Vec<Output>> {
+        let byte_cost_config = ByteCostConfigBuilder::new().finish();
+        let mut outputs = Vec::new();
+        for recipient in merge_payments(payments)? {
+            outputs.extend(recipient_outputs(
+                recipient,
+                nft_outputs,
+                &byte_cost_config,
+                address(9),
+                LOCAL_TIME,
+            )?);
+        }
+        Ok(outputs)
+    }
+
+    #[test]
+    fn merge_payments_per_address() {
+        let nft_id = NftId::new([2; 20]);
+        let nft_payment = || {
+            Payment::Nft(AddressAndNftId {
+                address: address(1).to_bech32("rms"),
+                nft_id,
+            })
+        };
+        let mut recipients = merge_payments(vec![
+            amount_payment(1, 1_000_000),
+            native_tokens_payment(1, 10, None),
+            amount_payment(2, 1_000_000),
+            amount_payment(1, 500_000),
+            native_tokens_payment(1, 5, Some(address(3).to_bech32("rms"))),
+            nft_payment(),
+            nft_payment(),
+        ])
+        .unwrap();
+
+        assert_eq!(recipients.len(), 2);
+        assert_eq!(recipients[0].address, address(1));
+        assert_eq!(recipients[0].amount, 1_500_000);
+        let native_tokens = recipients[0].native_tokens.take().unwrap().finish().unwrap();
+        assert_eq!(native_tokens.len(), 1);
+        assert_eq!(*native_tokens.first().unwrap().amount(), U256::from(15u8));
+        assert_eq!(recipients[0].return_address, Some(address(3).to_bech32("rms")));
+        assert_eq!(recipients[0].nft_ids, vec![nft_id]);
+        assert_eq!(recipients[1].address, address(2));
+        assert_eq!(recipients[1].amount, 1_000_000);
+
+        // The base coins cover the storage deposit of the native tokens, so a single output is created
+        let outputs = outputs(
+            vec![
+                amount_payment(1, 500_000),
+                native_tokens_payment(1, 10, None),
+                amount_payment(1, 500_000),
+            ],
+            &[],
+        )
+        .unwrap();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].amount(), 1_000_000);
+        assert_eq!(outputs[0].native_tokens().unwrap().len(), 1);
+        assert!(outputs[0]
+            .unlock_conditions()
+            .unwrap()
+            .storage_deposit_return()
+            .is_none());
+    }
+
+    #[test]
+    fn fold_into_nft_output() {
+        let nft_id = NftId::new([2; 20]);
+        let nft_output = nft_output(nft_id);
+        let payments = vec![
+            Payment::Nft(AddressAndNftId {
+                address: address(1).to_bech32("rms"),
+                nft_id,
+            }),
+            amount_payment(1, 1_000_000),
+            native_tokens_payment(1, 10, None),
+        ];
+
+        let outputs = outputs(payments, &[&nft_output]).unwrap();
+        assert_eq!(outputs.len(), 1);
+        match &outputs[0] {
+            Output::Nft(nft_output) => {
+                assert_eq!(*nft_output.nft_id(), nft_id);
+                assert_eq!(nft_output.amount(), 1_100_000);
+                assert_eq!(nft_output.native_tokens().len(), 1);
+                assert_eq!(nft_output.unlock_conditions().address().unwrap().address(), &address(1));
+            }
+            _ => panic!("expected an nft output"),
+        }
+    }
+
+    #[test]
+    fn storage_deposit_return() {
+        let byte_cost_config = ByteCostConfigBuilder::new().finish();
+
+        // Only native tokens, the storage deposit is sent back to the default return address
+        let outputs_without_amount = outputs(vec![native_tokens_payment(1, 10, None)], &[]).unwrap();
+        assert_eq!(outputs_without_amount.len(), 1);
+        let unlock_conditions = outputs_without_amount[0].unlock_conditions().unwrap();
+        let storage_deposit_return = unlock_conditions.storage_deposit_return().unwrap();
+        assert_eq!(*storage_deposit_return.return_address(), address(9));
+        assert_eq!(storage_deposit_return.amount(), outputs_without_amount[0].amount());
+        assert_eq!(
+            outputs_without_amount[0].amount(),
+            outputs_without_amount[0].byte_cost(&byte_cost_config)
+        );
+        let expiration = unlock_conditions.expiration().unwrap();
+        assert_eq!(*expiration.return_address(), address(9));
+        assert_eq!(expiration.timestamp(), LOCAL_TIME + DEFAULT_EXPIRATION_TIME);
+
+        // The base coins only cover the storage deposit of their own output, so the native tokens are sent separately
+        let basic_output_deposit = BasicOutputBuilder::new_with_amount(1)
+            .unwrap()
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(address(1))))
+            .finish_output()
+            .unwrap()
+            .byte_cost(&byte_cost_config);
+        let outputs = outputs(
+            vec![
+                amount_payment(1, basic_output_deposit),
+                native_tokens_payment(1, 10, Some(address(3).to_bech32("rms"))),
+            ],
+            &[],
+        )
+        .unwrap();
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[0].amount(), basic_output_deposit);
+        assert!(outputs[0].native_tokens().unwrap().is_empty());
+        let unlock_conditions = outputs[1].unlock_conditions().unwrap();
+        assert_eq!(
+            *unlock_conditions.storage_deposit_return().unwrap().return_address(),
+            address(3)
+        );
+        assert_eq!(*unlock_conditions.expiration().unwrap().return_address(), address(3));
+
+        // The base coins don't cover the storage deposit of their own output
+        assert!(matches!(
+            self::outputs(vec![amount_payment(1, 1), native_tokens_payment(1, 10, None)], &[]),
+            Err(Error::InsufficientStorageDeposit(..))
+        ));
+    }
+
+    #[test]
+    fn chunk_boundaries() {
+        let basic_output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .unwrap()
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(address(1))))
+            .finish_output()
+            .unwrap();
+        let nft_output = Output::Nft(nft_output(NftId::new([2; 20])));
+        let chunk_lengths = |outputs: Vec<Output>| chunk_outputs(outputs).iter().map(Vec::len).collect::<Vec<usize>>();
+
+        // One output is kept free for the remainder
+        let max_outputs = OUTPUT_COUNT_MAX as usize - 1;
+        assert_eq!(
+            chunk_lengths(vec![basic_output.clone(); max_outputs]),
+            vec![max_outputs]
+        );
+        assert_eq!(
+            chunk_lengths(vec![basic_output.clone(); max_outputs + 1]),
+            vec![max_outputs, 1]
+        );
+
+        assert_eq!(
+            chunk_lengths(vec![nft_output.clone(); NFT_INPUTS_MAX]),
+            vec![NFT_INPUTS_MAX]
+        );
+        assert_eq!(
+            chunk_lengths(vec![nft_output.clone(); NFT_INPUTS_MAX + 1]),
+            vec![NFT_INPUTS_MAX, 1]
+        );
+        // Basic outputs still fit into a chunk that reached the nft limit
+        let mut outputs = vec![nft_output.clone(); NFT_INPUTS_MAX];
+        outputs.push(basic_output);
+        outputs.push(nft_output);
+        assert_eq!(chunk_lengths(outputs), vec![NFT_INPUTS_MAX + 1, 1]);
+    }
+}
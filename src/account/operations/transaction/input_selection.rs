@@ -144,6 +144,11 @@ fn filter_inputs(
             continue;
         }
 
+        // Don't use outputs that were rejected
+        if account.ignored_outputs.contains(&output_data.output_id) {
+            continue;
+        }
+
         match &output_data.output {
             Output::Nft(nft_input) => {
                 // Don't add if output has not the same NftId, so we don't burn it
@@ -214,7 +219,57 @@ fn filter_inputs(
             continue;
         }
 
-        available_outputs_signing_data.push(output_data.input_signing_data(account, current_time, bech32_hrp)?);
+        // Outputs locked to an alias or nft address can only be unlocked if the alias or nft output is also an input
+        if !is_chain_output_transitioned(
+            account,
+            unlock_conditions.locked_address(&output_data.address, current_time),
+            outputs,
+        ) {
+            continue;
+        }
+
+        available_outputs_signing_data.push(output_data.input_signing_data(
+            account,
+            current_time,
+            bech32_hrp,
+            outputs,
+        )?);
     }
     Ok(available_outputs_signing_data)
 }
+
+// Check if the alias or nft output for an alias or nft address gets transitioned in the outputs, only then it's
+// added to the inputs and can unlock outputs locked to its address. An alias address requires a state transition.
+fn is_chain_output_transitioned(account: &Account, address: &Address, outputs: &[Output]) -> bool {
+    match address {
+        Address::Ed25519(_) => true,
+        Address::Alias(alias_address) => {
+            let alias_input = account
+                .unspent_outputs
+                .values()
+                .find_map(|output_data| match &output_data.output {
+                    Output::Alias(alias_input)
+                        if alias_input.alias_id().or_from_output_id(output_data.output_id)
+                            == *alias_address.alias_id() =>
+                    {
+                        Some(alias_input)
+                    }
+                    _ => None,
+                });
+            match alias_input {
+                Some(alias_input) => outputs.iter().any(|output| match output {
+                    Output::Alias(alias_output) => {
+                        alias_output.alias_id() == alias_address.alias_id()
+                            && alias_output.state_index() == alias_input.state_index() + 1
+                    }
+                    _ => false,
+                }),
+                None => false,
+            }
+        }
+        Address::Nft(nft_address) => outputs.iter().any(|output| match output {
+            Output::Nft(nft_output) => nft_output.nft_id() == nft_address.nft_id(),
+            _ => false,
+        }),
+    }
+}
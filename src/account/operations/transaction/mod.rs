@@ -5,24 +5,33 @@ mod build_transaction;
 pub(crate) mod high_level;
 mod input_selection;
 mod options;
+mod outbox;
 pub(crate) mod prepare_output;
 mod prepare_transaction;
 mod sign_transaction;
 pub(crate) mod submit_transaction;
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    collections::HashMap,
+    future::Future,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use iota_client::{
-    api::{verify_semantic, PreparedTransactionData, SignedTransactionData},
-    bee_block::{output::Output, payload::transaction::TransactionPayload, semantic::ConflictReason},
+    api::{
+        verify_semantic, PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData,
+        SignedTransactionDataDto,
+    },
+    bee_block::{output::Output, payload::transaction::TransactionPayload, semantic::ConflictReason, BlockId},
     secret::types::InputSigningData,
 };
 
+pub(crate) use self::options::RESERVED_IDEMPOTENCY_KEY_PREFIX;
 pub use self::options::{RemainderValueStrategy, TransactionOptions};
 use crate::{
     account::{
         handle::AccountHandle,
-        types::{InclusionState, Transaction},
+        types::{InclusionState, OutboxStage, Transaction},
     },
     iota_client::Error,
 };
@@ -73,37 +82,139 @@ impl AccountHandle {
     ) -> crate::Result<Transaction> {
         log::debug!("[TRANSACTION] finish_transaction");
 
-        let prepared_transaction_data = self.prepare_transaction(outputs, options).await?;
+        let prepare_transaction = self.prepare_transaction(outputs, options.clone());
+        self.prepare_sign_and_submit_transaction(&options, prepare_transaction)
+            .await
+    }
+
+    /// Prepare a transaction with `prepare_transaction`, sign it, submit it and store it in the account. If the options
+    /// have an idempotency key, it's reserved before the inputs are selected: a transaction that was already created
+    /// with the key is returned instead and a concurrent call with the same key fails with
+    /// [`Error::IdempotencyKeyInUse`](crate::Error::IdempotencyKeyInUse).
+    pub(crate) async fn prepare_sign_and_submit_transaction(
+        &self,
+        options: &Option<TransactionOptions>,
+        prepare_transaction: impl Future<Output = crate::Result<PreparedTransactionData>>,
+    ) -> crate::Result<Transaction> {
+        let idempotency_key = options.as_ref().and_then(|options| options.idempotency_key.clone());
+        if let Some(idempotency_key) = &idempotency_key {
+            if idempotency_key.starts_with(RESERVED_IDEMPOTENCY_KEY_PREFIX) {
+                return Err(crate::Error::InvalidIdempotencyKey(idempotency_key.clone()));
+            }
+        }
+        self.prepare_sign_and_submit_idempotent_transaction(idempotency_key, prepare_transaction)
+            .await
+    }
+
+    /// Like [`AccountHandle::prepare_sign_and_submit_transaction()`], but also accepts the reserved idempotency keys
+    /// of the wallet itself, for example from scheduled payments
+    pub(crate) async fn prepare_sign_and_submit_idempotent_transaction(
+        &self,
+        idempotency_key: Option<String>,
+        prepare_transaction: impl Future<Output = crate::Result<PreparedTransactionData>>,
+    ) -> crate::Result<Transaction> {
+        let idempotency_key = match idempotency_key {
+            Some(idempotency_key) => idempotency_key,
+            None => {
+                let prepared_transaction_data = prepare_transaction.await?;
+                return self
+                    .sign_and_submit_transaction_internal(prepared_transaction_data, None)
+                    .await;
+            }
+        };
+
+        {
+            let account = self.write().await;
+            if let Some(transaction) = account
+                .idempotency_keys
+                .get(&idempotency_key)
+                .and_then(|transaction_id| account.transactions.get(transaction_id))
+            {
+                log::debug!("[TRANSACTION] returning existing transaction for idempotency key {idempotency_key}");
+                return Ok(transaction.clone());
+            }
+            let mut idempotency_keys_in_flight = self.idempotency_keys_in_flight.lock().await;
+            // An outbox entry with the key is a transaction that didn't finish before the process stopped, it will be
+            // resumed by [`AccountHandle::reconcile_outbox()`]
+            if idempotency_keys_in_flight.contains(&idempotency_key)
+                || account
+                    .outbox
+                    .values()
+                    .any(|entry| entry.idempotency_key.as_ref() == Some(&idempotency_key))
+            {
+                return Err(crate::Error::IdempotencyKeyInUse(idempotency_key));
+            }
+            idempotency_keys_in_flight.insert(idempotency_key.clone());
+        }
 
-        self.sign_and_submit_transaction(prepared_transaction_data).await
+        let result = async {
+            let prepared_transaction_data = prepare_transaction.await?;
+            self.sign_and_submit_transaction_internal(prepared_transaction_data, Some(idempotency_key.clone()))
+                .await
+        }
+        .await;
+
+        self.idempotency_keys_in_flight.lock().await.remove(&idempotency_key);
+        result
     }
 
     /// Sign a transaction, submit it to a node and store it in the account
     pub async fn sign_and_submit_transaction(
         &self,
         prepared_transaction_data: PreparedTransactionData,
+    ) -> crate::Result<Transaction> {
+        self.sign_and_submit_transaction_internal(prepared_transaction_data, None)
+            .await
+    }
+
+    /// Sign a transaction, submit it to a node and store it in the account together with the idempotency key
+    pub(crate) async fn sign_and_submit_transaction_internal(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+        idempotency_key: Option<String>,
     ) -> crate::Result<Transaction> {
         log::debug!("[TRANSACTION] sign_and_submit_transaction");
 
+        let essence_hash = hex::encode(prepared_transaction_data.essence.hash());
+        self.update_outbox_entry(
+            essence_hash.clone(),
+            OutboxStage::Prepared(PreparedTransactionDataDto::from(&prepared_transaction_data)),
+            idempotency_key.clone(),
+        )
+        .await?;
+
         let signed_transaction_data = match self.sign_transaction_essence(&prepared_transaction_data).await {
             Ok(res) => res,
             Err(err) => {
-                // unlock outputs so they are available for a new transaction
-                self.unlock_inputs(prepared_transaction_data.inputs_data).await?;
+                self.discard_outbox_entry(&essence_hash, &prepared_transaction_data.inputs_data)
+                    .await;
                 return Err(err);
             }
         };
 
-        self.submit_and_store_transaction(signed_transaction_data).await
+        self.submit_and_store_transaction_internal(signed_transaction_data, idempotency_key)
+            .await
     }
 
     /// Validate the transaction, submit it to a node and store it in the account
     pub async fn submit_and_store_transaction(
         &self,
         signed_transaction_data: SignedTransactionData,
+    ) -> crate::Result<Transaction> {
+        self.submit_and_store_transaction_internal(signed_transaction_data, None)
+            .await
+    }
+
+    /// Validate the transaction, submit it to a node and store it in the account together with the idempotency key
+    pub(crate) async fn submit_and_store_transaction_internal(
+        &self,
+        signed_transaction_data: SignedTransactionData,
+        idempotency_key: Option<String>,
     ) -> crate::Result<Transaction> {
         log::debug!("[TRANSACTION] submit_and_store_transaction");
 
+        let essence_hash = hex::encode(signed_transaction_data.transaction_payload.essence().hash());
+
         // Validate transaction before sending and storing it
         let local_time = self.client.get_time_checked().await?;
 
@@ -115,11 +226,19 @@ impl AccountHandle {
 
         if conflict != ConflictReason::None {
             log::debug!("[TRANSACTION] conflict: {conflict:?}");
-            // unlock outputs so they are available for a new transaction
-            self.unlock_inputs(signed_transaction_data.inputs_data).await?;
+            self.discard_outbox_entry(&essence_hash, &signed_transaction_data.inputs_data)
+                .await;
             return Err(Error::TransactionSemantic(conflict).into());
         }
 
+        // Persist the signed transaction before submitting it, so we don't lose track of it if the process crashes
+        self.update_outbox_entry(
+            essence_hash.clone(),
+            OutboxStage::Signed(SignedTransactionDataDto::from(&signed_transaction_data)),
+            idempotency_key.clone(),
+        )
+        .await?;
+
         // Ignore errors from sending, we will try to send it again during [`sync_pending_transactions`]
         let block_id = match self
             .submit_transaction_payload(signed_transaction_data.transaction_payload.clone())
@@ -132,12 +251,43 @@ impl AccountHandle {
             }
         };
 
+        // Persist the block id, so the submitted block is checked first if the process crashes before the transaction
+        // is stored
+        if let Some(block_id) = block_id {
+            self.update_outbox_entry(
+                essence_hash.clone(),
+                OutboxStage::Submitted {
+                    block_id,
+                    payload: signed_transaction_data.transaction_payload.clone(),
+                },
+                idempotency_key.clone(),
+            )
+            .await?;
+        }
+
+        self.store_transaction(
+            signed_transaction_data.transaction_payload,
+            block_id,
+            idempotency_key,
+            &essence_hash,
+        )
+        .await
+    }
+
+    /// Store a sent transaction in the account and remove its outbox entry
+    async fn store_transaction(
+        &self,
+        transaction_payload: TransactionPayload,
+        block_id: Option<BlockId>,
+        idempotency_key: Option<String>,
+        essence_hash: &str,
+    ) -> crate::Result<Transaction> {
         // store transaction payload to account (with db feature also store the account to the db)
         let network_id = self.client.get_network_id().await?;
-        let transaction_id = signed_transaction_data.transaction_payload.id();
+        let transaction_id = transaction_payload.id();
         let transaction = Transaction {
-            transaction_id: signed_transaction_data.transaction_payload.id(),
-            payload: signed_transaction_data.transaction_payload,
+            transaction_id,
+            payload: transaction_payload,
             block_id,
             network_id,
             timestamp: SystemTime::now()
@@ -146,12 +296,18 @@ impl AccountHandle {
                 .as_millis(),
             inclusion_state: InclusionState::Pending,
             incoming: false,
+            idempotency_key,
+            returned_outputs: HashMap::new(),
         };
 
         let mut account = self.write().await;
 
+        if let Some(idempotency_key) = &transaction.idempotency_key {
+            account.idempotency_keys.insert(idempotency_key.clone(), transaction_id);
+        }
         account.transactions.insert(transaction_id, transaction.clone());
         account.pending_transactions.insert(transaction_id);
+        account.outbox.remove(essence_hash);
         #[cfg(feature = "storage")]
         {
             log::debug!("[TRANSACTION] storing account {}", account.index());
@@ -6,6 +6,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::account::types::address::AccountAddress;
 
+/// Prefix of the idempotency keys the wallet uses itself, client supplied keys with it are rejected so they can't
+/// collide with them
+pub(crate) const RESERVED_IDEMPOTENCY_KEY_PREFIX: &str = "iota-wallet/";
+
 /// Options for transactions
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct TransactionOptions {
@@ -17,6 +21,11 @@ pub struct TransactionOptions {
     pub custom_inputs: Option<Vec<OutputId>>,
     #[serde(rename = "allowBurning", default)]
     pub allow_burning: bool,
+    /// Client supplied key to make sending idempotent, if a transaction with the same key was already created by
+    /// this account, it will be returned instead of creating a new one. Keys starting with `iota-wallet/` are reserved
+    /// for transactions the wallet sends itself, like scheduled payments
+    #[serde(rename = "idempotencyKey", default)]
+    pub idempotency_key: Option<String>,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -0,0 +1,189 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use iota_client::{
+    api::{PreparedTransactionData, SignedTransactionData},
+    bee_block::{payload::transaction::TransactionPayload, BlockId},
+    secret::types::InputSigningData,
+};
+
+use crate::account::{
+    handle::AccountHandle,
+    types::{OutboxEntry, OutboxStage, Transaction},
+};
+
+impl AccountHandle {
+    /// Returns the transactions which were created, but not stored in the account yet
+    pub async fn list_outbox_entries(&self) -> Vec<OutboxEntry> {
+        let account = self.read().await;
+        account.outbox.values().cloned().collect()
+    }
+
+    /// Resume or reconcile transactions which didn't finish, for example because the process crashed.
+    /// Entries which were only prepared never left the wallet, so they're removed and their inputs unlocked. Signed
+    /// entries are stored if the node already included them and submitted again otherwise. Submitted entries are
+    /// stored with the block they were submitted in, if the node still knows it, syncing will then update the inclusion
+    /// state. Entries which fail are logged and kept for the next try.
+    pub async fn reconcile_outbox(&self) -> crate::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] reconcile_outbox");
+        let entries = self.list_outbox_entries().await;
+
+        let mut transactions = Vec::new();
+        for entry in entries {
+            let transaction = match entry.stage {
+                OutboxStage::Prepared(prepared_transaction_data) => {
+                    log::debug!("[TRANSACTION] removing not signed outbox entry {}", entry.essence_hash);
+                    match PreparedTransactionData::try_from(&prepared_transaction_data) {
+                        Ok(prepared_transaction_data) => {
+                            self.discard_outbox_entry(&entry.essence_hash, &prepared_transaction_data.inputs_data)
+                                .await
+                        }
+                        Err(err) => log::error!("Failed to read outbox entry {}: {err}", entry.essence_hash),
+                    }
+                    continue;
+                }
+                OutboxStage::Signed(signed_transaction_data) => {
+                    let signed_transaction_data = match SignedTransactionData::try_from(&signed_transaction_data) {
+                        Ok(signed_transaction_data) => signed_transaction_data,
+                        Err(err) => {
+                            log::error!("Failed to read outbox entry {}: {err}", entry.essence_hash);
+                            continue;
+                        }
+                    };
+                    let transaction_id = signed_transaction_data.transaction_payload.id();
+                    // The transaction could have been submitted before the process stopped
+                    match self.client.get_included_block(&transaction_id).await {
+                        Ok(included_block) => {
+                            log::debug!("[TRANSACTION] outbox transaction {transaction_id} is already included");
+                            self.store_transaction(
+                                signed_transaction_data.transaction_payload,
+                                Some(included_block.id()),
+                                entry.idempotency_key,
+                                &entry.essence_hash,
+                            )
+                            .await
+                        }
+                        Err(iota_client::Error::NotFound) => {
+                            log::debug!("[TRANSACTION] submitting outbox transaction {transaction_id} again");
+                            // On a conflict the entry gets removed, other errors keep it for the next try
+                            self.submit_and_store_transaction_internal(signed_transaction_data, entry.idempotency_key)
+                                .await
+                        }
+                        // The node couldn't tell if the transaction got included, so the entry is kept for the next try
+                        Err(err) => Err(err.into()),
+                    }
+                }
+                OutboxStage::Submitted { block_id, payload } => {
+                    let transaction_id = payload.id();
+                    let block_id = match self.submitted_block_id(block_id, &payload).await {
+                        Ok(block_id) => block_id,
+                        Err(err) => {
+                            log::error!(
+                                "Failed to get the inclusion state of outbox transaction {transaction_id}: {err}"
+                            );
+                            continue;
+                        }
+                    };
+                    self.store_transaction(payload, block_id, entry.idempotency_key, &entry.essence_hash)
+                        .await
+                }
+            };
+            match transaction {
+                Ok(transaction) => transactions.push(transaction),
+                Err(err) => log::error!("Failed to reconcile outbox entry {}: {err}", entry.essence_hash),
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// Returns the block id to store for a transaction that was already submitted. The block it was submitted in is
+    /// kept if the node knows it, otherwise the transaction could be included in another block or is submitted again.
+    /// A failed submission returns `None`, so syncing retries it like any other pending transaction.
+    async fn submitted_block_id(
+        &self,
+        block_id: BlockId,
+        payload: &TransactionPayload,
+    ) -> crate::Result<Option<BlockId>> {
+        match self.client.get_block_metadata(&block_id).await {
+            Ok(_) => {
+                log::debug!(
+                    "[TRANSACTION] outbox transaction {} was submitted in block {block_id}",
+                    payload.id()
+                );
+                return Ok(Some(block_id));
+            }
+            Err(iota_client::Error::NotFound) => {}
+            Err(err) => return Err(err.into()),
+        }
+
+        match self.client.get_included_block(&payload.id()).await {
+            Ok(included_block) => Ok(Some(included_block.id())),
+            Err(iota_client::Error::NotFound) => {
+                log::debug!("[TRANSACTION] submitting outbox transaction {} again", payload.id());
+                match self.submit_transaction_payload(payload.clone()).await {
+                    Ok(block_id) => Ok(Some(block_id)),
+                    Err(err) => {
+                        log::error!("Failed to submit_transaction_payload {}", err);
+                        Ok(None)
+                    }
+                }
+            }
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Insert or update the outbox entry for a transaction and persist it, so every stage the transaction reached is
+    /// known after a crash
+    pub(crate) async fn update_outbox_entry(
+        &self,
+        essence_hash: String,
+        stage: OutboxStage,
+        idempotency_key: Option<String>,
+    ) -> crate::Result<()> {
+        let mut account = self.write().await;
+        match account.outbox.get_mut(&essence_hash) {
+            Some(entry) => entry.stage = stage,
+            None => {
+                let entry = OutboxEntry {
+                    essence_hash: essence_hash.clone(),
+                    stage,
+                    idempotency_key,
+                    timestamp: SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .expect("Time went backwards")
+                        .as_millis(),
+                };
+                account.outbox.insert(essence_hash, entry);
+            }
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+
+    /// Unlock the inputs of a transaction that failed, so they are available for a new transaction, and remove its
+    /// outbox entry. Errors are only logged, so they don't hide the error of the transaction
+    pub(crate) async fn discard_outbox_entry(&self, essence_hash: &str, inputs: &[InputSigningData]) {
+        let mut account = self.write().await;
+        for input_signing_data in inputs {
+            match input_signing_data.output_id() {
+                Ok(output_id) => {
+                    account.locked_outputs.remove(&output_id);
+                    log::debug!("[TRANSACTION] Unlocked output {output_id} because of transaction error");
+                }
+                Err(err) => log::error!("Failed to unlock input of outbox transaction {essence_hash}: {err}"),
+            }
+        }
+        #[cfg(feature = "storage")]
+        if account.outbox.remove(essence_hash).is_some() {
+            if let Err(err) = self.save(Some(&account)).await {
+                log::error!("Failed to save account after removing outbox entry {essence_hash}: {err}");
+            }
+        }
+        #[cfg(not(feature = "storage"))]
+        account.outbox.remove(essence_hash);
+    }
+}
@@ -457,6 +457,19 @@ impl TryFrom<&OutputOptionsDto> for OutputOptions {
     }
 }
 
+impl From<&OutputOptions> for OutputOptionsDto {
+    fn from(value: &OutputOptions) -> Self {
+        Self {
+            recipient_address: value.recipient_address.clone(),
+            amount: value.amount.to_string(),
+            assets: value.assets.as_ref().map(AssetsDto::from),
+            features: value.features.clone(),
+            unlocks: value.unlocks.clone(),
+            storage_deposit: value.storage_deposit.clone(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct AssetsDto {
     #[serde(rename = "nativeTokens")]
@@ -485,3 +498,15 @@ impl TryFrom<&AssetsDto> for Assets {
         })
     }
 }
+
+impl From<&Assets> for AssetsDto {
+    fn from(value: &Assets) -> Self {
+        Self {
+            native_tokens: value
+                .native_tokens
+                .as_ref()
+                .map(|native_tokens| native_tokens.iter().map(NativeTokenDto::from).collect()),
+            nft_id: value.nft_id.map(|nft_id| nft_id.to_string()),
+        }
+    }
+}
@@ -61,9 +61,12 @@ impl AccountHandle {
                     let mut input_outputs = Vec::new();
                     for output_id in inputs {
                         match account.unspent_outputs().get(output_id) {
-                            Some(output) => {
-                                input_outputs.push(output.input_signing_data(&account, current_time, &bech32_hrp)?)
-                            }
+                            Some(output) => input_outputs.push(output.input_signing_data(
+                                &account,
+                                current_time,
+                                &bech32_hrp,
+                                &outputs,
+                            )?),
                             None => {
                                 return Err(crate::Error::CustomInputError(format!(
                                     "Custom input {} not found in unspent outputs",
@@ -0,0 +1,367 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use iota_client::bee_block::{
+    address::Address,
+    dto::U256Dto,
+    input::Input,
+    output::{NftId, Output, OutputId, TokenId},
+    payload::{
+        tagged_data::TaggedDataPayload,
+        transaction::{TransactionEssence, TransactionId},
+        Payload,
+    },
+};
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::account::{
+    handle::AccountHandle,
+    operations::transaction_query::incoming_transaction,
+    types::{InclusionState, Transaction},
+    Account,
+};
+
+/// A transaction decoded into its effect on the account
+#[derive(Debug, Clone, Serialize)]
+pub struct DecodedTransaction {
+    /// The transaction id
+    #[serde(rename = "transactionId")]
+    pub transaction_id: TransactionId,
+    /// If the transaction was sent by someone else
+    pub incoming: bool,
+    /// Inclusion state of the transaction
+    #[serde(rename = "inclusionState")]
+    pub inclusion_state: InclusionState,
+    /// Timestamp in milliseconds
+    pub timestamp: u128,
+    /// Net change of the base coin balance of the account
+    #[serde(rename = "baseCoinChange")]
+    pub base_coin_change: i128,
+    /// Net change of the native token balances of the account, tokens without a change are omitted
+    #[serde(rename = "nativeTokenChanges")]
+    pub native_token_changes: Vec<NativeTokenChange>,
+    /// Nfts the account received with the transaction
+    #[serde(rename = "nftsGained")]
+    pub nfts_gained: Vec<NftId>,
+    /// Nfts the account sent with the transaction
+    #[serde(rename = "nftsLost")]
+    pub nfts_lost: Vec<NftId>,
+    /// Bech32 encoded addresses of the inputs and sender features. The inputs of incoming transactions are only known
+    /// if they were synced with `sync_incoming_transactions`
+    pub senders: Vec<String>,
+    /// The outputs of the transaction
+    pub recipients: Vec<TransactionRecipient>,
+    /// The tagged data payload of the transaction
+    #[serde(rename = "taggedData")]
+    pub tagged_data: Option<TaggedDataPayload>,
+}
+
+/// Net change of a native token balance in a [`DecodedTransaction`]
+#[derive(Debug, Clone, Serialize)]
+pub struct NativeTokenChange {
+    /// The token id
+    #[serde(rename = "tokenId")]
+    pub token_id: TokenId,
+    /// The amount the balance changed
+    pub amount: U256,
+    /// If the balance decreased
+    #[serde(rename = "isDecrease")]
+    pub is_decrease: bool,
+}
+
+/// An output of a [`DecodedTransaction`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionRecipient {
+    /// Index of the output in the transaction
+    #[serde(rename = "outputIndex")]
+    pub output_index: u16,
+    /// Bech32 encoded address of the address unlock condition, None for alias and foundry outputs
+    pub address: Option<String>,
+    /// Amount of the output
+    pub amount: u64,
+    /// If the address unlock condition, or the state controller of an alias output, is an address of the account
+    #[serde(rename = "ownAddress")]
+    pub own_address: bool,
+}
+
+/// Dto for [`DecodedTransaction`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecodedTransactionDto {
+    /// The transaction id
+    #[serde(rename = "transactionId")]
+    pub transaction_id: TransactionId,
+    /// If the transaction was sent by someone else
+    pub incoming: bool,
+    /// Inclusion state of the transaction
+    #[serde(rename = "inclusionState")]
+    pub inclusion_state: InclusionState,
+    /// Timestamp in milliseconds
+    pub timestamp: String,
+    /// Net change of the base coin balance of the account
+    #[serde(rename = "baseCoinChange")]
+    pub base_coin_change: String,
+    /// Net change of the native token balances of the account
+    #[serde(rename = "nativeTokenChanges")]
+    pub native_token_changes: Vec<NativeTokenChangeDto>,
+    /// Nfts the account received with the transaction
+    #[serde(rename = "nftsGained")]
+    pub nfts_gained: Vec<NftId>,
+    /// Nfts the account sent with the transaction
+    #[serde(rename = "nftsLost")]
+    pub nfts_lost: Vec<NftId>,
+    /// Bech32 encoded addresses of the inputs and sender features
+    pub senders: Vec<String>,
+    /// The outputs of the transaction
+    pub recipients: Vec<TransactionRecipientDto>,
+    /// The tagged data payload of the transaction
+    #[serde(rename = "taggedData")]
+    pub tagged_data: Option<TaggedDataPayload>,
+}
+
+impl From<&DecodedTransaction> for DecodedTransactionDto {
+    fn from(value: &DecodedTransaction) -> Self {
+        Self {
+            transaction_id: value.transaction_id,
+            incoming: value.incoming,
+            inclusion_state: value.inclusion_state,
+            timestamp: value.timestamp.to_string(),
+            base_coin_change: value.base_coin_change.to_string(),
+            native_token_changes: value
+                .native_token_changes
+                .iter()
+                .map(NativeTokenChangeDto::from)
+                .collect(),
+            nfts_gained: value.nfts_gained.clone(),
+            nfts_lost: value.nfts_lost.clone(),
+            senders: value.senders.clone(),
+            recipients: value.recipients.iter().map(TransactionRecipientDto::from).collect(),
+            tagged_data: value.tagged_data.clone(),
+        }
+    }
+}
+
+/// Dto for [`NativeTokenChange`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NativeTokenChangeDto {
+    /// The token id
+    #[serde(rename = "tokenId")]
+    pub token_id: TokenId,
+    /// The amount the balance changed
+    pub amount: U256Dto,
+    /// If the balance decreased
+    #[serde(rename = "isDecrease")]
+    pub is_decrease: bool,
+}
+
+impl From<&NativeTokenChange> for NativeTokenChangeDto {
+    fn from(value: &NativeTokenChange) -> Self {
+        Self {
+            token_id: value.token_id,
+            amount: U256Dto::from(&value.amount),
+            is_decrease: value.is_decrease,
+        }
+    }
+}
+
+/// Dto for [`TransactionRecipient`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionRecipientDto {
+    /// Index of the output in the transaction
+    #[serde(rename = "outputIndex")]
+    pub output_index: u16,
+    /// Bech32 encoded address of the address unlock condition
+    pub address: Option<String>,
+    /// Amount of the output
+    pub amount: String,
+    /// If the address unlock condition, or the state controller of an alias output, is an address of the account
+    #[serde(rename = "ownAddress")]
+    pub own_address: bool,
+}
+
+impl From<&TransactionRecipient> for TransactionRecipientDto {
+    fn from(value: &TransactionRecipient) -> Self {
+        Self {
+            output_index: value.output_index,
+            address: value.address.clone(),
+            amount: value.amount.to_string(),
+            own_address: value.own_address,
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Decode a sent or incoming transaction of the account into its effect on the account, returns None if the
+    /// transaction is unknown
+    pub async fn decode_transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> crate::Result<Option<DecodedTransaction>> {
+        log::debug!("[decode_transaction] {}", transaction_id);
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let account = self.read().await;
+        let transaction = match account.transactions.get(transaction_id) {
+            Some(transaction) => Some(transaction.clone()),
+            None => account
+                .incoming_transactions
+                .get(transaction_id)
+                .and_then(|(payload, _inputs)| incoming_transaction(&account, transaction_id, payload)),
+        };
+        match transaction {
+            Some(transaction) => Ok(Some(decode_transaction(&account, &transaction, &bech32_hrp)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+pub(crate) fn decode_transaction(
+    account: &Account,
+    transaction: &Transaction,
+    bech32_hrp: &str,
+) -> crate::Result<DecodedTransaction> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    // Outputs of pending transactions aren't synced yet, so their addresses are checked too
+    let is_own_address = |address: &Address| {
+        account
+            .public_addresses
+            .iter()
+            .chain(account.internal_addresses.iter())
+            .any(|account_address| account_address.address.inner == *address)
+    };
+
+    let mut base_coin_change: i128 = 0;
+    // Received and sent amount per token
+    let mut native_tokens: Vec<(TokenId, U256, U256)> = Vec::new();
+    let mut nfts_gained = Vec::new();
+    let mut nfts_lost = Vec::new();
+    let mut senders: Vec<String> = Vec::new();
+    let mut add_sender = |address: &Address| {
+        let bech32_address = address.to_bech32(bech32_hrp);
+        if !senders.contains(&bech32_address) {
+            senders.push(bech32_address);
+        }
+    };
+
+    for input in essence.inputs() {
+        if let Input::Utxo(input) = input {
+            // Only inputs of the account are known, except for synced incoming transactions
+            if let Some(output_data) = account.outputs.get(input.output_id()) {
+                add_sender(&output_data.address);
+                base_coin_change -= output_data.output.amount() as i128;
+                add_native_tokens(&mut native_tokens, &output_data.output, false);
+                if let Output::Nft(nft_output) = &output_data.output {
+                    nfts_lost.push(nft_output.nft_id().or_from_output_id(output_data.output_id));
+                }
+            }
+        }
+    }
+    if let Some((_payload, inputs)) = account.incoming_transactions.get(&transaction.transaction_id) {
+        for input in inputs {
+            let output = Output::try_from(&input.output)?;
+            if let Some(address) = output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.address())
+            {
+                add_sender(address.address());
+            }
+        }
+    }
+
+    let mut recipients = Vec::new();
+    for (index, output) in essence.outputs().iter().enumerate() {
+        let output_id = OutputId::new(transaction.transaction_id, index as u16)?;
+        if let Some(sender) = output.features().and_then(|features| features.sender()) {
+            add_sender(sender.address());
+        }
+        let address = output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.address())
+            .map(|unlock_condition| *unlock_condition.address());
+        // Alias outputs belong to their state controller, the other outputs to their address unlock condition
+        let owner = match output {
+            Output::Alias(alias_output) => Some(alias_output.state_controller_address()),
+            _ => address.as_ref(),
+        };
+        let own_address = owner.map_or(false, is_own_address);
+        if own_address {
+            base_coin_change += output.amount() as i128;
+            add_native_tokens(&mut native_tokens, output, true);
+            if let Output::Nft(nft_output) = output {
+                nfts_gained.push(nft_output.nft_id().or_from_output_id(output_id));
+            }
+        }
+        recipients.push(TransactionRecipient {
+            output_index: index as u16,
+            address: address.map(|address| address.to_bech32(bech32_hrp)),
+            amount: output.amount(),
+            own_address,
+        });
+    }
+
+    // Nfts that were only moved between addresses of the account didn't change
+    let moved_nfts = nfts_gained
+        .iter()
+        .filter(|nft_id| nfts_lost.contains(nft_id))
+        .copied()
+        .collect::<HashSet<NftId>>();
+    nfts_gained.retain(|nft_id| !moved_nfts.contains(nft_id));
+    nfts_lost.retain(|nft_id| !moved_nfts.contains(nft_id));
+
+    let native_token_changes = native_tokens
+        .into_iter()
+        .filter(|(_token_id, received, sent)| received != sent)
+        .map(|(token_id, received, sent)| NativeTokenChange {
+            token_id,
+            amount: if received > sent {
+                received - sent
+            } else {
+                sent - received
+            },
+            is_decrease: sent > received,
+        })
+        .collect();
+
+    let tagged_data = match essence.payload() {
+        Some(Payload::TaggedData(tagged_data)) => Some(tagged_data.as_ref().clone()),
+        _ => None,
+    };
+
+    Ok(DecodedTransaction {
+        transaction_id: transaction.transaction_id,
+        incoming: transaction.incoming,
+        inclusion_state: transaction.inclusion_state,
+        timestamp: transaction.timestamp,
+        base_coin_change,
+        native_token_changes,
+        nfts_gained,
+        nfts_lost,
+        senders,
+        recipients,
+        tagged_data,
+    })
+}
+
+// Add the native tokens of an output to the received or sent amounts
+fn add_native_tokens(native_tokens: &mut Vec<(TokenId, U256, U256)>, output: &Output, received: bool) {
+    if let Some(output_native_tokens) = output.native_tokens() {
+        for native_token in output_native_tokens.iter() {
+            let index = match native_tokens
+                .iter()
+                .position(|(token_id, _received, _sent)| token_id == native_token.token_id())
+            {
+                Some(index) => index,
+                None => {
+                    native_tokens.push((*native_token.token_id(), U256::from(0u8), U256::from(0u8)));
+                    native_tokens.len() - 1
+                }
+            };
+            if received {
+                native_tokens[index].1 += *native_token.amount();
+            } else {
+                native_tokens[index].2 += *native_token.amount();
+            }
+        }
+    }
+}
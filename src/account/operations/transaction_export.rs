@@ -0,0 +1,362 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::{
+    payload::transaction::{TransactionEssence, TransactionId},
+    BlockId,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::account::{
+    handle::AccountHandle,
+    operations::{
+        annotations::TransactionAnnotations,
+        transaction_decoding::{decode_transaction, DecodedTransaction},
+        transaction_query::{AssetType, SortOrder, TransactionFilter, TransactionQuery},
+    },
+    types::{InclusionState, Transaction},
+};
+
+/// File formats for [`AccountHandle::export_transactions()`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ExportFormat {
+    /// Comma separated values with a header row
+    Csv,
+    /// One JSON object per line
+    JsonLines,
+}
+
+/// Options for [`AccountHandle::export_transactions()`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportOptions {
+    /// The file format
+    pub format: ExportFormat,
+    /// Only transactions with a timestamp at or after this time in milliseconds
+    #[serde(rename = "fromTime", default)]
+    pub from_time: Option<u128>,
+    /// Only transactions with a timestamp before this time in milliseconds
+    #[serde(rename = "toTime", default)]
+    pub to_time: Option<u128>,
+}
+
+/// Direction of an [`ExportRecord`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ExportDirection {
+    /// The account received the asset
+    Incoming,
+    /// The account sent the asset
+    Outgoing,
+}
+
+/// A row of the transaction export, every transaction has a row for the base coin and one for each native token and
+/// nft that changed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportRecord {
+    /// Alias of the account
+    pub account: String,
+    /// Timestamp in milliseconds
+    pub timestamp: u128,
+    /// The transaction id
+    #[serde(rename = "transactionId")]
+    pub transaction_id: TransactionId,
+    /// The block id
+    #[serde(rename = "blockId")]
+    pub block_id: Option<BlockId>,
+    /// If the asset was received or sent
+    pub direction: ExportDirection,
+    /// Bech32 encoded addresses of the senders for incoming and of the recipients for outgoing records
+    pub counterparties: Vec<String>,
+    /// The asset type, only base coin, native tokens and nfts are exported
+    #[serde(rename = "assetType")]
+    pub asset_type: AssetType,
+    /// The token id or nft id
+    #[serde(rename = "assetId")]
+    pub asset_id: Option<String>,
+    /// The net amount, negative for outgoing records
+    pub amount: String,
+    /// Storage deposit that has to be returned by or to the account, part of the base coin amount
+    #[serde(rename = "storageDeposit")]
+    pub storage_deposit: u64,
+    /// Inclusion state of the transaction
+    #[serde(rename = "inclusionState")]
+    pub inclusion_state: InclusionState,
+    /// Note of the user
+    pub note: Option<String>,
+}
+
+const CSV_HEADER: &str = "account,timestamp,transactionId,blockId,direction,counterparties,assetType,assetId,amount,\
+                          storageDeposit,inclusionState,note";
+
+impl AccountHandle {
+    /// Export the sent and incoming transactions of the account for accounting, sorted by their timestamp
+    /// ```ignore
+    /// let csv = account_handle
+    ///     .export_transactions(ExportOptions {
+    ///         format: ExportFormat::Csv,
+    ///         from_time: Some(1_640_995_200_000),
+    ///         to_time: None,
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn export_transactions(&self, options: ExportOptions) -> crate::Result<String> {
+        let records = self.export_records(&options).await?;
+        format_export_records(&records, options.format)
+    }
+
+    /// Build the export records of the account
+    pub(crate) async fn export_records(&self, options: &ExportOptions) -> crate::Result<Vec<ExportRecord>> {
+        log::debug!("[export_transactions]");
+        let transactions = self
+            .query_transactions(TransactionQuery {
+                filter: TransactionFilter {
+                    from_time: options.from_time,
+                    to_time: options.to_time,
+                    ..Default::default()
+                },
+                sort: SortOrder::Ascending,
+                cursor: None,
+                limit: Some(usize::MAX),
+            })
+            .await?
+            .transactions;
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+
+        let account = self.read().await;
+        let annotations = TransactionAnnotations::new(&account);
+        let mut records = Vec::new();
+        for transaction in &transactions {
+            let decoded_transaction = decode_transaction(&account, transaction, &bech32_hrp)?;
+            // Notes of the transaction and of its outputs
+            let notes = annotations
+                .get(&transaction.transaction_id)
+                .filter_map(|annotation| annotation.note.clone())
+                .collect::<Vec<String>>();
+            let note = if notes.is_empty() { None } else { Some(notes.join("; ")) };
+            records.extend(transaction_records(
+                account.alias(),
+                transaction,
+                &decoded_transaction,
+                note,
+            ));
+        }
+
+        Ok(records)
+    }
+}
+
+// Create the records for the base coin, native tokens and nfts of a transaction
+fn transaction_records(
+    account_alias: &str,
+    transaction: &Transaction,
+    decoded_transaction: &DecodedTransaction,
+    note: Option<String>,
+) -> Vec<ExportRecord> {
+    let fallback_direction = if transaction.incoming {
+        ExportDirection::Incoming
+    } else {
+        ExportDirection::Outgoing
+    };
+    let record = |direction: ExportDirection, asset_type: AssetType, asset_id: Option<String>, amount: String| {
+        let counterparties = match direction {
+            ExportDirection::Incoming => decoded_transaction
+                .senders
+                .iter()
+                .filter(|sender| {
+                    !decoded_transaction
+                        .recipients
+                        .iter()
+                        .any(|recipient| recipient.own_address && recipient.address.as_ref() == Some(*sender))
+                })
+                .cloned()
+                .collect(),
+            ExportDirection::Outgoing => decoded_transaction
+                .recipients
+                .iter()
+                .filter(|recipient| !recipient.own_address)
+                .filter_map(|recipient| recipient.address.clone())
+                .collect(),
+        };
+        ExportRecord {
+            account: account_alias.to_string(),
+            timestamp: transaction.timestamp,
+            transaction_id: transaction.transaction_id,
+            block_id: transaction.block_id,
+            direction,
+            counterparties,
+            asset_type,
+            asset_id,
+            amount,
+            storage_deposit: 0,
+            inclusion_state: transaction.inclusion_state,
+            note: note.clone(),
+        }
+    };
+
+    let base_coin_direction = match decoded_transaction.base_coin_change {
+        change if change > 0 => ExportDirection::Incoming,
+        change if change < 0 => ExportDirection::Outgoing,
+        _ => fallback_direction,
+    };
+    let mut base_coin_record = record(
+        base_coin_direction,
+        AssetType::BaseCoin,
+        None,
+        decoded_transaction.base_coin_change.to_string(),
+    );
+    base_coin_record.storage_deposit = storage_deposit(transaction, decoded_transaction, base_coin_direction);
+    let mut records = vec![base_coin_record];
+
+    for native_token_change in &decoded_transaction.native_token_changes {
+        let (direction, sign) = if native_token_change.is_decrease {
+            (ExportDirection::Outgoing, "-")
+        } else {
+            (ExportDirection::Incoming, "")
+        };
+        records.push(record(
+            direction,
+            AssetType::NativeToken,
+            Some(native_token_change.token_id.to_string()),
+            format!("{sign}{}", native_token_change.amount),
+        ));
+    }
+    for nft_id in &decoded_transaction.nfts_gained {
+        records.push(record(
+            ExportDirection::Incoming,
+            AssetType::Nft,
+            Some(nft_id.to_string()),
+            "1".to_string(),
+        ));
+    }
+    for nft_id in &decoded_transaction.nfts_lost {
+        records.push(record(
+            ExportDirection::Outgoing,
+            AssetType::Nft,
+            Some(nft_id.to_string()),
+            "-1".to_string(),
+        ));
+    }
+
+    records
+}
+
+// Sum of the storage deposit return amounts, for incoming records of the outputs the account received, for outgoing
+// records of the outputs the account sent to others
+fn storage_deposit(
+    transaction: &Transaction,
+    decoded_transaction: &DecodedTransaction,
+    direction: ExportDirection,
+) -> u64 {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    essence
+        .outputs()
+        .iter()
+        .zip(decoded_transaction.recipients.iter())
+        .filter(|(_output, recipient)| recipient.own_address == (direction == ExportDirection::Incoming))
+        .filter_map(|(output, _recipient)| {
+            output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+                .map(|sdr| sdr.amount())
+        })
+        .sum()
+}
+
+/// Format export records as CSV or JSON lines
+pub(crate) fn format_export_records(records: &[ExportRecord], format: ExportFormat) -> crate::Result<String> {
+    let mut lines = Vec::new();
+    match format {
+        ExportFormat::Csv => {
+            lines.push(CSV_HEADER.to_string());
+            for record in records {
+                let fields = [
+                    record.account.clone(),
+                    record.timestamp.to_string(),
+                    record.transaction_id.to_string(),
+                    record.block_id.map(|block_id| block_id.to_string()).unwrap_or_default(),
+                    format!("{:?}", record.direction),
+                    record.counterparties.join(" "),
+                    format!("{:?}", record.asset_type),
+                    record.asset_id.clone().unwrap_or_default(),
+                    record.amount.clone(),
+                    record.storage_deposit.to_string(),
+                    format!("{:?}", record.inclusion_state),
+                    record.note.clone().unwrap_or_default(),
+                ];
+                lines.push(
+                    fields
+                        .iter()
+                        .map(|field| escape_csv_field(field))
+                        .collect::<Vec<String>>()
+                        .join(","),
+                );
+            }
+        }
+        ExportFormat::JsonLines => {
+            for record in records {
+                lines.push(serde_json::to_string(record)?);
+            }
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+// Quote fields that contain separators, quotes or line breaks
+fn escape_csv_field(field: &str) -> String {
+    if field.contains(|c: char| matches!(c, ',' | '"' | '\n' | '\r')) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_escaping() {
+        assert_eq!(escape_csv_field("plain note"), "plain note");
+        assert_eq!(escape_csv_field(""), "");
+        assert_eq!(escape_csv_field("rent, march"), "\"rent, march\"");
+        assert_eq!(escape_csv_field("the \"big\" one"), "\"the \"\"big\"\" one\"");
+        assert_eq!(escape_csv_field("first\nsecond"), "\"first\nsecond\"");
+        assert_eq!(escape_csv_field("first\r\nsecond"), "\"first\r\nsecond\"");
+    }
+
+    #[test]
+    fn export_formats() {
+        let record = ExportRecord {
+            account: "Alice, Bob".to_string(),
+            timestamp: 1_660_000_000_000,
+            transaction_id: TransactionId::new([1; 32]),
+            block_id: None,
+            direction: ExportDirection::Outgoing,
+            counterparties: vec!["rms1a".to_string(), "rms1b".to_string()],
+            asset_type: AssetType::BaseCoin,
+            asset_id: None,
+            amount: "-1000000".to_string(),
+            storage_deposit: 0,
+            inclusion_state: InclusionState::Confirmed,
+            note: Some("say \"hi\"".to_string()),
+        };
+
+        let csv = format_export_records(&[record.clone()], ExportFormat::Csv).unwrap();
+        let lines = csv.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            format!(
+                "\"Alice, Bob\",1660000000000,{},,Outgoing,rms1a rms1b,BaseCoin,,-1000000,0,Confirmed,\"say \"\"hi\"\"\"",
+                TransactionId::new([1; 32])
+            )
+        );
+
+        let json_lines = format_export_records(&[record.clone(), record], ExportFormat::JsonLines).unwrap();
+        assert_eq!(json_lines.lines().count(), 2);
+        for line in json_lines.lines() {
+            let record: ExportRecord = serde_json::from_str(line).unwrap();
+            assert_eq!(record.note.as_deref(), Some("say \"hi\""));
+        }
+    }
+}
@@ -0,0 +1,445 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, str::FromStr};
+
+use iota_client::bee_block::{
+    address::Address,
+    output::{Output, TokenId},
+    payload::{
+        transaction::{TransactionEssence, TransactionId},
+        TransactionPayload,
+    },
+    BlockId,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::account::{
+    handle::AccountHandle,
+    operations::{
+        annotations::TransactionAnnotations,
+        helpers::pagination::{paginate, parse_cursor},
+    },
+    types::{InclusionState, OutputData, Transaction, TransactionDto},
+    Account,
+};
+
+/// The default amount of transactions per page
+pub(crate) const DEFAULT_TRANSACTION_QUERY_LIMIT: usize = 20;
+
+/// Query for [`AccountHandle::query_transactions()`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TransactionQuery {
+    /// Only transactions that match all set fields of the filter are returned
+    #[serde(default)]
+    pub filter: TransactionFilter,
+    /// Sort order by the timestamp, newest first by default
+    #[serde(default)]
+    pub sort: SortOrder,
+    /// Cursor from a previous [`TransactionPage`], the page will start after the last transaction of the previous page
+    pub cursor: Option<String>,
+    /// Maximum amount of transactions in the page, default is 20
+    pub limit: Option<usize>,
+}
+
+/// Filter for [`TransactionQuery`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TransactionFilter {
+    /// Only incoming or only outgoing transactions
+    pub incoming: Option<bool>,
+    /// Only transactions with one of these inclusion states
+    #[serde(rename = "inclusionStates", default)]
+    pub inclusion_states: Vec<InclusionState>,
+    /// Only transactions with a timestamp at or after this time in milliseconds
+    #[serde(rename = "fromTime", default)]
+    pub from_time: Option<u128>,
+    /// Only transactions with a timestamp before this time in milliseconds
+    #[serde(rename = "toTime", default)]
+    pub to_time: Option<u128>,
+    /// Only transactions with this Bech32 encoded address as recipient or sender
+    #[serde(default)]
+    pub address: Option<String>,
+    /// Only transactions with outputs of this asset type
+    #[serde(rename = "assetType", default)]
+    pub asset_type: Option<AssetType>,
+    /// Only transactions with outputs holding this native token
+    #[serde(rename = "tokenId", default)]
+    pub token_id: Option<TokenId>,
+    /// Only transactions that have this tag, on the transaction or one of its outputs
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Only transactions with a note that contains this text, ignoring the case
+    #[serde(default)]
+    pub note: Option<String>,
+}
+
+/// Asset types for [`TransactionFilter`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AssetType {
+    /// Basic outputs without native tokens
+    BaseCoin,
+    /// Outputs with native tokens
+    NativeToken,
+    /// Nft outputs
+    Nft,
+    /// Alias outputs
+    Alias,
+    /// Foundry outputs
+    Foundry,
+}
+
+impl AssetType {
+    fn matches(&self, output: &Output) -> bool {
+        match self {
+            Self::BaseCoin => {
+                matches!(output, Output::Basic(basic_output) if basic_output.native_tokens().is_empty())
+            }
+            Self::NativeToken => output
+                .native_tokens()
+                .map_or(false, |native_tokens| !native_tokens.is_empty()),
+            Self::Nft => matches!(output, Output::Nft(_)),
+            Self::Alias => matches!(output, Output::Alias(_)),
+            Self::Foundry => matches!(output, Output::Foundry(_)),
+        }
+    }
+}
+
+/// Sort order for queries
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum SortOrder {
+    /// Oldest first
+    Ascending,
+    /// Newest first
+    Descending,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        Self::Descending
+    }
+}
+
+/// A page of transactions returned by [`AccountHandle::query_transactions()`]
+#[derive(Debug, Clone, Serialize)]
+pub struct TransactionPage {
+    /// The transactions of the page
+    pub transactions: Vec<Transaction>,
+    /// Cursor for the next page, None if there are no further transactions
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+}
+
+/// Dto for [`TransactionPage`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransactionPageDto {
+    /// The transactions of the page
+    pub transactions: Vec<TransactionDto>,
+    /// Cursor for the next page, None if there are no further transactions
+    #[serde(rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+}
+
+impl From<&TransactionPage> for TransactionPageDto {
+    fn from(value: &TransactionPage) -> Self {
+        Self {
+            transactions: value.transactions.iter().map(TransactionDto::from).collect(),
+            next_cursor: value.next_cursor.clone(),
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Query the sent and incoming transactions of the account with a filter, sorted by their timestamp. Incoming
+    /// transactions are only available if they were synced with `sync_incoming_transactions`.
+    /// ```ignore
+    /// let query = TransactionQuery {
+    ///     filter: TransactionFilter {
+    ///         incoming: Some(false),
+    ///         ..Default::default()
+    ///     },
+    ///     limit: Some(10),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let page = account_handle.query_transactions(query).await?;
+    /// let next_page = account_handle
+    ///     .query_transactions(TransactionQuery {
+    ///         cursor: page.next_cursor,
+    ///         ..query
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn query_transactions(&self, query: TransactionQuery) -> crate::Result<TransactionPage> {
+        log::debug!("[query_transactions]");
+        let address = match &query.filter.address {
+            Some(address) => Some(Address::try_from_bech32(address)?.1),
+            None => None,
+        };
+        let cursor = match &query.cursor {
+            Some(cursor) => Some(parse_cursor(cursor)?),
+            None => None,
+        };
+        let limit = query.limit.unwrap_or(DEFAULT_TRANSACTION_QUERY_LIMIT);
+
+        let account = self.read().await;
+        // Index the received outputs by their transaction id once, the block and time of incoming transactions are
+        // taken from them
+        let mut received_outputs = HashMap::new();
+        for output_data in account.outputs.values() {
+            received_outputs
+                .entry(output_data.output_id.transaction_id())
+                .or_insert(output_data);
+        }
+        let incoming_transactions = account
+            .incoming_transactions
+            .iter()
+            .filter(|(transaction_id, _)| !account.transactions.contains_key(*transaction_id))
+            .filter_map(|(transaction_id, (payload, _inputs))| {
+                received_outputs
+                    .get(transaction_id)
+                    .map(|&output_data| TransactionRef::Incoming(transaction_id, payload, output_data))
+            });
+        let annotations = TransactionAnnotations::new(&account);
+        // Only references are filtered and sorted, the transactions of the page get cloned at the end
+        let mut transactions = account
+            .transactions
+            .values()
+            .map(TransactionRef::Sent)
+            .chain(incoming_transactions)
+            .filter(|transaction| matches_filter(&account, &annotations, transaction, &query.filter, address.as_ref()))
+            .collect::<Vec<TransactionRef<'_>>>();
+
+        let next_cursor = paginate(&mut transactions, TransactionRef::sort_key, query.sort, cursor, limit);
+
+        Ok(TransactionPage {
+            transactions: transactions.iter().map(TransactionRef::to_transaction).collect(),
+            next_cursor,
+        })
+    }
+}
+
+// A sent or incoming transaction of the account, borrowed from the account while the query is evaluated
+enum TransactionRef<'a> {
+    Sent(&'a Transaction),
+    // An incoming transaction with the first received output of it
+    Incoming(&'a TransactionId, &'a TransactionPayload, &'a OutputData),
+}
+
+impl TransactionRef<'_> {
+    fn transaction_id(&self) -> &TransactionId {
+        match self {
+            Self::Sent(transaction) => &transaction.transaction_id,
+            Self::Incoming(transaction_id, _, _) => transaction_id,
+        }
+    }
+
+    fn payload(&self) -> &TransactionPayload {
+        match self {
+            Self::Sent(transaction) => &transaction.payload,
+            Self::Incoming(_, payload, _) => payload,
+        }
+    }
+
+    fn timestamp(&self) -> u128 {
+        match self {
+            Self::Sent(transaction) => transaction.timestamp,
+            Self::Incoming(_, _, output_data) => incoming_timestamp(output_data),
+        }
+    }
+
+    fn inclusion_state(&self) -> InclusionState {
+        match self {
+            Self::Sent(transaction) => transaction.inclusion_state,
+            Self::Incoming(..) => InclusionState::Confirmed,
+        }
+    }
+
+    fn incoming(&self) -> bool {
+        match self {
+            Self::Sent(transaction) => transaction.incoming,
+            Self::Incoming(..) => true,
+        }
+    }
+
+    // Transactions are sorted by their timestamp, the id makes the order unique
+    fn sort_key(&self) -> (u128, TransactionId) {
+        (self.timestamp(), *self.transaction_id())
+    }
+
+    fn to_transaction(&self) -> Transaction {
+        match self {
+            Self::Sent(transaction) => (*transaction).clone(),
+            Self::Incoming(transaction_id, payload, output_data) => {
+                build_incoming_transaction(transaction_id, payload, output_data)
+            }
+        }
+    }
+}
+
+// Build the metadata of an incoming transaction, the block and time are taken from the received outputs
+pub(crate) fn incoming_transaction(
+    account: &Account,
+    transaction_id: &TransactionId,
+    payload: &TransactionPayload,
+) -> Option<Transaction> {
+    let output_data = account
+        .outputs
+        .values()
+        .find(|output_data| output_data.output_id.transaction_id() == transaction_id)?;
+    Some(build_incoming_transaction(transaction_id, payload, output_data))
+}
+
+fn build_incoming_transaction(
+    transaction_id: &TransactionId,
+    payload: &TransactionPayload,
+    output_data: &OutputData,
+) -> Transaction {
+    Transaction {
+        payload: payload.clone(),
+        block_id: BlockId::from_str(&output_data.metadata.block_id).ok(),
+        inclusion_state: InclusionState::Confirmed,
+        timestamp: incoming_timestamp(output_data),
+        transaction_id: *transaction_id,
+        network_id: output_data.network_id,
+        incoming: true,
+        idempotency_key: None,
+        returned_outputs: HashMap::new(),
+    }
+}
+
+// The milestone timestamp of a received output in milliseconds
+fn incoming_timestamp(output_data: &OutputData) -> u128 {
+    output_data.metadata.milestone_timestamp_booked as u128 * 1000
+}
+
+fn matches_filter(
+    account: &Account,
+    annotations: &TransactionAnnotations<'_>,
+    transaction: &TransactionRef<'_>,
+    filter: &TransactionFilter,
+    address: Option<&Address>,
+) -> bool {
+    if filter
+        .incoming
+        .map_or(false, |incoming| incoming != transaction.incoming())
+    {
+        return false;
+    }
+    if !filter.inclusion_states.is_empty() && !filter.inclusion_states.contains(&transaction.inclusion_state()) {
+        return false;
+    }
+    if filter
+        .from_time
+        .map_or(false, |from_time| transaction.timestamp() < from_time)
+        || filter
+            .to_time
+            .map_or(false, |to_time| transaction.timestamp() >= to_time)
+    {
+        return false;
+    }
+
+    let TransactionEssence::Regular(essence) = transaction.payload().essence();
+    let outputs = essence.outputs();
+    if let Some(asset_type) = filter.asset_type {
+        if !outputs.iter().any(|output| asset_type.matches(output)) {
+            return false;
+        }
+    }
+    if let Some(token_id) = &filter.token_id {
+        if !outputs.iter().any(|output| {
+            output.native_tokens().map_or(false, |native_tokens| {
+                native_tokens
+                    .iter()
+                    .any(|native_token| native_token.token_id() == token_id)
+            })
+        }) {
+            return false;
+        }
+    }
+    if let Some(address) = address {
+        let is_recipient = outputs.iter().any(|output| {
+            output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.address())
+                .map_or(false, |unlock_condition| unlock_condition.address() == address)
+        });
+        let is_sender = outputs.iter().any(|output| {
+            output
+                .features()
+                .and_then(|features| features.sender())
+                .map_or(false, |sender| sender.address() == address)
+        }) || account.incoming_transactions.get(transaction.transaction_id()).map_or(
+            false,
+            |(_payload, inputs)| {
+                inputs.iter().any(|input| {
+                    Output::try_from(&input.output).map_or(false, |output| {
+                        output
+                            .unlock_conditions()
+                            .and_then(|unlock_conditions| unlock_conditions.address())
+                            .map_or(false, |unlock_condition| unlock_condition.address() == address)
+                    })
+                })
+            },
+        );
+        if !is_recipient && !is_sender {
+            return false;
+        }
+    }
+    if let Some(tag) = &filter.tag {
+        if !annotations
+            .get(transaction.transaction_id())
+            .any(|annotation| annotation.tags.contains(tag))
+        {
+            return false;
+        }
+    }
+    if let Some(note) = &filter.note {
+        let note = note.to_lowercase();
+        if !annotations.get(transaction.transaction_id()).any(|annotation| {
+            annotation
+                .note
+                .as_ref()
+                .map_or(false, |annotation_note| annotation_note.to_lowercase().contains(&note))
+        }) {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_client::bee_block::{
+        address::Ed25519Address,
+        output::{
+            unlock_condition::{AddressUnlockCondition, UnlockCondition},
+            BasicOutputBuilder, NativeToken,
+        },
+    };
+    use primitive_types::U256;
+
+    use super::*;
+
+    #[test]
+    fn asset_type() {
+        let output = |native_tokens: Vec<NativeToken>| {
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .unwrap()
+                .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(Address::Ed25519(
+                    Ed25519Address::new([0; 32]),
+                ))))
+                .with_native_tokens(native_tokens)
+                .finish_output()
+                .unwrap()
+        };
+        let base_coin_output = output(Vec::new());
+        let native_token_output = output(vec![NativeToken::new(TokenId::new([1; 38]), U256::from(10u8)).unwrap()]);
+
+        assert!(AssetType::BaseCoin.matches(&base_coin_output));
+        assert!(!AssetType::NativeToken.matches(&base_coin_output));
+        // Outputs with native tokens aren't base coin outputs, also if they hold base coins
+        assert!(!AssetType::BaseCoin.matches(&native_token_output));
+        assert!(AssetType::NativeToken.matches(&native_token_output));
+        assert!(!AssetType::Nft.matches(&native_token_output));
+    }
+}
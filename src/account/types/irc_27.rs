@@ -0,0 +1,207 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use iota_client::bee_block::{
+    address::Address,
+    output::{feature::Feature, NftOutput},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// The value of the `standard` field for IRC27 metadata
+pub const IRC_27_STANDARD: &str = "IRC27";
+/// The supported version of IRC27
+pub const IRC_27_VERSION: &str = "v1.0";
+
+/// Nft metadata as defined in IRC27 (https://github.com/iotaledger/tips/pull/65), stored as JSON in the immutable
+/// metadata feature of the nft output
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Irc27Metadata {
+    /// The metadata standard, always "IRC27"
+    pub standard: String,
+    /// The version of the standard, "v1.0"
+    pub version: String,
+    /// The MIME type of the file the uri points to, for example "image/png"
+    #[serde(rename = "type")]
+    pub media_type: String,
+    /// URL pointing to the nft file
+    pub uri: String,
+    /// The human-readable name of the nft
+    pub name: String,
+    /// The human-readable name of the collection
+    #[serde(rename = "collectionName", skip_serializing_if = "Option::is_none", default)]
+    pub collection_name: Option<String>,
+    /// Bech32 encoded addresses that receive royalties, with their share as decimal between 0 and 1
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub royalties: BTreeMap<String, f64>,
+    /// The human-readable name of the creator
+    #[serde(rename = "issuerName", skip_serializing_if = "Option::is_none", default)]
+    pub issuer_name: Option<String>,
+    /// The human-readable description of the nft
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    /// Attributes of the nft
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub attributes: Vec<Irc27Attribute>,
+}
+
+/// An attribute of [`Irc27Metadata`]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Irc27Attribute {
+    /// The name of the trait
+    pub trait_type: String,
+    /// The value of the trait
+    pub value: serde_json::Value,
+}
+
+impl Irc27Metadata {
+    /// Create new IRC27 metadata with the required fields
+    pub fn new(media_type: impl Into<String>, uri: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            standard: IRC_27_STANDARD.to_string(),
+            version: IRC_27_VERSION.to_string(),
+            media_type: media_type.into(),
+            uri: uri.into(),
+            name: name.into(),
+            collection_name: None,
+            royalties: BTreeMap::new(),
+            issuer_name: None,
+            description: None,
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Check that the required fields are set and have valid values
+    pub fn validate(&self) -> crate::Result<()> {
+        if self.standard != IRC_27_STANDARD {
+            return Err(Error::InvalidNftMetadata(format!(
+                "standard needs to be {IRC_27_STANDARD}, but is {}",
+                self.standard
+            )));
+        }
+        if self.version != IRC_27_VERSION {
+            return Err(Error::InvalidNftMetadata(format!(
+                "unsupported version {}",
+                self.version
+            )));
+        }
+        if !self.media_type.contains('/') {
+            return Err(Error::InvalidNftMetadata(format!(
+                "type {} is not a MIME type",
+                self.media_type
+            )));
+        }
+        if self.uri.trim().is_empty() {
+            return Err(Error::InvalidNftMetadata("uri can't be empty".to_string()));
+        }
+        if self.name.trim().is_empty() {
+            return Err(Error::InvalidNftMetadata("name can't be empty".to_string()));
+        }
+        let mut royalties_sum = 0.0;
+        for (address, share) in &self.royalties {
+            Address::try_from_bech32(address)
+                .map_err(|_| Error::InvalidNftMetadata(format!("invalid royalty address {address}")))?;
+            if share.is_nan() || *share <= 0.0 || *share > 1.0 {
+                return Err(Error::InvalidNftMetadata(format!(
+                    "royalty share {share} for {address} is not between 0 and 1"
+                )));
+            }
+            royalties_sum += share;
+        }
+        if royalties_sum > 1.0 {
+            return Err(Error::InvalidNftMetadata(format!(
+                "sum of the royalty shares {royalties_sum} is larger than 1"
+            )));
+        }
+        if self
+            .attributes
+            .iter()
+            .any(|attribute| attribute.trait_type.trim().is_empty())
+        {
+            return Err(Error::InvalidNftMetadata("trait_type can't be empty".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Validate and encode the metadata as JSON for the immutable metadata feature
+    pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
+        self.validate()?;
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Decode and validate metadata from the bytes of a metadata feature
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let metadata: Self = serde_json::from_slice(bytes).map_err(|e| Error::InvalidNftMetadata(e.to_string()))?;
+        metadata.validate()?;
+        Ok(metadata)
+    }
+
+    /// Decode the metadata from the immutable metadata feature of an nft output, returns None if the nft has no
+    /// immutable metadata
+    pub fn from_nft_output(nft_output: &NftOutput) -> Option<crate::Result<Self>> {
+        nft_output
+            .immutable_features()
+            .iter()
+            .find_map(|feature| match feature {
+                Feature::Metadata(metadata_feature) => Some(metadata_feature.data()),
+                _ => None,
+            })
+            .map(Self::from_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu";
+
+    #[test]
+    fn irc_27_metadata_validation() {
+        let mut metadata = Irc27Metadata::new("image/png", "https://example.com/nft.png", "My nft");
+        metadata.royalties.insert(ADDRESS.to_string(), 0.05);
+        metadata.attributes.push(Irc27Attribute {
+            trait_type: "color".to_string(),
+            value: serde_json::Value::String("blue".to_string()),
+        });
+        let bytes = metadata.to_bytes().unwrap();
+        assert_eq!(Irc27Metadata::from_bytes(&bytes).unwrap(), metadata);
+
+        let mut invalid_metadata = metadata.clone();
+        invalid_metadata.version = "v2.0".to_string();
+        assert!(invalid_metadata.validate().is_err());
+
+        let mut invalid_metadata = metadata.clone();
+        invalid_metadata.media_type = "png".to_string();
+        assert!(invalid_metadata.validate().is_err());
+
+        let mut invalid_metadata = metadata.clone();
+        invalid_metadata.uri = String::new();
+        assert!(invalid_metadata.validate().is_err());
+
+        let mut invalid_metadata = metadata.clone();
+        invalid_metadata.royalties.insert("invalid".to_string(), 0.1);
+        assert!(invalid_metadata.validate().is_err());
+
+        let mut invalid_metadata = metadata.clone();
+        invalid_metadata.royalties.insert(ADDRESS.to_string(), 1.5);
+        assert!(invalid_metadata.validate().is_err());
+        invalid_metadata.royalties.insert(ADDRESS.to_string(), f64::NAN);
+        assert!(invalid_metadata.validate().is_err());
+
+        let mut invalid_metadata = metadata;
+        invalid_metadata.attributes[0].trait_type = " ".to_string();
+        assert!(invalid_metadata.validate().is_err());
+
+        // The type is serialized as `type`
+        let metadata = Irc27Metadata::from_bytes(
+            br#"{"standard":"IRC27","version":"v1.0","type":"image/jpeg","uri":"ipfs://nft","name":"Nft"}"#,
+        )
+        .unwrap();
+        assert_eq!(metadata.media_type, "image/jpeg");
+        assert!(Irc27Metadata::from_bytes(br#"{"standard":"IRC27","version":"v1.0","name":"Nft"}"#).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::bee_block::output::{feature::Feature, FoundryOutput};
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// The value of the `standard` field for IRC30 metadata
+pub const IRC_30_STANDARD: &str = "IRC30";
+
+/// Native token metadata as defined in IRC30 (https://github.com/iotaledger/tips/pull/68), stored as JSON in the
+/// immutable metadata feature of the foundry output that controls the token
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Irc30Metadata {
+    /// The metadata standard, always "IRC30"
+    pub standard: String,
+    /// The human-readable name of the token
+    pub name: String,
+    /// The symbol of the token
+    pub symbol: String,
+    /// Number of decimals the token uses, an amount is divided by `10^decimals` to get its user representation
+    pub decimals: u32,
+    /// The human-readable description of the token
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    /// URL pointing to more resources about the token
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub url: Option<String>,
+    /// URL pointing to an image resource of the token logo
+    #[serde(rename = "logoUrl", skip_serializing_if = "Option::is_none", default)]
+    pub logo_url: Option<String>,
+    /// The svg logo of the token encoded as string
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub logo: Option<String>,
+}
+
+impl Irc30Metadata {
+    /// Create new IRC30 metadata with the required fields
+    pub fn new(name: impl Into<String>, symbol: impl Into<String>, decimals: u32) -> Self {
+        Self {
+            standard: IRC_30_STANDARD.to_string(),
+            name: name.into(),
+            symbol: symbol.into(),
+            decimals,
+            description: None,
+            url: None,
+            logo_url: None,
+            logo: None,
+        }
+    }
+
+    /// Check that the required fields are set and have valid values
+    pub fn validate(&self) -> crate::Result<()> {
+        if self.standard != IRC_30_STANDARD {
+            return Err(Error::InvalidTokenMetadata(format!(
+                "standard needs to be {IRC_30_STANDARD}, but is {}",
+                self.standard
+            )));
+        }
+        if self.name.trim().is_empty() {
+            return Err(Error::InvalidTokenMetadata("name can't be empty".to_string()));
+        }
+        if self.symbol.trim().is_empty() {
+            return Err(Error::InvalidTokenMetadata("symbol can't be empty".to_string()));
+        }
+        // U256::MAX has 78 digits
+        if self.decimals > 77 {
+            return Err(Error::InvalidTokenMetadata(format!(
+                "decimals {} is larger than 77",
+                self.decimals
+            )));
+        }
+        Ok(())
+    }
+
+    /// Validate and encode the metadata as JSON for the foundry metadata feature
+    pub fn to_bytes(&self) -> crate::Result<Vec<u8>> {
+        self.validate()?;
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Decode and validate metadata from the bytes of a metadata feature
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let metadata: Self = serde_json::from_slice(bytes).map_err(|e| Error::InvalidTokenMetadata(e.to_string()))?;
+        metadata.validate()?;
+        Ok(metadata)
+    }
+
+    /// Decode the metadata from the immutable metadata feature of a foundry output, returns None if the foundry has
+    /// no metadata or it isn't valid IRC30 metadata
+    pub fn from_foundry_output(foundry_output: &FoundryOutput) -> Option<Self> {
+        foundry_output
+            .immutable_features()
+            .iter()
+            .find_map(|feature| match feature {
+                Feature::Metadata(metadata_feature) => Some(metadata_feature.data()),
+                _ => None,
+            })
+            .and_then(|data| Self::from_bytes(data).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn irc_30_metadata_validation() {
+        let metadata = Irc30Metadata::new("Shimmer Token", "STK", 6);
+        let bytes = metadata.to_bytes().unwrap();
+        assert_eq!(Irc30Metadata::from_bytes(&bytes).unwrap(), metadata);
+
+        let mut invalid_metadata = metadata.clone();
+        invalid_metadata.standard = "IRC27".to_string();
+        assert!(invalid_metadata.validate().is_err());
+
+        let mut invalid_metadata = metadata.clone();
+        invalid_metadata.name = " ".to_string();
+        assert!(invalid_metadata.validate().is_err());
+
+        let mut invalid_metadata = metadata.clone();
+        invalid_metadata.symbol = String::new();
+        assert!(invalid_metadata.to_bytes().is_err());
+
+        let mut invalid_metadata = metadata;
+        invalid_metadata.decimals = 78;
+        assert!(invalid_metadata.validate().is_err());
+        invalid_metadata.decimals = 77;
+        assert!(invalid_metadata.validate().is_ok());
+
+        // Required fields are missing
+        assert!(Irc30Metadata::from_bytes(br#"{"standard":"IRC30","name":"Token"}"#).is_err());
+        assert!(Irc30Metadata::from_bytes(b"not json").is_err());
+        let metadata =
+            Irc30Metadata::from_bytes(br#"{"standard":"IRC30","name":"Token","symbol":"TK","decimals":2,"url":"u"}"#)
+                .unwrap();
+        assert_eq!(metadata.url.as_deref(), Some("u"));
+    }
+}
@@ -6,6 +6,15 @@ pub(crate) mod address;
 pub use address::{AccountAddress, AddressWithUnspentOutputs};
 /// Custom de/serialization for [`address::AddressWrapper`]
 pub(crate) mod address_serde;
+/// IRC27 nft metadata
+pub(crate) mod irc_27;
+pub use irc_27::{Irc27Attribute, Irc27Metadata, IRC_27_STANDARD, IRC_27_VERSION};
+/// IRC30 native token metadata
+pub(crate) mod irc_30;
+pub use irc_30::{Irc30Metadata, IRC_30_STANDARD};
+/// Types for transactions which are not finished yet
+pub(crate) mod outbox;
+pub use outbox::{OutboxEntry, OutboxStage};
 
 use std::{collections::HashMap, str::FromStr};
 
@@ -13,7 +22,7 @@ use crypto::keys::slip10::Chain;
 use iota_client::{
     bee_block::{
         address::{dto::AddressDto, Address},
-        output::{dto::OutputDto, AliasId, FoundryId, NftId, Output, OutputId, TokenId},
+        output::{dto::OutputDto, AliasId, FoundryId, NativeToken, NftId, Output, OutputId, TokenId},
         payload::transaction::{dto::TransactionPayloadDto, TransactionId, TransactionPayload},
         BlockId,
     },
@@ -23,7 +32,10 @@ use iota_client::{
 use primitive_types::U256;
 use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::account::Account;
+use crate::account::{
+    operations::amount_formatting::{format_amount, AmountFormatOptions},
+    Account,
+};
 
 /// The balance of an account, returned from [`crate::account::handle::AccountHandle::sync()`] and
 /// [`crate::account::handle::AccountHandle::balance()`].
@@ -48,6 +60,67 @@ pub struct AccountBalance {
     /// [`TimelockUnlockCondition`] or [`ExpirationUnlockCondition`] this can change at any time
     #[serde(rename = "potentiallyLockedOutputs")]
     pub potentially_locked_outputs: HashMap<OutputId, bool>,
+    /// Balances of the alias and nft addresses owned by the account, these amounts are not included in the totals, as
+    /// they can only be spent together with the alias or nft
+    #[serde(rename = "chainAddresses", default)]
+    pub chain_addresses: Vec<ChainAddressBalance>,
+    /// Outputs that were rejected and are excluded from the balance
+    #[serde(rename = "ignoredOutputs", default)]
+    pub ignored_outputs: Vec<OutputId>,
+    /// Breakdown of the outputs that can't be spent at the moment by the reason why
+    #[serde(rename = "lockedBalance", default)]
+    pub locked_balance: LockedBalance,
+}
+
+/// Breakdown of the outputs that can't be spent at the moment for [`AccountBalance`]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LockedBalance {
+    /// Outputs that can be unlocked by the account once their timelock expired
+    pub timelocked: Vec<LockedOutput>,
+    /// Outputs sent by the account that can be claimed back after their expiration, if the recipient doesn't claim
+    /// them before
+    #[serde(rename = "claimableAfterExpiration")]
+    pub claimable_after_expiration: Vec<LockedOutput>,
+    /// Outputs with a storage deposit return unlock condition, the amount is what needs to be sent back when the
+    /// output is claimed
+    #[serde(rename = "storageDepositReturns")]
+    pub storage_deposit_returns: Vec<LockedOutput>,
+    /// Outputs that are used as inputs in pending transactions
+    #[serde(rename = "pendingTransactions")]
+    pub pending_transactions: Vec<LockedOutput>,
+    /// Outputs that were rejected and are ignored by the account
+    pub frozen: Vec<LockedOutput>,
+    /// Required storage deposit of the outputs in the balance by output kind
+    #[serde(rename = "storageDeposit")]
+    pub storage_deposit: RequiredStorageDeposit,
+}
+
+/// An output of [`LockedBalance`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LockedOutput {
+    /// The output id
+    #[serde(rename = "outputId")]
+    pub output_id: OutputId,
+    /// Locked amount of the base coin
+    pub amount: u64,
+    /// Locked native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeToken>,
+    /// The nft id if it's an nft output
+    #[serde(rename = "nftId")]
+    pub nft_id: Option<NftId>,
+    /// Unix time in seconds from which on the output can be unlocked, if it's known
+    #[serde(rename = "unlockTime")]
+    pub unlock_time: Option<u32>,
+}
+
+/// Required storage deposit by output kind for [`LockedBalance`]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RequiredStorageDeposit {
+    pub alias: u64,
+    pub basic: u64,
+    pub foundry: u64,
+    pub nft: u64,
 }
 
 /// Base coin fields for [`AccountBalance`]
@@ -69,6 +142,28 @@ pub struct NativeTokensBalance {
     pub total: U256,
     /// Balance that can currently be spent
     pub available: U256,
+    /// IRC30 metadata of the token, if the foundry has valid metadata
+    #[serde(default)]
+    pub metadata: Option<Irc30Metadata>,
+}
+
+impl NativeTokensBalance {
+    /// The total amount divided by `10^decimals` from the IRC30 metadata, for example `1234.5`, None if the token has
+    /// no metadata
+    pub fn formatted_total(&self) -> Option<String> {
+        self.format(self.total)
+    }
+
+    /// The available amount divided by `10^decimals` from the IRC30 metadata, None if the token has no metadata
+    pub fn formatted_available(&self) -> Option<String> {
+        self.format(self.available)
+    }
+
+    fn format(&self, amount: U256) -> Option<String> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| format_amount(amount, metadata.decimals, &AmountFormatOptions::default()).ok())
+    }
 }
 
 impl Default for NativeTokensBalance {
@@ -77,10 +172,58 @@ impl Default for NativeTokensBalance {
             token_id: TokenId::null(),
             total: U256::from(0u8),
             available: U256::from(0u8),
+            metadata: None,
         }
     }
 }
 
+/// Balance of the outputs locked to an alias or nft address owned by the account, for [`AccountBalance`]. These
+/// outputs can only be spent by transitioning the alias or nft output in the same transaction.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChainAddressBalance {
+    /// Bech32 encoded alias or nft address
+    pub address: String,
+    /// Total and available amount of the base coin
+    #[serde(rename = "baseCoin")]
+    pub base_coin: BaseCoinBalance,
+    /// Native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokensBalance>,
+    /// Nfts
+    pub nfts: Vec<NftId>,
+    /// Output ids of the outputs locked to the address
+    #[serde(rename = "outputIds")]
+    pub output_ids: Vec<OutputId>,
+}
+
+/// Balance of a public or internal address of the account, returned from
+/// [`crate::account::handle::AccountHandle::address_balances()`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AddressBalance {
+    /// Bech32 encoded address
+    pub address: String,
+    /// The address key index
+    #[serde(rename = "keyIndex")]
+    pub key_index: u32,
+    /// Determines if an address is a public or an internal (change) address
+    pub internal: bool,
+    /// Total and available amount of the base coin
+    #[serde(rename = "baseCoin")]
+    pub base_coin: BaseCoinBalance,
+    /// Required storage deposit amount of the outputs of the address
+    #[serde(rename = "requiredStorageDeposit")]
+    pub required_storage_deposit: u64,
+    /// Native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokensBalance>,
+    /// Nfts
+    pub nfts: Vec<NftId>,
+    /// Aliases
+    pub aliases: Vec<AliasId>,
+    /// Foundries
+    pub foundries: Vec<FoundryId>,
+}
+
 /// An output with metadata
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct OutputData {
@@ -109,16 +252,35 @@ impl OutputData {
         account: &Account,
         current_time: u32,
         bech32_hrp: &str,
+        outputs: &[Output],
     ) -> crate::Result<InputSigningData> {
-        let unlock_address = {
-            if let Some(unlock_conditions) = self.output.unlock_conditions() {
-                *unlock_conditions.locked_address(&self.address, current_time)
-            } else {
-                self.address
+        let unlock_address = match &self.output {
+            // A governance transition (same state index in the output) needs to be unlocked by the governor
+            Output::Alias(alias_input)
+                if outputs.iter().any(|output| match output {
+                    Output::Alias(alias_output) => {
+                        *alias_output.alias_id() == alias_input.alias_id().or_from_output_id(self.output_id)
+                            && alias_output.state_index() == alias_input.state_index()
+                    }
+                    _ => false,
+                }) =>
+            {
+                *alias_input.governor_address()
+            }
+            _ => {
+                if let Some(unlock_conditions) = self.output.unlock_conditions() {
+                    *unlock_conditions.locked_address(&self.address, current_time)
+                } else {
+                    self.address
+                }
             }
         };
 
-        let chain = if unlock_address == self.address {
+        let chain = if let Address::Alias(_) | Address::Nft(_) = unlock_address {
+            // Alias and NFT addresses have no chain, the alias or nft output needs to be an input of the same
+            // transaction to unlock it
+            None
+        } else if unlock_address == self.address {
             self.chain.clone()
         } else if let Address::Ed25519(_) = self.address {
             if let Some(address) = account
@@ -208,6 +370,13 @@ pub struct Transaction {
     pub network_id: u64,
     // set if the transaction was created by the wallet or if it was sent by someone else and is incoming
     pub incoming: bool,
+    // key provided in the TransactionOptions to prevent sending the same transaction multiple times
+    #[serde(rename = "idempotencyKey", default)]
+    pub idempotency_key: Option<String>,
+    // outputs of this transaction that expired without being claimed by the recipient and were claimed back, with the
+    // id of the transaction that claimed them
+    #[serde(rename = "returnedOutputs", default)]
+    pub returned_outputs: HashMap<OutputId, TransactionId>,
 }
 
 /// Dto for a transaction with metadata
@@ -228,6 +397,12 @@ pub struct TransactionDto {
     pub network_id: String,
     /// If the transaction was created by the wallet or if it was sent by someone else and is incoming
     pub incoming: bool,
+    /// Idempotency key from the TransactionOptions the transaction was created with
+    #[serde(rename = "idempotencyKey", default)]
+    pub idempotency_key: Option<String>,
+    /// Outputs of the transaction that expired and were claimed back, with the id of the transaction that claimed them
+    #[serde(rename = "returnedOutputs", default)]
+    pub returned_outputs: HashMap<OutputId, TransactionId>,
 }
 
 impl From<&Transaction> for TransactionDto {
@@ -239,6 +414,8 @@ impl From<&Transaction> for TransactionDto {
             timestamp: value.timestamp.to_string(),
             network_id: value.network_id.to_string(),
             incoming: value.incoming,
+            idempotency_key: value.idempotency_key.clone(),
+            returned_outputs: value.returned_outputs.clone(),
         }
     }
 }
@@ -0,0 +1,40 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_client::{
+    api::{PreparedTransactionDataDto, SignedTransactionDataDto},
+    bee_block::{payload::transaction::TransactionPayload, BlockId},
+};
+use serde::{Deserialize, Serialize};
+
+/// A transaction that was created by the wallet, but isn't stored as [`Transaction`](crate::account::types::Transaction)
+/// yet. Entries are persisted in every stage, so they can be resumed or reconciled after a crash.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Hex encoded hash of the transaction essence, used as id for the entry
+    #[serde(rename = "essenceHash")]
+    pub essence_hash: String,
+    /// The last stage the transaction reached
+    pub stage: OutboxStage,
+    /// Idempotency key from the TransactionOptions
+    #[serde(rename = "idempotencyKey", default)]
+    pub idempotency_key: Option<String>,
+    /// Creation time of the entry
+    pub timestamp: u128,
+}
+
+/// The stages a transaction goes through before it's stored in the account
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum OutboxStage {
+    /// The transaction essence got prepared, but isn't signed yet
+    Prepared(PreparedTransactionDataDto),
+    /// The transaction got signed, but maybe not submitted yet
+    Signed(SignedTransactionDataDto),
+    /// The transaction got submitted in a block, but isn't stored in the account yet
+    Submitted {
+        #[serde(rename = "blockId")]
+        block_id: BlockId,
+        payload: TransactionPayload,
+    },
+}
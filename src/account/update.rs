@@ -106,6 +106,7 @@ impl AccountHandle {
                     log::debug!("[SYNC] Spent output {}", output_id);
                     account.locked_outputs.remove(&output_id);
                     account.unspent_outputs.remove(&output_id);
+                    account.ignored_outputs.remove(&output_id);
                     // Update spent data fields
                     if let Some(output_data) = account.outputs.get_mut(&output_id) {
                         output_data.metadata.is_spent = true;
@@ -208,6 +209,7 @@ impl AccountHandle {
             }
             account.locked_outputs.remove(output_to_unlock);
             account.unspent_outputs.remove(output_to_unlock);
+            account.ignored_outputs.remove(output_to_unlock);
             log::debug!("[SYNC] Unlocked spent output {}", output_to_unlock);
         }
 
@@ -164,7 +164,7 @@ impl AccountManagerBuilder {
             #[cfg(feature = "events")]
             let event_emitter = Arc::new(Mutex::new(EventEmitter::new()));
 
-            return Ok(AccountManager {
+            let account_manager = AccountManager {
                 #[cfg(not(feature = "events"))]
                 accounts: Arc::new(RwLock::new(
                     accounts
@@ -195,7 +195,15 @@ impl AccountManagerBuilder {
                 event_emitter,
                 storage_options,
                 storage_manager,
-            });
+            };
+
+            // Transactions that were interrupted by a crash are tracked again, a failure here shouldn't prevent the
+            // wallet from starting, the entries are kept for the next try
+            if let Err(err) = account_manager.reconcile_outboxes().await {
+                log::error!("Failed to reconcile outboxes: {err}");
+            }
+
+            return Ok(account_manager);
         }
 
         Ok(AccountManager {
@@ -28,8 +28,12 @@ use crate::{
     account::{
         builder::AccountBuilder,
         handle::AccountHandle,
-        operations::{balance::add_balances, syncing::SyncOptions},
-        types::AccountBalance,
+        operations::{
+            balance::add_balances,
+            syncing::SyncOptions,
+            transaction_export::{format_export_records, ExportOptions},
+        },
+        types::{AccountBalance, Transaction},
     },
     ClientOptions,
 };
@@ -209,6 +213,38 @@ impl AccountManager {
         Ok(balance)
     }
 
+    /// Resume or reconcile the unfinished transactions of all accounts, so transactions which were interrupted by a
+    /// crash are tracked again. This is done when the account manager gets created from storage
+    pub async fn reconcile_outboxes(&self) -> crate::Result<Vec<Transaction>> {
+        let accounts = self.accounts.read().await;
+
+        let mut transactions = Vec::new();
+        for account in accounts.iter() {
+            // One failing account shouldn't keep the others from being reconciled
+            match account.reconcile_outbox().await {
+                Ok(account_transactions) => transactions.extend(account_transactions),
+                Err(err) => log::error!(
+                    "Failed to reconcile outbox of account {}: {err}",
+                    account.read().await.index()
+                ),
+            }
+        }
+
+        Ok(transactions)
+    }
+
+    /// Export the transactions of all accounts for accounting, sorted by account and timestamp
+    pub async fn export_transactions(&self, options: ExportOptions) -> crate::Result<String> {
+        let accounts = self.accounts.read().await;
+
+        let mut records = Vec::new();
+        for account in accounts.iter() {
+            records.extend(account.export_records(&options).await?);
+        }
+
+        format_export_records(&records, options.format)
+    }
+
     /// Stop the background syncing of the accounts
     pub fn stop_background_syncing(&self) -> crate::Result<()> {
         log::debug!("[stop_background_syncing]");
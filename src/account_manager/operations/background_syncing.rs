@@ -46,7 +46,23 @@ impl AccountManager {
                             break 'outer;
                         }
                         match account.sync(options.clone()).await {
-                            Ok(_) => {}
+                            Ok(_) => {
+                                if let Err(err) = account.execute_due_scheduled_payments().await {
+                                    log::debug!("[background_syncing] scheduled payments error: {}", err)
+                                }
+                                if let Err(err) = account.execute_due_recurring_payments().await {
+                                    log::debug!("[background_syncing] recurring payments error: {}", err)
+                                }
+                                if let Err(err) = account.apply_rejection_policy().await {
+                                    log::debug!("[background_syncing] rejection policy error: {}", err)
+                                }
+                                if let Err(err) = account.reclaim_expired_outputs().await {
+                                    log::debug!("[background_syncing] reclaiming expired outputs error: {}", err)
+                                }
+                                if let Err(err) = account.apply_claim_policy().await {
+                                    log::debug!("[background_syncing] claim policy error: {}", err)
+                                }
+                            }
                             Err(err) => log::debug!("[background_syncing] error: {}", err),
                         };
                     }
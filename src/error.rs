@@ -96,6 +96,9 @@ pub enum Error {
     /// Too many outputs
     #[error("too many outputs: {0}, max is {1}")]
     TooManyInputs(usize, u16),
+    /// Insufficient storage deposit
+    #[error("amount {1} for {0} doesn't cover the required storage deposit {2}")]
+    InsufficientStorageDeposit(String, u64, u64),
     /// Funds are spread over too many outputs
     #[error("funds are spread over too many outputs {0}/{1}, consolidation required")]
     ConsolidationRequired(usize, u16),
@@ -111,6 +114,9 @@ pub enum Error {
     /// Nft not found in unspent outputs
     #[error("nft not found in unspent outputs")]
     NftNotFoundInUnspentOutputs,
+    /// Alias not found in unspent outputs
+    #[error("alias not found in unspent outputs")]
+    AliasNotFoundInUnspentOutputs,
     /// Tokio task join error
     #[error("{0}")]
     TaskJoinError(#[from] tokio::task::JoinError),
@@ -132,6 +138,75 @@ pub enum Error {
     /// Local time doesn't match the time of the latest timestamp
     #[error("Local time {0} doesn't match the time of the latest timestamp: {1}")]
     TimeNotSynced(u32, u32),
+    /// Scheduled payment not found
+    #[error("scheduled payment {0} not found")]
+    ScheduledPaymentNotFound(u32),
+    /// Scheduled payment was already executed or cancelled
+    #[error("scheduled payment {0} is not pending")]
+    ScheduledPaymentNotPending(u32),
+    /// Invalid scheduled payment
+    #[error("invalid scheduled payment: {0}")]
+    InvalidScheduledPayment(String),
+    /// Recurring payment not found
+    #[error("recurring payment {0} not found")]
+    RecurringPaymentNotFound(u32),
+    /// Recurring payment was already completed or cancelled
+    #[error("recurring payment {0} is not active")]
+    RecurringPaymentNotActive(u32),
+    /// Invalid recurring payment
+    #[error("invalid recurring payment: {0}")]
+    InvalidRecurringPayment(String),
+    /// Invalid IRC30 native token metadata
+    #[error("invalid token metadata: {0}")]
+    InvalidTokenMetadata(String),
+    /// Invalid IRC27 nft metadata
+    #[error("invalid nft metadata: {0}")]
+    InvalidNftMetadata(String),
+    /// Invalid claim policy
+    #[error("invalid claim policy: {0}")]
+    InvalidClaimPolicy(String),
+    /// Output not found in unspent outputs
+    #[error("output {0} not found in unspent outputs")]
+    OutputNotFoundInUnspentOutputs(iota_client::bee_block::output::OutputId),
+    /// Output can't be returned to the sender
+    #[error("output {0} can't be returned, only unexpired outputs with a storage deposit return can be returned")]
+    OutputNotReturnable(iota_client::bee_block::output::OutputId),
+    /// Invalid rejection policy
+    #[error("invalid rejection policy: {0}")]
+    InvalidRejectionPolicy(String),
+    /// Transaction not found in the sent or incoming transactions
+    #[error("transaction {0} not found in the account")]
+    TransactionNotFound(iota_client::bee_block::payload::transaction::TransactionId),
+    /// Output not found in the outputs of the account
+    #[error("output {0} not found in the account")]
+    OutputNotFound(iota_client::bee_block::output::OutputId),
+    /// Invalid cursor for a paginated query
+    #[error("invalid cursor: {0}")]
+    InvalidCursor(String),
+    /// Invalid payment request URI
+    #[error("invalid payment request: {0}")]
+    InvalidPaymentRequest(String),
+    /// Invoice not found
+    #[error("invoice {0} not found")]
+    InvoiceNotFound(u32),
+    /// Invoice can't be cancelled
+    #[error("invoice {0} can't be cancelled, it's already paid or cancelled")]
+    InvoiceNotCancellable(u32),
+    /// Invalid invoice
+    #[error("invalid invoice: {0}")]
+    InvalidInvoice(String),
+    /// A transaction with the idempotency key is currently created
+    #[error("a transaction with idempotency key {0} is already in progress")]
+    IdempotencyKeyInUse(String),
+    /// Idempotency key with the reserved prefix
+    #[error("invalid idempotency key {0}, keys starting with `iota-wallet/` are reserved")]
+    InvalidIdempotencyKey(String),
+    /// Too many decimals to represent an amount
+    #[error("invalid decimals {0}, the maximum is 77")]
+    InvalidDecimals(u32),
+    /// Decimals of a native token are unknown
+    #[error("no IRC30 metadata with the decimals of token {0}")]
+    MissingTokenDecimals(iota_client::bee_block::output::TokenId),
 }
 
 impl From<iota_client::Error> for Error {
@@ -197,11 +272,13 @@ impl serde::Serialize for Error {
             Self::FailedToGetRemainder => serialize_variant(self, serializer, "FailedToGetRemainder"),
             Self::TooManyOutputs(..) => serialize_variant(self, serializer, "TooManyOutputs"),
             Self::TooManyInputs(..) => serialize_variant(self, serializer, "TooManyInputs"),
+            Self::InsufficientStorageDeposit(..) => serialize_variant(self, serializer, "InsufficientStorageDeposit"),
             Self::ConsolidationRequired(..) => serialize_variant(self, serializer, "ConsolidationRequired"),
             Self::AddressNotFoundInAccount(_) => serialize_variant(self, serializer, "AddressNotFoundInAccount"),
             Self::MintingFailed(_) => serialize_variant(self, serializer, "MintingFailed"),
             Self::BurningOrMeltingFailed(_) => serialize_variant(self, serializer, "BurningOrMeltingFailed"),
             Self::NftNotFoundInUnspentOutputs => serialize_variant(self, serializer, "NftNotFoundInUnspentOutputs"),
+            Self::AliasNotFoundInUnspentOutputs => serialize_variant(self, serializer, "AliasNotFoundInUnspentOutputs"),
             Self::TaskJoinError(_) => serialize_variant(self, serializer, "TaskJoinError"),
             Self::StdThreadJoinError => serialize_variant(self, serializer, "StdThreadJoinError"),
             Self::Blake2b256(_) => serialize_variant(self, serializer, "Blake2b256"),
@@ -209,6 +286,31 @@ impl serde::Serialize for Error {
             Self::ClientNotSet => serialize_variant(self, serializer, "ClientNotSet"),
             Self::Logger(_) => serialize_variant(self, serializer, "Logger"),
             Self::TimeNotSynced(..) => serialize_variant(self, serializer, "TimeNotSynced"),
+            Self::ScheduledPaymentNotFound(_) => serialize_variant(self, serializer, "ScheduledPaymentNotFound"),
+            Self::ScheduledPaymentNotPending(_) => serialize_variant(self, serializer, "ScheduledPaymentNotPending"),
+            Self::InvalidScheduledPayment(_) => serialize_variant(self, serializer, "InvalidScheduledPayment"),
+            Self::RecurringPaymentNotFound(_) => serialize_variant(self, serializer, "RecurringPaymentNotFound"),
+            Self::RecurringPaymentNotActive(_) => serialize_variant(self, serializer, "RecurringPaymentNotActive"),
+            Self::InvalidRecurringPayment(_) => serialize_variant(self, serializer, "InvalidRecurringPayment"),
+            Self::InvalidTokenMetadata(_) => serialize_variant(self, serializer, "InvalidTokenMetadata"),
+            Self::InvalidNftMetadata(_) => serialize_variant(self, serializer, "InvalidNftMetadata"),
+            Self::InvalidClaimPolicy(_) => serialize_variant(self, serializer, "InvalidClaimPolicy"),
+            Self::OutputNotFoundInUnspentOutputs(_) => {
+                serialize_variant(self, serializer, "OutputNotFoundInUnspentOutputs")
+            }
+            Self::OutputNotReturnable(_) => serialize_variant(self, serializer, "OutputNotReturnable"),
+            Self::InvalidRejectionPolicy(_) => serialize_variant(self, serializer, "InvalidRejectionPolicy"),
+            Self::TransactionNotFound(_) => serialize_variant(self, serializer, "TransactionNotFound"),
+            Self::OutputNotFound(_) => serialize_variant(self, serializer, "OutputNotFound"),
+            Self::InvalidCursor(_) => serialize_variant(self, serializer, "InvalidCursor"),
+            Self::InvalidPaymentRequest(_) => serialize_variant(self, serializer, "InvalidPaymentRequest"),
+            Self::InvoiceNotFound(_) => serialize_variant(self, serializer, "InvoiceNotFound"),
+            Self::InvoiceNotCancellable(_) => serialize_variant(self, serializer, "InvoiceNotCancellable"),
+            Self::InvalidInvoice(_) => serialize_variant(self, serializer, "InvalidInvoice"),
+            Self::IdempotencyKeyInUse(_) => serialize_variant(self, serializer, "IdempotencyKeyInUse"),
+            Self::InvalidIdempotencyKey(_) => serialize_variant(self, serializer, "InvalidIdempotencyKey"),
+            Self::InvalidDecimals(_) => serialize_variant(self, serializer, "InvalidDecimals"),
+            Self::MissingTokenDecimals(_) => serialize_variant(self, serializer, "MissingTokenDecimals"),
         }
     }
 }
@@ -39,6 +39,10 @@ impl EventEmitter {
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
                 WalletEventType::ConsolidationRequired,
+                WalletEventType::ScheduledPayment,
+                WalletEventType::RecurringPayment,
+                WalletEventType::OutputsClaimed,
+                WalletEventType::Invoice,
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
             ] {
@@ -73,6 +77,10 @@ impl EventEmitter {
             WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
             WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
             WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
+            WalletEvent::ScheduledPayment(_) => WalletEventType::ScheduledPayment,
+            WalletEvent::RecurringPayment(_) => WalletEventType::RecurringPayment,
+            WalletEvent::OutputsClaimed(_) => WalletEventType::OutputsClaimed,
+            WalletEvent::Invoice(_) => WalletEventType::Invoice,
             #[cfg(feature = "ledger_nano")]
             WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
         };
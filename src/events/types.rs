@@ -2,10 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use getset::Getters;
-use iota_client::{api::PreparedTransactionDataDto, bee_block::payload::transaction::TransactionId};
+use iota_client::{
+    api::PreparedTransactionDataDto,
+    bee_block::{output::OutputId, payload::transaction::TransactionId},
+};
 use serde::{Deserialize, Serialize};
 
-use crate::account::types::{address::AddressWrapper, InclusionState, OutputDataDto};
+use crate::account::{
+    types::{address::AddressWrapper, InclusionState, OutputDataDto},
+    InvoiceStatus, RecurringPaymentRun, ScheduledPaymentStatus,
+};
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Event {
     /// Associated account index.
@@ -24,6 +30,10 @@ pub enum WalletEvent {
     SpentOutput(SpentOutputEvent),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
+    ScheduledPayment(ScheduledPaymentEvent),
+    RecurringPayment(RecurringPaymentEvent),
+    OutputsClaimed(OutputsClaimedEvent),
+    Invoice(InvoiceEvent),
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -35,6 +45,10 @@ pub enum WalletEventType {
     SpentOutput,
     TransactionInclusion,
     TransactionProgress,
+    ScheduledPayment,
+    RecurringPayment,
+    OutputsClaimed,
+    Invoice,
 }
 
 impl TryFrom<&str> for WalletEventType {
@@ -49,6 +63,10 @@ impl TryFrom<&str> for WalletEventType {
             "SpentOutput" => WalletEventType::SpentOutput,
             "TransactionInclusion" => WalletEventType::TransactionInclusion,
             "TransactionProgress" => WalletEventType::TransactionProgress,
+            "ScheduledPayment" => WalletEventType::ScheduledPayment,
+            "RecurringPayment" => WalletEventType::RecurringPayment,
+            "OutputsClaimed" => WalletEventType::OutputsClaimed,
+            "Invoice" => WalletEventType::Invoice,
             _ => return Err(format!("invalid event type {}", value)),
         };
         Ok(event_type)
@@ -75,6 +93,46 @@ pub struct TransactionInclusionEvent {
     pub inclusion_state: InclusionState,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledPaymentEvent {
+    /// Id of the scheduled payment
+    #[serde(rename = "paymentId")]
+    pub payment_id: u32,
+    /// The new status after the payment was executed
+    pub status: ScheduledPaymentStatus,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RecurringPaymentEvent {
+    /// Id of the recurring payment
+    #[serde(rename = "paymentId")]
+    pub payment_id: u32,
+    /// The run that was processed
+    pub run: RecurringPaymentRun,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct OutputsClaimedEvent {
+    /// Id of the claiming transaction
+    #[serde(rename = "transactionId")]
+    pub transaction_id: TransactionId,
+    /// The outputs that were claimed in the transaction
+    #[serde(rename = "outputIds")]
+    pub output_ids: Vec<OutputId>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InvoiceEvent {
+    /// Id of the invoice
+    #[serde(rename = "invoiceId")]
+    pub invoice_id: u32,
+    /// The new status of the invoice
+    pub status: InvoiceStatus,
+    /// Outputs that were matched to the invoice since the last event
+    #[serde(rename = "outputIds")]
+    pub output_ids: Vec<OutputId>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum TransactionProgressEvent {
     /// Performing input selection.
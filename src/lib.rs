@@ -35,11 +35,13 @@ pub use primitive_types::U256;
 
 pub use self::{
     account::operations::transaction::high_level::{
+        alias::AliasOptions,
         minting::{mint_native_token::NativeTokenOptions, mint_nfts::NftOptions},
         send_amount::AddressWithAmount,
         send_micro_transaction::AddressWithMicroAmount,
         send_native_tokens::AddressNativeTokens,
         send_nft::AddressAndNftId,
+        send_payments::Payment,
     },
     error::Error,
 };
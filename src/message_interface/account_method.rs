@@ -4,6 +4,7 @@
 use iota_client::{
     api::{PreparedTransactionDataDto, SignedTransactionDataDto},
     bee_block::{
+        dto::U256Dto,
         output::{
             dto::{AliasIdDto, NativeTokenDto, NftIdDto, OutputDto, TokenIdDto, TokenSchemeDto},
             feature::dto::FeatureDto,
@@ -18,12 +19,22 @@ use serde::Deserialize;
 use crate::{
     account::operations::{
         address_generation::AddressGenerationOptions,
-        output_claiming::OutputsToClaim,
+        amount_formatting::AmountFormatOptions,
+        annotations::Annotation,
+        invoices::InvoiceOptionsDto,
+        output_claiming::{ClaimPolicy, OutputsToClaim},
+        output_query::OutputQuery,
+        output_rejection::RejectionPolicy,
+        payment_request::PaymentRequestDto,
+        recurring_payments::RecurringPaymentSchedule,
+        scheduled_payments::ScheduledPaymentTrigger,
         syncing::SyncOptions,
         transaction::{prepare_output::OutputOptionsDto, TransactionOptions},
+        transaction_export::ExportOptions,
+        transaction_query::TransactionQuery,
     },
-    message_interface::dtos::{AddressWithAmountDto, AddressWithMicroAmountDto},
-    AddressAndNftId, AddressNativeTokens, NativeTokenOptions, NftOptions,
+    message_interface::dtos::{AddressWithAmountDto, AddressWithMicroAmountDto, PaymentDto},
+    AddressAndNftId, AddressNativeTokens, AliasOptions, NativeTokenOptions, NftOptions,
 };
 
 /// Each public account method.
@@ -135,6 +146,38 @@ pub enum AccountMethod {
         #[serde(rename = "transactionId")]
         transaction_id: TransactionId,
     },
+    /// Decode a sent or incoming transaction of the account into its effect on the account
+    /// Expected response: [`DecodedTransaction`](crate::message_interface::Response::DecodedTransaction)
+    DecodeTransaction {
+        #[serde(rename = "transactionId")]
+        transaction_id: TransactionId,
+    },
+    /// Set the private note and tags of a sent or incoming transaction, an empty annotation removes them.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    AnnotateTransaction {
+        #[serde(rename = "transactionId")]
+        transaction_id: TransactionId,
+        annotation: Annotation,
+    },
+    /// Get the private note and tags of a transaction.
+    /// Expected response: [`Annotation`](crate::message_interface::Response::Annotation)
+    GetTransactionAnnotation {
+        #[serde(rename = "transactionId")]
+        transaction_id: TransactionId,
+    },
+    /// Set the private note and tags of an output, an empty annotation removes them.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    AnnotateOutput {
+        #[serde(rename = "outputId")]
+        output_id: OutputId,
+        annotation: Annotation,
+    },
+    /// Get the private note and tags of an output.
+    /// Expected response: [`Annotation`](crate::message_interface::Response::Annotation)
+    GetOutputAnnotation {
+        #[serde(rename = "outputId")]
+        output_id: OutputId,
+    },
     /// Get the transaction with inputs of an incoming transaction stored in the account
     /// List might not be complete, if the node pruned the data already
     /// Expected response: [`IncomingTransactionData`](crate::message_interface::Response::IncomingTransactionData)
@@ -149,18 +192,71 @@ pub enum AccountMethod {
     /// Expected response:
     /// [`AddressesWithUnspentOutputs`](crate::message_interface::Response::AddressesWithUnspentOutputs)
     ListAddressesWithUnspentOutputs,
+    /// Get the balance of each public and internal address of the account.
+    /// Expected response: [`AddressBalances`](crate::message_interface::Response::AddressBalances)
+    GetAddressBalances,
+    /// Set the resolution of the balance history in milliseconds.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    SetBalanceHistoryResolution {
+        #[serde(rename = "resolutionInMilliseconds")]
+        resolution_in_milliseconds: u64,
+    },
+    /// Get the latest balance snapshot taken at or before a time in milliseconds.
+    /// Expected response: [`BalanceSnapshot`](crate::message_interface::Response::BalanceSnapshot)
+    GetBalanceAt { timestamp: u128 },
+    /// Get the balance snapshots taken within a time range in milliseconds.
+    /// Expected response: [`BalanceHistory`](crate::message_interface::Response::BalanceHistory)
+    GetBalanceHistory {
+        #[serde(rename = "fromTime")]
+        from_time: Option<u128>,
+        #[serde(rename = "toTime")]
+        to_time: Option<u128>,
+    },
+    /// Format an amount of the base coin with the decimals from the node info.
+    /// Expected response: [`FormattedAmount`](crate::message_interface::Response::FormattedAmount)
+    FormatBaseCoinAmount {
+        amount: String,
+        options: Option<AmountFormatOptions>,
+    },
+    /// Parse a human-readable amount of the base coin.
+    /// Expected response: [`BaseCoinAmount`](crate::message_interface::Response::BaseCoinAmount)
+    ParseBaseCoinAmount { amount: String },
+    /// Format an amount of a native token with the decimals from its IRC30 metadata.
+    /// Expected response: [`FormattedAmount`](crate::message_interface::Response::FormattedAmount)
+    FormatNativeTokenAmount {
+        #[serde(rename = "tokenId")]
+        token_id: TokenIdDto,
+        amount: U256Dto,
+        options: Option<AmountFormatOptions>,
+    },
+    /// Parse a human-readable amount of a native token.
+    /// Expected response: [`NativeTokenAmount`](crate::message_interface::Response::NativeTokenAmount)
+    ParseNativeTokenAmount {
+        #[serde(rename = "tokenId")]
+        token_id: TokenIdDto,
+        amount: String,
+    },
     /// Returns all outputs of the account
     /// Expected response: [`OutputsData`](crate::message_interface::Response::OutputsData)
     ListOutputs,
     /// Returns all unspent outputs of the account
     /// Expected response: [`OutputsData`](crate::message_interface::Response::OutputsData)
     ListUnspentOutputs,
+    /// Query the outputs of the account with a filter, sorted and paginated.
+    /// Expected response: [`OutputPage`](crate::message_interface::Response::OutputPage)
+    QueryOutputs { query: OutputQuery },
     /// Returns all transaction of the account
     /// Expected response: [`Transactions`](crate::message_interface::Response::Transactions)
     ListTransactions,
     /// Returns all pending transaction of the account
     /// Expected response: [`Transactions`](crate::message_interface::Response::Transactions)
     ListPendingTransactions,
+    /// Query the transactions of the account with a filter, sorted by their timestamp and paginated.
+    /// Expected response: [`TransactionPage`](crate::message_interface::Response::TransactionPage)
+    QueryTransactions { query: TransactionQuery },
+    /// Export the transactions of the account as CSV or JSON lines.
+    /// Expected response: [`ExportedTransactions`](crate::message_interface::Response::ExportedTransactions)
+    ExportTransactions { options: ExportOptions },
     /// Calculate the minimum required storage deposit for an output.
     /// Expected response:
     /// [`MinimumRequiredStorageDeposit`](crate::message_interface::Response::MinimumRequiredStorageDeposit)
@@ -172,6 +268,20 @@ pub enum AccountMethod {
         native_token_options: NativeTokenOptions,
         options: Option<TransactionOptions>,
     },
+    /// Mint additional native tokens with an existing foundry.
+    /// Expected response: [`MintTokenTransaction`](crate::message_interface::Response::MintTokenTransaction)
+    IncreaseNativeTokenSupply {
+        #[serde(rename = "tokenId")]
+        token_id: TokenIdDto,
+        #[serde(rename = "increaseAmount")]
+        increase_amount: U256Dto,
+        #[serde(rename = "accountAddress")]
+        account_address: Option<String>,
+        options: Option<TransactionOptions>,
+    },
+    /// List the nfts of the account with their IRC27 metadata.
+    /// Expected response: [`Nfts`](crate::message_interface::Response::Nfts)
+    ListNfts,
     /// Mint nft.
     /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
     MintNfts {
@@ -179,6 +289,72 @@ pub enum AccountMethod {
         nfts_options: Vec<NftOptions>,
         options: Option<TransactionOptions>,
     },
+    /// Mint the nft that represents a collection.
+    /// Expected response: [`MintCollectionTransaction`](crate::message_interface::Response::MintCollectionTransaction)
+    MintCollection {
+        #[serde(rename = "collectionOptions")]
+        collection_options: NftOptions,
+        options: Option<TransactionOptions>,
+    },
+    /// Mint nfts with a collection nft or alias as issuer, split over multiple transactions if needed.
+    /// Expected response: [`SentTransactions`](crate::message_interface::Response::SentTransactions)
+    MintCollectionNfts {
+        issuer: String,
+        #[serde(rename = "nftOptions")]
+        nfts_options: Vec<NftOptions>,
+        options: Option<TransactionOptions>,
+    },
+    /// List the nfts of the account that were minted with the provided issuer.
+    /// Expected response: [`Nfts`](crate::message_interface::Response::Nfts)
+    ListCollectionNfts { issuer: String },
+    /// Create an alias output.
+    /// Expected response: [`CreateAliasTransaction`](crate::message_interface::Response::CreateAliasTransaction)
+    CreateAlias {
+        #[serde(rename = "aliasOptions")]
+        alias_options: AliasOptions,
+        options: Option<TransactionOptions>,
+    },
+    /// Update the state metadata of an alias with a state transition.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    UpdateAliasStateMetadata {
+        #[serde(rename = "aliasId")]
+        alias_id: AliasIdDto,
+        #[serde(rename = "stateMetadata")]
+        state_metadata: Vec<u8>,
+        options: Option<TransactionOptions>,
+    },
+    /// Set or remove the metadata feature of an alias with a governance transition.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    UpdateAliasGovernorMetadata {
+        #[serde(rename = "aliasId")]
+        alias_id: AliasIdDto,
+        metadata: Option<Vec<u8>>,
+        options: Option<TransactionOptions>,
+    },
+    /// Change the state controller address of an alias.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    SetAliasStateController {
+        #[serde(rename = "aliasId")]
+        alias_id: AliasIdDto,
+        address: String,
+        options: Option<TransactionOptions>,
+    },
+    /// Change the governor address of an alias.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    SetAliasGovernor {
+        #[serde(rename = "aliasId")]
+        alias_id: AliasIdDto,
+        address: String,
+        options: Option<TransactionOptions>,
+    },
+    /// Transfer an alias by setting the state controller and governor to the provided address.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    TransferAlias {
+        #[serde(rename = "aliasId")]
+        alias_id: AliasIdDto,
+        address: String,
+        options: Option<TransactionOptions>,
+    },
     /// Get account balance information.
     /// Expected response: [`Balance`](crate::message_interface::Response::Balance)
     GetBalance,
@@ -188,6 +364,15 @@ pub enum AccountMethod {
         options: OutputOptionsDto,
         transaction_options: Option<TransactionOptions>,
     },
+    /// Create an `iota:` payment request URI.
+    /// Expected response: [`PaymentRequestUri`](crate::message_interface::Response::PaymentRequestUri)
+    CreatePaymentRequestUri {
+        #[serde(rename = "paymentRequest")]
+        payment_request: PaymentRequestDto,
+    },
+    /// Parse an `iota:` payment request URI into the options for `PrepareOutput`.
+    /// Expected response: [`OutputOptions`](crate::message_interface::Response::OutputOptions)
+    ParsePaymentRequestUri { uri: String },
     /// Prepare transaction.
     /// Expected response: [`PreparedTransactionData`](crate::message_interface::Response::PreparedTransactionData)
     PrepareTransaction {
@@ -236,6 +421,12 @@ pub enum AccountMethod {
         addresses_nft_ids: Vec<AddressAndNftId>,
         options: Option<TransactionOptions>,
     },
+    /// Send base coins, native tokens and nfts to multiple addresses in as few transactions as possible.
+    /// Expected response: [`SentTransactions`](crate::message_interface::Response::SentTransactions)
+    SendPayments {
+        payments: Vec<PaymentDto>,
+        options: Option<TransactionOptions>,
+    },
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
     SetAlias { alias: String },
@@ -245,6 +436,14 @@ pub enum AccountMethod {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptions>,
     },
+    /// Send outputs with the funds of an alias or nft address of the account.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    SendFromChainAddress {
+        #[serde(rename = "chainAddress")]
+        chain_address: String,
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptions>,
+    },
     /// Sign a prepared transaction.
     /// Expected response: [`TransactionPayload`](crate::message_interface::Response::TransactionPayload)
     SignTransactionEssence {
@@ -269,4 +468,106 @@ pub enum AccountMethod {
         #[serde(rename = "outputIdsToClaim")]
         output_ids_to_claim: Vec<OutputId>,
     },
+    /// Get outputs sent by the account that expired without being claimed by the recipient.
+    /// Expected response: [`OutputIds`](crate::message_interface::Response::OutputIds)
+    GetExpiredOutgoingOutputs,
+    /// Claim back outputs sent by the account that expired without being claimed by the recipient.
+    /// Expected response: [`SentTransactions`](crate::message_interface::Response::SentTransactions)
+    ReclaimExpiredOutputs,
+    /// Set the policy which outputs are claimed automatically by the background syncing.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    SetClaimPolicy {
+        #[serde(rename = "claimPolicy")]
+        claim_policy: ClaimPolicy,
+    },
+    /// Get the policy which outputs are claimed automatically by the background syncing.
+    /// Expected response: [`ClaimPolicy`](crate::message_interface::Response::ClaimPolicy)
+    GetClaimPolicy,
+    /// Set the policy which incoming outputs are rejected automatically by the background syncing, None disables it.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    SetRejectionPolicy {
+        #[serde(rename = "rejectionPolicy")]
+        rejection_policy: Option<RejectionPolicy>,
+    },
+    /// Get the policy which incoming outputs are rejected automatically by the background syncing.
+    /// Expected response: [`RejectionPolicy`](crate::message_interface::Response::RejectionPolicy)
+    GetRejectionPolicy,
+    /// Exclude outputs from the balance, input selection and claiming.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    IgnoreOutputs {
+        #[serde(rename = "outputIds")]
+        output_ids: Vec<OutputId>,
+    },
+    /// Include ignored outputs in the balance again.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    UnignoreOutputs {
+        #[serde(rename = "outputIds")]
+        output_ids: Vec<OutputId>,
+    },
+    /// Send outputs with a storage deposit return unlock condition back to their return address.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    ReturnOutputs {
+        #[serde(rename = "outputIds")]
+        output_ids: Vec<OutputId>,
+        options: Option<TransactionOptions>,
+    },
+    /// Schedule a payment that will be sent once it's due.
+    /// Expected response: [`ScheduledPayment`](crate::message_interface::Response::ScheduledPayment)
+    SchedulePayment {
+        outputs: Vec<OutputOptionsDto>,
+        trigger: ScheduledPaymentTrigger,
+        options: Option<TransactionOptions>,
+    },
+    /// Returns all scheduled payments of the account
+    /// Expected response: [`ScheduledPayments`](crate::message_interface::Response::ScheduledPayments)
+    ListScheduledPayments,
+    /// Replace the outputs, trigger and options of a pending or failed scheduled payment.
+    /// Expected response: [`ScheduledPayment`](crate::message_interface::Response::ScheduledPayment)
+    EditScheduledPayment {
+        #[serde(rename = "paymentId")]
+        payment_id: u32,
+        outputs: Vec<OutputOptionsDto>,
+        trigger: ScheduledPaymentTrigger,
+        options: Option<TransactionOptions>,
+    },
+    /// Cancel a pending or failed scheduled payment.
+    /// Expected response: [`ScheduledPayment`](crate::message_interface::Response::ScheduledPayment)
+    CancelScheduledPayment {
+        #[serde(rename = "paymentId")]
+        payment_id: u32,
+    },
+    /// Create a payment that will be sent repeatedly according to the schedule.
+    /// Expected response: [`RecurringPayment`](crate::message_interface::Response::RecurringPayment)
+    CreateRecurringPayment {
+        outputs: Vec<OutputOptionsDto>,
+        schedule: RecurringPaymentSchedule,
+        options: Option<TransactionOptions>,
+    },
+    /// Returns all recurring payments of the account with their runs
+    /// Expected response: [`RecurringPayments`](crate::message_interface::Response::RecurringPayments)
+    ListRecurringPayments,
+    /// Cancel an active recurring payment.
+    /// Expected response: [`RecurringPayment`](crate::message_interface::Response::RecurringPayment)
+    CancelRecurringPayment {
+        #[serde(rename = "paymentId")]
+        payment_id: u32,
+    },
+    /// Create an invoice with a new public address, payments to it are matched to the invoice during syncing.
+    /// Expected response: [`Invoice`](crate::message_interface::Response::Invoice)
+    CreateInvoice { options: InvoiceOptionsDto },
+    /// Get an invoice by its id.
+    /// Expected response: [`Invoice`](crate::message_interface::Response::Invoice)
+    GetInvoice {
+        #[serde(rename = "invoiceId")]
+        invoice_id: u32,
+    },
+    /// Returns all invoices of the account with their payments.
+    /// Expected response: [`Invoices`](crate::message_interface::Response::Invoices)
+    ListInvoices,
+    /// Cancel an invoice that isn't paid yet.
+    /// Expected response: [`Invoice`](crate::message_interface::Response::Invoice)
+    CancelInvoice {
+        #[serde(rename = "invoiceId")]
+        invoice_id: u32,
+    },
 }
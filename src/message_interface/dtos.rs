@@ -10,7 +10,10 @@ use std::{
 
 use iota_client::bee_block::{
     dto::U256Dto,
-    output::{dto::TokenIdDto, AliasId, FoundryId, NftId, OutputId},
+    output::{
+        dto::{NativeTokenDto, TokenIdDto},
+        AliasId, FoundryId, NftId, OutputId,
+    },
     payload::transaction::TransactionId,
 };
 use serde::{Deserialize, Serialize};
@@ -18,12 +21,13 @@ use serde::{Deserialize, Serialize};
 use crate::{
     account::{
         types::{
-            address::AddressWrapper, AccountAddress, AccountBalance, AddressWithUnspentOutputs, BaseCoinBalance,
-            NativeTokensBalance, TransactionDto,
+            address::AddressWrapper, AccountAddress, AccountBalance, AddressBalance, AddressWithUnspentOutputs,
+            BaseCoinBalance, ChainAddressBalance, Irc30Metadata, LockedBalance, LockedOutput, NativeTokensBalance,
+            RequiredStorageDeposit, TransactionDto,
         },
-        Account, OutputDataDto,
+        Account, BalanceSnapshot, NativeTokenTotal, OutputDataDto,
     },
-    AddressWithAmount, AddressWithMicroAmount,
+    AddressAndNftId, AddressNativeTokens, AddressWithAmount, AddressWithMicroAmount, Payment,
 };
 
 /// Dto for address with amount for `send_amount()`
@@ -76,6 +80,30 @@ impl TryFrom<&AddressWithMicroAmountDto> for AddressWithMicroAmount {
     }
 }
 
+/// Dto for a payment for `send_payments()`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum PaymentDto {
+    /// Send an amount of the base coin
+    Amount(AddressWithAmountDto),
+    /// Send native tokens
+    NativeTokens(AddressNativeTokens),
+    /// Send an nft
+    Nft(AddressAndNftId),
+}
+
+impl TryFrom<&PaymentDto> for Payment {
+    type Error = crate::Error;
+
+    fn try_from(value: &PaymentDto) -> crate::Result<Self> {
+        Ok(match value {
+            PaymentDto::Amount(address_with_amount) => Self::Amount(AddressWithAmount::try_from(address_with_amount)?),
+            PaymentDto::NativeTokens(address_native_tokens) => Self::NativeTokens(address_native_tokens.clone()),
+            PaymentDto::Nft(address_and_nft_id) => Self::Nft(address_and_nft_id.clone()),
+        })
+    }
+}
+
 /// Dto for an account address with output_ids of unspent outputs.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct AddressWithUnspentOutputsDto {
@@ -131,6 +159,14 @@ pub struct NativeTokensBalanceDto {
     pub total: U256Dto,
     /// Balance that can currently be spent
     pub available: U256Dto,
+    /// IRC30 metadata of the token
+    pub metadata: Option<Irc30Metadata>,
+    /// Total amount adjusted by the decimals from the metadata
+    #[serde(rename = "formattedTotal")]
+    pub formatted_total: Option<String>,
+    /// Available amount adjusted by the decimals from the metadata
+    #[serde(rename = "formattedAvailable")]
+    pub formatted_available: Option<String>,
 }
 
 impl From<&NativeTokensBalance> for NativeTokensBalanceDto {
@@ -139,6 +175,90 @@ impl From<&NativeTokensBalance> for NativeTokensBalanceDto {
             token_id: TokenIdDto::from(&value.token_id),
             total: U256Dto::from(&value.total),
             available: U256Dto::from(&value.available),
+            metadata: value.metadata.clone(),
+            formatted_total: value.formatted_total(),
+            formatted_available: value.formatted_available(),
+        }
+    }
+}
+
+/// Dto for [`ChainAddressBalance`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ChainAddressBalanceDto {
+    /// Bech32 encoded alias or nft address
+    pub address: String,
+    /// Total and available amount of the base coin
+    #[serde(rename = "baseCoin")]
+    pub base_coin: BaseCoinBalanceDto,
+    /// Native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokensBalanceDto>,
+    /// Nfts
+    pub nfts: Vec<NftId>,
+    /// Output ids of the outputs locked to the address
+    #[serde(rename = "outputIds")]
+    pub output_ids: Vec<OutputId>,
+}
+
+impl From<&ChainAddressBalance> for ChainAddressBalanceDto {
+    fn from(value: &ChainAddressBalance) -> Self {
+        Self {
+            address: value.address.clone(),
+            base_coin: BaseCoinBalanceDto::from(&value.base_coin),
+            native_tokens: value
+                .native_tokens
+                .iter()
+                .map(NativeTokensBalanceDto::from)
+                .collect::<_>(),
+            nfts: value.nfts.clone(),
+            output_ids: value.output_ids.clone(),
+        }
+    }
+}
+
+/// Dto for [`AddressBalance`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AddressBalanceDto {
+    /// Bech32 encoded address
+    pub address: String,
+    /// The address key index
+    #[serde(rename = "keyIndex")]
+    pub key_index: u32,
+    /// Determines if an address is a public or an internal (change) address
+    pub internal: bool,
+    /// Total and available amount of the base coin
+    #[serde(rename = "baseCoin")]
+    pub base_coin: BaseCoinBalanceDto,
+    /// Required storage deposit amount of the outputs of the address
+    #[serde(rename = "requiredStorageDeposit")]
+    pub required_storage_deposit: String,
+    /// Native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokensBalanceDto>,
+    /// Nfts
+    pub nfts: Vec<NftId>,
+    /// Aliases
+    pub aliases: Vec<AliasId>,
+    /// Foundries
+    pub foundries: Vec<FoundryId>,
+}
+
+impl From<&AddressBalance> for AddressBalanceDto {
+    fn from(value: &AddressBalance) -> Self {
+        Self {
+            address: value.address.clone(),
+            key_index: value.key_index,
+            internal: value.internal,
+            base_coin: BaseCoinBalanceDto::from(&value.base_coin),
+            required_storage_deposit: value.required_storage_deposit.to_string(),
+            native_tokens: value
+                .native_tokens
+                .iter()
+                .map(NativeTokensBalanceDto::from)
+                .collect::<_>(),
+            nfts: value.nfts.clone(),
+            aliases: value.aliases.clone(),
+            foundries: value.foundries.clone(),
         }
     }
 }
@@ -166,6 +286,15 @@ pub struct AccountBalanceDto {
     /// [`TimelockUnlockCondition`] or [`ExpirationUnlockCondition`] this can change at any time
     #[serde(rename = "potentiallyLockedOutputs")]
     pub potentially_locked_outputs: HashMap<OutputId, bool>,
+    /// Balances of the alias and nft addresses owned by the account
+    #[serde(rename = "chainAddresses")]
+    pub chain_addresses: Vec<ChainAddressBalanceDto>,
+    /// Outputs that were rejected and are excluded from the balance
+    #[serde(rename = "ignoredOutputs")]
+    pub ignored_outputs: Vec<OutputId>,
+    /// Breakdown of the outputs that can't be spent at the moment by the reason why
+    #[serde(rename = "lockedBalance")]
+    pub locked_balance: LockedBalanceDto,
 }
 
 impl From<&AccountBalance> for AccountBalanceDto {
@@ -182,6 +311,148 @@ impl From<&AccountBalance> for AccountBalanceDto {
             aliases: value.aliases.clone(),
             foundries: value.foundries.clone(),
             potentially_locked_outputs: value.potentially_locked_outputs.clone(),
+            chain_addresses: value
+                .chain_addresses
+                .iter()
+                .map(ChainAddressBalanceDto::from)
+                .collect::<_>(),
+            ignored_outputs: value.ignored_outputs.clone(),
+            locked_balance: LockedBalanceDto::from(&value.locked_balance),
+        }
+    }
+}
+
+/// Dto for [`BalanceSnapshot`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BalanceSnapshotDto {
+    /// Time of the sync in milliseconds
+    pub timestamp: String,
+    /// Total and available amount of the base coin after the sync
+    #[serde(rename = "baseCoin")]
+    pub base_coin: BaseCoinBalanceDto,
+    /// Total amounts of the native tokens after the sync
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokenTotalDto>,
+}
+
+impl From<&BalanceSnapshot> for BalanceSnapshotDto {
+    fn from(value: &BalanceSnapshot) -> Self {
+        Self {
+            timestamp: value.timestamp.to_string(),
+            base_coin: BaseCoinBalanceDto::from(&value.base_coin),
+            native_tokens: value.native_tokens.iter().map(NativeTokenTotalDto::from).collect(),
+        }
+    }
+}
+
+/// Dto for [`NativeTokenTotal`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct NativeTokenTotalDto {
+    /// Token id
+    #[serde(rename = "tokenId")]
+    pub token_id: TokenIdDto,
+    /// Total amount
+    pub total: U256Dto,
+}
+
+impl From<&NativeTokenTotal> for NativeTokenTotalDto {
+    fn from(value: &NativeTokenTotal) -> Self {
+        Self {
+            token_id: TokenIdDto::from(&value.token_id),
+            total: U256Dto::from(&value.total),
+        }
+    }
+}
+
+/// Dto for [`LockedBalance`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LockedBalanceDto {
+    /// Outputs that can be unlocked by the account once their timelock expired
+    pub timelocked: Vec<LockedOutputDto>,
+    /// Outputs sent by the account that can be claimed back after their expiration
+    #[serde(rename = "claimableAfterExpiration")]
+    pub claimable_after_expiration: Vec<LockedOutputDto>,
+    /// Outputs with a storage deposit return unlock condition, the amount is what needs to be sent back
+    #[serde(rename = "storageDepositReturns")]
+    pub storage_deposit_returns: Vec<LockedOutputDto>,
+    /// Outputs that are used as inputs in pending transactions
+    #[serde(rename = "pendingTransactions")]
+    pub pending_transactions: Vec<LockedOutputDto>,
+    /// Outputs that were rejected and are ignored by the account
+    pub frozen: Vec<LockedOutputDto>,
+    /// Required storage deposit of the outputs in the balance by output kind
+    #[serde(rename = "storageDeposit")]
+    pub storage_deposit: RequiredStorageDepositDto,
+}
+
+impl From<&LockedBalance> for LockedBalanceDto {
+    fn from(value: &LockedBalance) -> Self {
+        Self {
+            timelocked: value.timelocked.iter().map(LockedOutputDto::from).collect(),
+            claimable_after_expiration: value
+                .claimable_after_expiration
+                .iter()
+                .map(LockedOutputDto::from)
+                .collect(),
+            storage_deposit_returns: value
+                .storage_deposit_returns
+                .iter()
+                .map(LockedOutputDto::from)
+                .collect(),
+            pending_transactions: value.pending_transactions.iter().map(LockedOutputDto::from).collect(),
+            frozen: value.frozen.iter().map(LockedOutputDto::from).collect(),
+            storage_deposit: RequiredStorageDepositDto::from(&value.storage_deposit),
+        }
+    }
+}
+
+/// Dto for [`LockedOutput`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LockedOutputDto {
+    /// The output id
+    #[serde(rename = "outputId")]
+    pub output_id: OutputId,
+    /// Locked amount of the base coin
+    pub amount: String,
+    /// Locked native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokenDto>,
+    /// The nft id if it's an nft output
+    #[serde(rename = "nftId")]
+    pub nft_id: Option<NftId>,
+    /// Unix time in seconds from which on the output can be unlocked, if it's known
+    #[serde(rename = "unlockTime")]
+    pub unlock_time: Option<u32>,
+}
+
+impl From<&LockedOutput> for LockedOutputDto {
+    fn from(value: &LockedOutput) -> Self {
+        Self {
+            output_id: value.output_id,
+            amount: value.amount.to_string(),
+            native_tokens: value.native_tokens.iter().map(NativeTokenDto::from).collect(),
+            nft_id: value.nft_id,
+            unlock_time: value.unlock_time,
+        }
+    }
+}
+
+/// Dto for [`RequiredStorageDeposit`]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RequiredStorageDepositDto {
+    pub alias: String,
+    pub basic: String,
+    pub foundry: String,
+    pub nft: String,
+}
+
+impl From<&RequiredStorageDeposit> for RequiredStorageDepositDto {
+    fn from(value: &RequiredStorageDeposit) -> Self {
+        Self {
+            alias: value.alias.to_string(),
+            basic: value.basic.to_string(),
+            foundry: value.foundry.to_string(),
+            nft: value.nft.to_string(),
         }
     }
 }
@@ -14,7 +14,10 @@ use super::account_method::AccountMethod;
 #[cfg(debug_assertions)]
 use crate::events::types::WalletEvent;
 use crate::{
-    account::{operations::syncing::SyncOptions, types::AccountIdentifier},
+    account::{
+        operations::{syncing::SyncOptions, transaction_export::ExportOptions},
+        types::AccountIdentifier,
+    },
     ClientOptions,
 };
 
@@ -149,6 +152,12 @@ pub enum Message {
     /// Stop background syncing.
     /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
     StopBackgroundSync,
+    /// Resume or reconcile transactions of all accounts which didn't finish, for example because of a crash.
+    /// Expected response: [`SentTransactions`](crate::message_interface::Response::SentTransactions)
+    ReconcileOutboxes,
+    /// Export the transactions of all accounts as CSV or JSON lines.
+    /// Expected response: [`ExportedTransactions`](crate::message_interface::Response::ExportedTransactions)
+    ExportTransactions(ExportOptions),
     /// Emits an event for testing if the event system is working
     /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
     #[cfg(feature = "events")]
@@ -231,6 +240,8 @@ impl Debug for Message {
                 options, interval_in_milliseconds
             ),
             Message::StopBackgroundSync => write!(f, "StopBackgroundSync"),
+            Message::ReconcileOutboxes => write!(f, "ReconcileOutboxes"),
+            Message::ExportTransactions(options) => write!(f, "ExportTransactions({:?})", options),
             #[cfg(feature = "events")]
             #[cfg(debug_assertions)]
             Message::EmitTestEvent(event) => write!(f, "EmitTestEvent({:?})", event),
@@ -300,6 +311,8 @@ impl Serialize for Message {
             Message::HexToBech32 { .. } => serializer.serialize_unit_variant("Message", 24, "HexToBech32"),
             #[cfg(feature = "ledger_nano")]
             Message::GetLedgerStatus => serializer.serialize_unit_variant("Message", 25, "GetLedgerStatus"),
+            Message::ReconcileOutboxes => serializer.serialize_unit_variant("Message", 26, "ReconcileOutboxes"),
+            Message::ExportTransactions(_) => serializer.serialize_unit_variant("Message", 27, "ExportTransactions"),
         }
     }
 }
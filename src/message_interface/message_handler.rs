@@ -13,7 +13,8 @@ use futures::{Future, FutureExt};
 use iota_client::{
     api::{PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto},
     bee_block::{
-        output::{dto::OutputDto, ByteCost, Output, TokenId},
+        dto::U256Dto,
+        output::{dto::OutputDto, AliasId, ByteCost, Output, TokenId},
         payload::transaction::dto::TransactionPayloadDto,
     },
     constants::SHIMMER_TESTNET_BECH32_HRP,
@@ -22,6 +23,7 @@ use iota_client::{
     },
     utils, Client, NodeInfoWrapper,
 };
+use primitive_types::U256;
 use tokio::sync::mpsc::UnboundedSender;
 use zeroize::Zeroize;
 
@@ -30,20 +32,25 @@ use crate::events::types::{Event, WalletEventType};
 use crate::{
     account::{
         operations::transaction::{
-            high_level::minting::mint_native_token::MintTokenTransactionDto, prepare_output::OutputOptions,
+            high_level::{
+                alias::CreateAliasTransactionDto,
+                minting::{mint_collection::MintCollectionTransactionDto, mint_native_token::MintTokenTransactionDto},
+            },
+            prepare_output::{OutputOptions, OutputOptionsDto},
         },
         types::{AccountIdentifier, TransactionDto},
-        OutputDataDto,
+        DecodedTransactionDto, InvoiceDto, InvoiceOptions, NftWithMetadataDto, OutputDataDto, OutputPageDto,
+        PaymentRequest, RecurringPaymentDto, ScheduledPaymentDto, TransactionPageDto,
     },
     account_manager::AccountManager,
     message_interface::{
         account_method::AccountMethod,
-        dtos::{AccountBalanceDto, AccountDto},
+        dtos::{AccountBalanceDto, AccountDto, AddressBalanceDto, BalanceSnapshotDto},
         message::{AccountToCreate, Message},
         response::Response,
         AddressWithUnspentOutputsDto,
     },
-    AddressWithAmount, AddressWithMicroAmount, Result,
+    AddressWithAmount, AddressWithMicroAmount, Payment, Result,
 };
 
 fn panic_to_response_message(panic: Box<dyn Any>) -> Response {
@@ -279,6 +286,19 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            Message::ReconcileOutboxes => {
+                convert_async_panics(|| async {
+                    let transactions = self.account_manager.reconcile_outboxes().await?;
+                    Ok(Response::SentTransactions(
+                        transactions.iter().map(TransactionDto::from).collect(),
+                    ))
+                })
+                .await
+            }
+            Message::ExportTransactions(options) => {
+                let export = self.account_manager.export_transactions(options).await?;
+                Ok(Response::ExportedTransactions(export))
+            }
             #[cfg(feature = "events")]
             #[cfg(debug_assertions)]
             Message::EmitTestEvent(event) => {
@@ -458,6 +478,36 @@ impl WalletMessageHandler {
                     transaction.as_ref().map(TransactionDto::from).map(Box::new),
                 ))
             }
+            AccountMethod::DecodeTransaction { transaction_id } => {
+                let decoded_transaction = account_handle.decode_transaction(transaction_id).await?;
+                Ok(Response::DecodedTransaction(
+                    decoded_transaction
+                        .as_ref()
+                        .map(DecodedTransactionDto::from)
+                        .map(Box::new),
+                ))
+            }
+            AccountMethod::AnnotateTransaction {
+                transaction_id,
+                annotation,
+            } => {
+                account_handle
+                    .annotate_transaction(*transaction_id, annotation.clone())
+                    .await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::GetTransactionAnnotation { transaction_id } => {
+                let annotation = account_handle.get_transaction_annotation(transaction_id).await;
+                Ok(Response::Annotation(annotation))
+            }
+            AccountMethod::AnnotateOutput { output_id, annotation } => {
+                account_handle.annotate_output(*output_id, annotation.clone()).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::GetOutputAnnotation { output_id } => {
+                let annotation = account_handle.get_output_annotation(output_id).await;
+                Ok(Response::Annotation(annotation))
+            }
             AccountMethod::GetIncomingTransactionData { transaction_id } => {
                 let transaction_data = account_handle.get_incoming_transaction_data(transaction_id).await;
                 match transaction_data {
@@ -486,6 +536,10 @@ impl WalletMessageHandler {
                 let outputs = account_handle.list_unspent_outputs().await?;
                 Ok(Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect()))
             }
+            AccountMethod::QueryOutputs { query } => {
+                let output_page = account_handle.query_outputs(query.clone()).await?;
+                Ok(Response::OutputPage(OutputPageDto::from(&output_page)))
+            }
             AccountMethod::ListTransactions => {
                 let transactions = account_handle.list_transactions().await?;
                 Ok(Response::Transactions(
@@ -498,6 +552,14 @@ impl WalletMessageHandler {
                     transactions.iter().map(TransactionDto::from).collect(),
                 ))
             }
+            AccountMethod::ExportTransactions { options } => {
+                let export = account_handle.export_transactions(options.clone()).await?;
+                Ok(Response::ExportedTransactions(export))
+            }
+            AccountMethod::QueryTransactions { query } => {
+                let transaction_page = account_handle.query_transactions(query.clone()).await?;
+                Ok(Response::TransactionPage(TransactionPageDto::from(&transaction_page)))
+            }
             AccountMethod::MintNativeToken {
                 native_token_options,
                 options,
@@ -512,6 +574,33 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::IncreaseNativeTokenSupply {
+                token_id,
+                increase_amount,
+                account_address,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let token_id = TokenId::try_from(token_id)?;
+                    let increase_amount = U256::try_from(increase_amount)?;
+                    let transaction = account_handle
+                        .increase_native_token_supply(
+                            token_id,
+                            increase_amount,
+                            account_address.clone(),
+                            options.clone(),
+                        )
+                        .await?;
+                    Ok(Response::MintTokenTransaction(MintTokenTransactionDto::from(
+                        &transaction,
+                    )))
+                })
+                .await
+            }
+            AccountMethod::ListNfts => {
+                let nfts = account_handle.list_nfts_with_metadata().await;
+                Ok(Response::Nfts(nfts.iter().map(NftWithMetadataDto::from).collect()))
+            }
             AccountMethod::MinimumRequiredStorageDeposit { output } => {
                 convert_async_panics(|| async {
                     let output = Output::try_from(output)?;
@@ -532,9 +621,187 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::MintCollection {
+                collection_options,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle
+                        .mint_collection(collection_options.clone(), options.clone())
+                        .await?;
+                    Ok(Response::MintCollectionTransaction(MintCollectionTransactionDto::from(
+                        &transaction,
+                    )))
+                })
+                .await
+            }
+            AccountMethod::MintCollectionNfts {
+                issuer,
+                nfts_options,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transactions = account_handle
+                        .mint_collection_nfts(issuer.clone(), nfts_options.clone(), options.clone())
+                        .await?;
+                    Ok(Response::SentTransactions(
+                        transactions.iter().map(TransactionDto::from).collect(),
+                    ))
+                })
+                .await
+            }
+            AccountMethod::ListCollectionNfts { issuer } => {
+                let nfts = account_handle.list_collection_nfts(issuer).await?;
+                Ok(Response::Nfts(nfts.iter().map(NftWithMetadataDto::from).collect()))
+            }
+            AccountMethod::CreateAlias { alias_options, options } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle
+                        .create_alias(alias_options.clone(), options.clone())
+                        .await?;
+                    Ok(Response::CreateAliasTransaction(CreateAliasTransactionDto::from(
+                        &transaction,
+                    )))
+                })
+                .await
+            }
+            AccountMethod::UpdateAliasStateMetadata {
+                alias_id,
+                state_metadata,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let alias_id = AliasId::try_from(alias_id)?;
+                    let transaction = account_handle
+                        .update_alias_state_metadata(alias_id, state_metadata.clone(), options.clone())
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::UpdateAliasGovernorMetadata {
+                alias_id,
+                metadata,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let alias_id = AliasId::try_from(alias_id)?;
+                    let transaction = account_handle
+                        .update_alias_governor_metadata(alias_id, metadata.clone(), options.clone())
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::SetAliasStateController {
+                alias_id,
+                address,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let alias_id = AliasId::try_from(alias_id)?;
+                    let transaction = account_handle
+                        .set_alias_state_controller(alias_id, address.clone(), options.clone())
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::SetAliasGovernor {
+                alias_id,
+                address,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let alias_id = AliasId::try_from(alias_id)?;
+                    let transaction = account_handle
+                        .set_alias_governor(alias_id, address.clone(), options.clone())
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::TransferAlias {
+                alias_id,
+                address,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let alias_id = AliasId::try_from(alias_id)?;
+                    let transaction = account_handle
+                        .transfer_alias(alias_id, address.clone(), options.clone())
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
             AccountMethod::GetBalance => Ok(Response::Balance(AccountBalanceDto::from(
                 &account_handle.balance().await?,
             ))),
+            AccountMethod::GetAddressBalances => {
+                let address_balances = account_handle.address_balances().await?;
+                Ok(Response::AddressBalances(
+                    address_balances.iter().map(AddressBalanceDto::from).collect(),
+                ))
+            }
+            AccountMethod::SetBalanceHistoryResolution {
+                resolution_in_milliseconds,
+            } => {
+                account_handle
+                    .set_balance_history_resolution(*resolution_in_milliseconds)
+                    .await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::GetBalanceAt { timestamp } => {
+                let snapshot = account_handle.get_balance_at(*timestamp).await;
+                Ok(Response::BalanceSnapshot(
+                    snapshot.as_ref().map(BalanceSnapshotDto::from),
+                ))
+            }
+            AccountMethod::GetBalanceHistory { from_time, to_time } => {
+                let snapshots = account_handle.get_balance_history(*from_time, *to_time).await;
+                Ok(Response::BalanceHistory(
+                    snapshots.iter().map(BalanceSnapshotDto::from).collect(),
+                ))
+            }
+            AccountMethod::FormatBaseCoinAmount { amount, options } => {
+                let amount: u64 = amount
+                    .parse()
+                    .map_err(|_| iota_client::Error::InvalidAmount(amount.clone()))?;
+                let formatted_amount = account_handle.format_base_coin_amount(amount, options.clone()).await?;
+                Ok(Response::FormattedAmount(formatted_amount))
+            }
+            AccountMethod::ParseBaseCoinAmount { amount } => {
+                let amount = account_handle.parse_base_coin_amount(amount).await?;
+                Ok(Response::BaseCoinAmount(amount.to_string()))
+            }
+            AccountMethod::FormatNativeTokenAmount {
+                token_id,
+                amount,
+                options,
+            } => {
+                let token_id = TokenId::try_from(token_id)?;
+                let amount = U256::try_from(amount)?;
+                let formatted_amount = account_handle
+                    .format_native_token_amount(&token_id, amount, options.clone())
+                    .await?;
+                Ok(Response::FormattedAmount(formatted_amount))
+            }
+            AccountMethod::ParseNativeTokenAmount { token_id, amount } => {
+                let token_id = TokenId::try_from(token_id)?;
+                let amount = account_handle.parse_native_token_amount(&token_id, amount).await?;
+                Ok(Response::NativeTokenAmount(U256Dto::from(&amount)))
+            }
+            AccountMethod::CreatePaymentRequestUri { payment_request } => {
+                let uri = account_handle
+                    .create_payment_request_uri(PaymentRequest::try_from(payment_request)?)
+                    .await?;
+                Ok(Response::PaymentRequestUri(uri))
+            }
+            AccountMethod::ParsePaymentRequestUri { uri } => {
+                let output_options = account_handle.parse_payment_request_uri(uri).await?;
+                Ok(Response::OutputOptions(OutputOptionsDto::from(&output_options)))
+            }
             AccountMethod::PrepareOutput {
                 options,
                 transaction_options,
@@ -643,6 +910,23 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::SendPayments { payments, options } => {
+                convert_async_panics(|| async {
+                    let transactions = account_handle
+                        .send_payments(
+                            payments
+                                .iter()
+                                .map(Payment::try_from)
+                                .collect::<Result<Vec<Payment>>>()?,
+                            options.clone(),
+                        )
+                        .await?;
+                    Ok(Response::SentTransactions(
+                        transactions.iter().map(TransactionDto::from).collect(),
+                    ))
+                })
+                .await
+            }
             AccountMethod::SetAlias { alias } => {
                 convert_async_panics(|| async {
                     account_handle.set_alias(alias).await?;
@@ -650,6 +934,26 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::SendFromChainAddress {
+                chain_address,
+                outputs,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle
+                        .send_from_chain_address(
+                            chain_address.clone(),
+                            outputs
+                                .iter()
+                                .map(|o| Ok(Output::try_from(o)?))
+                                .collect::<crate::Result<Vec<Output>>>()?,
+                            options.clone(),
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
             AccountMethod::SendOutputs { outputs, options } => {
                 convert_async_panics(|| async {
                     let transaction = account_handle
@@ -708,6 +1012,153 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::GetExpiredOutgoingOutputs => {
+                let output_ids = account_handle.get_expired_outgoing_outputs().await?;
+                Ok(Response::OutputIds(output_ids))
+            }
+            AccountMethod::ReclaimExpiredOutputs => {
+                convert_async_panics(|| async {
+                    let transactions = account_handle.reclaim_expired_outputs().await?;
+                    Ok(Response::SentTransactions(
+                        transactions.iter().map(TransactionDto::from).collect(),
+                    ))
+                })
+                .await
+            }
+            AccountMethod::SetClaimPolicy { claim_policy } => {
+                account_handle.set_claim_policy(claim_policy.clone()).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::GetClaimPolicy => {
+                let claim_policy = account_handle.get_claim_policy().await;
+                Ok(Response::ClaimPolicy(claim_policy))
+            }
+            AccountMethod::SetRejectionPolicy { rejection_policy } => {
+                account_handle.set_rejection_policy(rejection_policy.clone()).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::GetRejectionPolicy => {
+                let rejection_policy = account_handle.get_rejection_policy().await;
+                Ok(Response::RejectionPolicy(rejection_policy))
+            }
+            AccountMethod::IgnoreOutputs { output_ids } => {
+                account_handle.ignore_outputs(output_ids.to_vec()).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::UnignoreOutputs { output_ids } => {
+                account_handle.unignore_outputs(output_ids.to_vec()).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::ReturnOutputs { output_ids, options } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle
+                        .return_outputs(output_ids.to_vec(), options.clone())
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::SchedulePayment {
+                outputs,
+                trigger,
+                options,
+            } => {
+                let scheduled_payment = account_handle
+                    .schedule_payment(
+                        outputs
+                            .iter()
+                            .map(OutputOptions::try_from)
+                            .collect::<crate::Result<Vec<OutputOptions>>>()?,
+                        *trigger,
+                        options.clone(),
+                    )
+                    .await?;
+                Ok(Response::ScheduledPayment(ScheduledPaymentDto::from(
+                    &scheduled_payment,
+                )))
+            }
+            AccountMethod::ListScheduledPayments => {
+                let scheduled_payments = account_handle.list_scheduled_payments().await;
+                Ok(Response::ScheduledPayments(
+                    scheduled_payments.iter().map(ScheduledPaymentDto::from).collect(),
+                ))
+            }
+            AccountMethod::EditScheduledPayment {
+                payment_id,
+                outputs,
+                trigger,
+                options,
+            } => {
+                let scheduled_payment = account_handle
+                    .edit_scheduled_payment(
+                        *payment_id,
+                        outputs
+                            .iter()
+                            .map(OutputOptions::try_from)
+                            .collect::<crate::Result<Vec<OutputOptions>>>()?,
+                        *trigger,
+                        options.clone(),
+                    )
+                    .await?;
+                Ok(Response::ScheduledPayment(ScheduledPaymentDto::from(
+                    &scheduled_payment,
+                )))
+            }
+            AccountMethod::CancelScheduledPayment { payment_id } => {
+                let scheduled_payment = account_handle.cancel_scheduled_payment(*payment_id).await?;
+                Ok(Response::ScheduledPayment(ScheduledPaymentDto::from(
+                    &scheduled_payment,
+                )))
+            }
+            AccountMethod::CreateRecurringPayment {
+                outputs,
+                schedule,
+                options,
+            } => {
+                let recurring_payment = account_handle
+                    .create_recurring_payment(
+                        outputs
+                            .iter()
+                            .map(OutputOptions::try_from)
+                            .collect::<crate::Result<Vec<OutputOptions>>>()?,
+                        *schedule,
+                        options.clone(),
+                    )
+                    .await?;
+                Ok(Response::RecurringPayment(RecurringPaymentDto::from(
+                    &recurring_payment,
+                )))
+            }
+            AccountMethod::ListRecurringPayments => {
+                let recurring_payments = account_handle.list_recurring_payments().await;
+                Ok(Response::RecurringPayments(
+                    recurring_payments.iter().map(RecurringPaymentDto::from).collect(),
+                ))
+            }
+            AccountMethod::CancelRecurringPayment { payment_id } => {
+                let recurring_payment = account_handle.cancel_recurring_payment(*payment_id).await?;
+                Ok(Response::RecurringPayment(RecurringPaymentDto::from(
+                    &recurring_payment,
+                )))
+            }
+            AccountMethod::CreateInvoice { options } => {
+                let invoice = account_handle
+                    .create_invoice(InvoiceOptions::try_from(options)?)
+                    .await?;
+                Ok(Response::Invoice(Some(InvoiceDto::from(&invoice))))
+            }
+            AccountMethod::GetInvoice { invoice_id } => {
+                let invoice = account_handle.get_invoice(*invoice_id).await;
+                Ok(Response::Invoice(invoice.as_ref().map(InvoiceDto::from)))
+            }
+            AccountMethod::ListInvoices => {
+                let invoices = account_handle.list_invoices().await;
+                Ok(Response::Invoices(invoices.iter().map(InvoiceDto::from).collect()))
+            }
+            AccountMethod::CancelInvoice { invoice_id } => {
+                let invoice = account_handle.cancel_invoice(*invoice_id).await?;
+                Ok(Response::Invoice(Some(InvoiceDto::from(&invoice))))
+            }
         }
     }
 
@@ -15,7 +15,7 @@ use tokio::sync::mpsc::unbounded_channel;
 
 pub use self::{
     account_method::AccountMethod,
-    dtos::{AccountBalanceDto, AddressWithAmountDto, AddressWithUnspentOutputsDto},
+    dtos::{AccountBalanceDto, AddressWithAmountDto, AddressWithUnspentOutputsDto, PaymentDto},
     message::{AccountToCreate, Message},
     message_handler::WalletMessageHandler,
     response::Response,
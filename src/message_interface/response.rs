@@ -8,6 +8,7 @@ use iota_client::secret::LedgerStatus;
 use iota_client::{
     api::{PreparedTransactionDataDto, SignedTransactionDataDto},
     bee_block::{
+        dto::U256Dto,
         output::{dto::OutputDto, OutputId},
         payload::transaction::{dto::TransactionPayloadDto, TransactionId},
     },
@@ -18,11 +19,20 @@ use serde::Serialize;
 
 use crate::{
     account::{
-        operations::transaction::high_level::minting::mint_native_token::MintTokenTransactionDto,
+        operations::transaction::{
+            high_level::{
+                alias::CreateAliasTransactionDto,
+                minting::{mint_collection::MintCollectionTransactionDto, mint_native_token::MintTokenTransactionDto},
+            },
+            prepare_output::OutputOptionsDto,
+        },
         types::{address::AccountAddress, TransactionDto},
-        OutputDataDto,
+        Annotation, ClaimPolicy, DecodedTransactionDto, InvoiceDto, NftWithMetadataDto, OutputDataDto, OutputPageDto,
+        RecurringPaymentDto, RejectionPolicy, ScheduledPaymentDto, TransactionPageDto,
+    },
+    message_interface::dtos::{
+        AccountBalanceDto, AccountDto, AddressBalanceDto, AddressWithUnspentOutputsDto, BalanceSnapshotDto,
     },
-    message_interface::dtos::{AccountBalanceDto, AccountDto, AddressWithUnspentOutputsDto},
     Error,
 };
 
@@ -52,11 +62,18 @@ pub enum Response {
     /// [`PrepareOutput`](crate::message_interface::AccountMethod::PrepareOutput)
     Output(OutputDto),
     /// Response for
+    /// [`ParsePaymentRequestUri`](crate::message_interface::AccountMethod::ParsePaymentRequestUri)
+    OutputOptions(OutputOptionsDto),
+    /// Response for
+    /// [`CreatePaymentRequestUri`](crate::message_interface::AccountMethod::CreatePaymentRequestUri)
+    PaymentRequestUri(String),
+    /// Response for
     /// [`MinimumRequiredStorageDeposit`](crate::message_interface::AccountMethod::MinimumRequiredStorageDeposit)
     MinimumRequiredStorageDeposit(String),
     /// Response for
     /// [`GetOutputsWithAdditionalUnlockConditions`](crate::message_interface::AccountMethod::
-    /// GetOutputsWithAdditionalUnlockConditions)
+    /// GetOutputsWithAdditionalUnlockConditions),
+    /// [`GetExpiredOutgoingOutputs`](crate::message_interface::AccountMethod::GetExpiredOutgoingOutputs)
     OutputIds(Vec<OutputId>),
     /// Response for [`GetOutput`](crate::message_interface::AccountMethod::GetOutput)
     OutputData(Option<Box<OutputDataDto>>),
@@ -65,6 +82,9 @@ pub enum Response {
     /// [`ListUnspentOutputs`](crate::message_interface::AccountMethod::ListUnspentOutputs)
     OutputsData(Vec<OutputDataDto>),
     /// Response for
+    /// [`QueryOutputs`](crate::message_interface::AccountMethod::QueryOutputs)
+    OutputPage(OutputPageDto),
+    /// Response for
     /// [`PrepareSendAmount`](crate::message_interface::AccountMethod::PrepareSendAmount),
     /// [`PrepareTransaction`](crate::message_interface::AccountMethod::PrepareTransaction)
     PreparedTransaction(PreparedTransactionDataDto),
@@ -72,10 +92,20 @@ pub enum Response {
     /// [`GetTransaction`](crate::message_interface::AccountMethod::GetTransaction),
     Transaction(Option<Box<TransactionDto>>),
     /// Response for
+    /// [`DecodeTransaction`](crate::message_interface::AccountMethod::DecodeTransaction)
+    DecodedTransaction(Option<Box<DecodedTransactionDto>>),
+    /// Response for
     /// [`ListTransactions`](crate::message_interface::AccountMethod::ListTransactions),
     /// [`ListPendingTransactions`](crate::message_interface::AccountMethod::ListPendingTransactions)
     Transactions(Vec<TransactionDto>),
     /// Response for
+    /// [`QueryTransactions`](crate::message_interface::AccountMethod::QueryTransactions)
+    TransactionPage(TransactionPageDto),
+    /// Response for
+    /// [`ExportTransactions`](crate::message_interface::Message::ExportTransactions),
+    /// [`ExportTransactions`](crate::message_interface::AccountMethod::ExportTransactions)
+    ExportedTransactions(String),
+    /// Response for
     /// [`SignTransaction`](crate::message_interface::AccountMethod::SignTransaction)
     SignedTransactionData(SignedTransactionDataDto),
     /// GenerateAddress response.
@@ -86,6 +116,25 @@ pub enum Response {
     /// [`SyncAccount`](crate::message_interface::AccountMethod::SyncAccount)
     Balance(AccountBalanceDto),
     /// Response for
+    /// [`GetAddressBalances`](crate::message_interface::AccountMethod::GetAddressBalances)
+    AddressBalances(Vec<AddressBalanceDto>),
+    /// Response for
+    /// [`GetBalanceAt`](crate::message_interface::AccountMethod::GetBalanceAt)
+    BalanceSnapshot(Option<BalanceSnapshotDto>),
+    /// Response for
+    /// [`GetBalanceHistory`](crate::message_interface::AccountMethod::GetBalanceHistory)
+    BalanceHistory(Vec<BalanceSnapshotDto>),
+    /// Response for
+    /// [`FormatBaseCoinAmount`](crate::message_interface::AccountMethod::FormatBaseCoinAmount),
+    /// [`FormatNativeTokenAmount`](crate::message_interface::AccountMethod::FormatNativeTokenAmount)
+    FormattedAmount(String),
+    /// Response for
+    /// [`ParseBaseCoinAmount`](crate::message_interface::AccountMethod::ParseBaseCoinAmount)
+    BaseCoinAmount(String),
+    /// Response for
+    /// [`ParseNativeTokenAmount`](crate::message_interface::AccountMethod::ParseNativeTokenAmount)
+    NativeTokenAmount(U256Dto),
+    /// Response for
     /// [`GetLedgerStatus`](crate::message_interface::Message::GetLedgerStatus),
     #[cfg(feature = "ledger_nano")]
     LedgerStatus(LedgerStatus),
@@ -99,16 +148,71 @@ pub enum Response {
     /// [`SendNativeTokens`](crate::message_interface::AccountMethod::SendNativeTokens),
     /// [`SendNft`](crate::message_interface::AccountMethod::SendNft),
     /// [`SendOutputs`](crate::message_interface::AccountMethod::SendOutputs)
+    /// [`SendFromChainAddress`](crate::message_interface::AccountMethod::SendFromChainAddress)
     /// [`SubmitAndStoreTransaction`](crate::message_interface::AccountMethod::SubmitAndStoreTransaction)
+    /// [`UpdateAliasStateMetadata`](crate::message_interface::AccountMethod::UpdateAliasStateMetadata)
+    /// [`UpdateAliasGovernorMetadata`](crate::message_interface::AccountMethod::UpdateAliasGovernorMetadata)
+    /// [`SetAliasStateController`](crate::message_interface::AccountMethod::SetAliasStateController)
+    /// [`SetAliasGovernor`](crate::message_interface::AccountMethod::SetAliasGovernor)
+    /// [`TransferAlias`](crate::message_interface::AccountMethod::TransferAlias)
+    /// [`ReturnOutputs`](crate::message_interface::AccountMethod::ReturnOutputs)
     SentTransaction(TransactionDto),
     /// Response for
     /// [`TryClaimOutputs`](crate::message_interface::AccountMethod::TryClaimOutputs),
     /// [`ClaimOutputs`](crate::message_interface::AccountMethod::ClaimOutputs)
     /// [`ConsolidateOutputs`](crate::message_interface::AccountMethod::ConsolidateOutputs)
+    /// [`ReconcileOutboxes`](crate::message_interface::Message::ReconcileOutboxes),
+    /// [`SendPayments`](crate::message_interface::AccountMethod::SendPayments)
+    /// [`MintCollectionNfts`](crate::message_interface::AccountMethod::MintCollectionNfts)
+    /// [`ReclaimExpiredOutputs`](crate::message_interface::AccountMethod::ReclaimExpiredOutputs)
     SentTransactions(Vec<TransactionDto>),
     /// [`MintNativeToken`](crate::message_interface::AccountMethod::MintNativeToken),
+    /// [`IncreaseNativeTokenSupply`](crate::message_interface::AccountMethod::IncreaseNativeTokenSupply)
     MintTokenTransaction(MintTokenTransactionDto),
     /// Response for
+    /// [`MintCollection`](crate::message_interface::AccountMethod::MintCollection)
+    MintCollectionTransaction(MintCollectionTransactionDto),
+    /// Response for
+    /// [`CreateAlias`](crate::message_interface::AccountMethod::CreateAlias)
+    CreateAliasTransaction(CreateAliasTransactionDto),
+    /// Response for
+    /// [`SchedulePayment`](crate::message_interface::AccountMethod::SchedulePayment),
+    /// [`EditScheduledPayment`](crate::message_interface::AccountMethod::EditScheduledPayment),
+    /// [`CancelScheduledPayment`](crate::message_interface::AccountMethod::CancelScheduledPayment)
+    ScheduledPayment(ScheduledPaymentDto),
+    /// Response for
+    /// [`ListScheduledPayments`](crate::message_interface::AccountMethod::ListScheduledPayments)
+    ScheduledPayments(Vec<ScheduledPaymentDto>),
+    /// Response for
+    /// [`CreateRecurringPayment`](crate::message_interface::AccountMethod::CreateRecurringPayment),
+    /// [`CancelRecurringPayment`](crate::message_interface::AccountMethod::CancelRecurringPayment)
+    RecurringPayment(RecurringPaymentDto),
+    /// Response for
+    /// [`ListRecurringPayments`](crate::message_interface::AccountMethod::ListRecurringPayments)
+    RecurringPayments(Vec<RecurringPaymentDto>),
+    /// Response for
+    /// [`CreateInvoice`](crate::message_interface::AccountMethod::CreateInvoice),
+    /// [`GetInvoice`](crate::message_interface::AccountMethod::GetInvoice),
+    /// [`CancelInvoice`](crate::message_interface::AccountMethod::CancelInvoice)
+    Invoice(Option<InvoiceDto>),
+    /// Response for
+    /// [`ListInvoices`](crate::message_interface::AccountMethod::ListInvoices)
+    Invoices(Vec<InvoiceDto>),
+    /// Response for
+    /// [`ListNfts`](crate::message_interface::AccountMethod::ListNfts),
+    /// [`ListCollectionNfts`](crate::message_interface::AccountMethod::ListCollectionNfts)
+    Nfts(Vec<NftWithMetadataDto>),
+    /// Response for
+    /// [`GetTransactionAnnotation`](crate::message_interface::AccountMethod::GetTransactionAnnotation),
+    /// [`GetOutputAnnotation`](crate::message_interface::AccountMethod::GetOutputAnnotation)
+    Annotation(Option<Annotation>),
+    /// Response for
+    /// [`GetClaimPolicy`](crate::message_interface::AccountMethod::GetClaimPolicy)
+    ClaimPolicy(ClaimPolicy),
+    /// Response for
+    /// [`GetRejectionPolicy`](crate::message_interface::AccountMethod::GetRejectionPolicy)
+    RejectionPolicy(Option<RejectionPolicy>),
+    /// Response for
     /// [`IsStrongholdPasswordAvailable`](crate::message_interface::Message::IsStrongholdPasswordAvailable)
     StrongholdPasswordIsAvailable(bool),
     /// An error occurred.
@@ -137,6 +241,13 @@ pub enum Response {
     /// [`StartBackgroundSync`](crate::message_interface::Message::StartBackgroundSync),
     /// [`StopBackgroundSync`](crate::message_interface::Message::StopBackgroundSync),
     /// [`EmitTestEvent`](crate::message_interface::Message::EmitTestEvent),
+    /// [`SetClaimPolicy`](crate::message_interface::AccountMethod::SetClaimPolicy),
+    /// [`SetRejectionPolicy`](crate::message_interface::AccountMethod::SetRejectionPolicy),
+    /// [`IgnoreOutputs`](crate::message_interface::AccountMethod::IgnoreOutputs),
+    /// [`UnignoreOutputs`](crate::message_interface::AccountMethod::UnignoreOutputs),
+    /// [`SetBalanceHistoryResolution`](crate::message_interface::AccountMethod::SetBalanceHistoryResolution),
+    /// [`AnnotateTransaction`](crate::message_interface::AccountMethod::AnnotateTransaction),
+    /// [`AnnotateOutput`](crate::message_interface::AccountMethod::AnnotateOutput),
     Ok(()),
 }
 
@@ -151,20 +262,32 @@ impl Debug for Response {
                 write!(f, "AddressesWithUnspentOutputs({:?})", addresses)
             }
             Response::Output(output) => write!(f, "Output({:?})", output),
+            Response::OutputOptions(options) => write!(f, "OutputOptions({:?})", options),
+            Response::PaymentRequestUri(uri) => write!(f, "PaymentRequestUri({})", uri),
             Response::MinimumRequiredStorageDeposit(amount) => write!(f, "MinimumRequiredStorageDeposit({:?})", amount),
             Response::OutputIds(output_ids) => write!(f, "OutputIds({:?})", output_ids),
             Response::OutputData(output) => write!(f, "OutputData({:?})", output),
             Response::OutputsData(outputs) => write!(f, "OutputsData{:?}", outputs),
+            Response::OutputPage(output_page) => write!(f, "OutputPage({:?})", output_page),
             Response::PreparedTransaction(transaction_data) => {
                 write!(f, "PreparedTransaction({:?})", transaction_data)
             }
             Response::Transaction(transaction) => write!(f, "Transaction({:?})", transaction),
+            Response::DecodedTransaction(transaction) => write!(f, "DecodedTransaction({:?})", transaction),
             Response::Transactions(transactions) => write!(f, "Transactions({:?})", transactions),
+            Response::TransactionPage(transaction_page) => write!(f, "TransactionPage({:?})", transaction_page),
+            Response::ExportedTransactions(export) => write!(f, "ExportedTransactions({:?})", export),
             Response::SignedTransactionData(signed_transaction_data) => {
                 write!(f, "SignedTransactionData({:?})", signed_transaction_data)
             }
             Response::GeneratedAddress(addresses) => write!(f, "GeneratedAddress({:?})", addresses),
             Response::Balance(balance) => write!(f, "Balance({:?})", balance),
+            Response::AddressBalances(balances) => write!(f, "AddressBalances({:?})", balances),
+            Response::BalanceSnapshot(snapshot) => write!(f, "BalanceSnapshot({:?})", snapshot),
+            Response::BalanceHistory(snapshots) => write!(f, "BalanceHistory({:?})", snapshots),
+            Response::FormattedAmount(amount) => write!(f, "FormattedAmount({})", amount),
+            Response::BaseCoinAmount(amount) => write!(f, "BaseCoinAmount({})", amount),
+            Response::NativeTokenAmount(amount) => write!(f, "NativeTokenAmount({:?})", amount),
             Response::IncomingTransactionData(transaction_data) => {
                 write!(f, "IncomingTransactionData({:?})", transaction_data)
             }
@@ -173,6 +296,26 @@ impl Debug for Response {
             Response::MintTokenTransaction(mint_transaction) => {
                 write!(f, "MintTokenTransaction({:?})", mint_transaction)
             }
+            Response::ScheduledPayment(scheduled_payment) => write!(f, "ScheduledPayment({:?})", scheduled_payment),
+            Response::ScheduledPayments(scheduled_payments) => {
+                write!(f, "ScheduledPayments({:?})", scheduled_payments)
+            }
+            Response::RecurringPayment(recurring_payment) => write!(f, "RecurringPayment({:?})", recurring_payment),
+            Response::RecurringPayments(recurring_payments) => {
+                write!(f, "RecurringPayments({:?})", recurring_payments)
+            }
+            Response::Invoice(invoice) => write!(f, "Invoice({:?})", invoice),
+            Response::Invoices(invoices) => write!(f, "Invoices({:?})", invoices),
+            Response::MintCollectionTransaction(mint_transaction) => {
+                write!(f, "MintCollectionTransaction({:?})", mint_transaction)
+            }
+            Response::CreateAliasTransaction(alias_transaction) => {
+                write!(f, "CreateAliasTransaction({:?})", alias_transaction)
+            }
+            Response::Nfts(nfts) => write!(f, "Nfts({:?})", nfts),
+            Response::Annotation(annotation) => write!(f, "Annotation({:?})", annotation),
+            Response::ClaimPolicy(claim_policy) => write!(f, "ClaimPolicy({:?})", claim_policy),
+            Response::RejectionPolicy(rejection_policy) => write!(f, "RejectionPolicy({:?})", rejection_policy),
             Response::StrongholdPasswordIsAvailable(is_available) => {
                 write!(f, "StrongholdPasswordIsAvailable({:?})", is_available)
             }
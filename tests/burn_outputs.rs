@@ -62,6 +62,7 @@ async fn mint_and_burn_nft() -> Result<()> {
         address: Some(account_addresses[0].address().to_bech32()),
         immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
         metadata: Some(b"some nft metadata".to_vec()),
+        irc_27_metadata: None,
     }];
 
     let transaction = account.mint_nfts(nft_options, None).await.unwrap();
@@ -138,6 +139,7 @@ async fn mint_and_melt_native_token() -> Result<()> {
         circulating_supply,
         maximum_supply: U256::from(100i32),
         foundry_metadata: None,
+        token_metadata: None,
     };
 
     let transaction = account.mint_native_token(native_token_options, None).await.unwrap();
@@ -0,0 +1,88 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use iota_client::request_funds_from_faucet;
+use iota_wallet::{
+    account::TransactionOptions,
+    account_manager::AccountManager,
+    iota_client::constants::SHIMMER_COIN_TYPE,
+    secret::{mnemonic::MnemonicSecretManager, SecretManager},
+    AddressWithAmount, ClientOptions, Result,
+};
+
+#[ignore]
+#[tokio::test]
+#[cfg(feature = "storage")]
+// A transaction sent with an idempotency key is returned again after restarting the account manager
+async fn idempotent_send_after_restart() -> Result<()> {
+    let storage_path = "test-storage/idempotent_send_after_restart";
+    std::fs::remove_dir_all(storage_path).unwrap_or(());
+    let mnemonic = "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak";
+    let client_options = ClientOptions::new()
+        .with_node("http://localhost:14265")?
+        .with_node_sync_disabled();
+
+    let manager = AccountManager::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            mnemonic,
+        )?))
+        .with_client_options(client_options.clone())
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path(storage_path)
+        .finish()
+        .await?;
+
+    let account = manager
+        .create_account()
+        .with_alias("Alice".to_string())
+        .finish()
+        .await?;
+    let address = account.generate_addresses(1, None).await?[0].address().to_bech32();
+    request_funds_from_faucet("http://localhost:14265/api/enqueue", &address).await?;
+    tokio::time::sleep(Duration::new(15, 0)).await;
+    account.sync(None).await?;
+
+    let outputs = vec![AddressWithAmount {
+        address: address.clone(),
+        amount: 1_000_000,
+    }];
+    let options = Some(TransactionOptions {
+        idempotency_key: Some("idempotent-send".to_string()),
+        ..Default::default()
+    });
+    let transaction = account.send_amount(outputs.clone(), options.clone()).await?;
+
+    // The same key in the same session doesn't send a second transaction
+    let repeated_transaction = account.send_amount(outputs.clone(), options.clone()).await?;
+    assert_eq!(transaction.transaction_id, repeated_transaction.transaction_id);
+    drop(account);
+    drop(manager);
+
+    let manager = AccountManager::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            mnemonic,
+        )?))
+        .with_client_options(client_options)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path(storage_path)
+        .finish()
+        .await?;
+    let account = manager.get_account("Alice".to_string()).await?;
+
+    // The index of the idempotency keys is persisted, so the key is still known after the restart
+    let restored_transaction = account.send_amount(outputs, options).await?;
+    assert_eq!(transaction.transaction_id, restored_transaction.transaction_id);
+    assert_eq!(
+        account
+            .get_transaction_by_idempotency_key("idempotent-send")
+            .await
+            .map(|transaction| transaction.transaction_id),
+        Some(transaction.transaction_id)
+    );
+    assert!(account.list_outbox_entries().await.is_empty());
+
+    std::fs::remove_dir_all(storage_path).unwrap_or(());
+    Ok(())
+}
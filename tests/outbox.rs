@@ -0,0 +1,82 @@
+// Copyright 2022 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use iota_client::request_funds_from_faucet;
+use iota_wallet::{
+    account_manager::AccountManager,
+    iota_client::constants::SHIMMER_COIN_TYPE,
+    secret::{mnemonic::MnemonicSecretManager, SecretManager},
+    AddressWithAmount, ClientOptions, Result,
+};
+
+const MNEMONIC: &str = "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak";
+const OTHER_MNEMONIC: &str = "route hen wink below army inmate object crew vintage gas best space visit say fortune gown few brain emerge umbrella consider spider digital galaxy";
+
+async fn build_manager(storage_path: &str, mnemonic: &str) -> Result<AccountManager> {
+    AccountManager::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            mnemonic,
+        )?))
+        .with_client_options(
+            ClientOptions::new()
+                .with_node("http://localhost:14265")?
+                .with_node_sync_disabled(),
+        )
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path(storage_path)
+        .finish()
+        .await
+}
+
+#[ignore]
+#[tokio::test]
+#[cfg(feature = "storage")]
+// A transaction that fails after it got added to the outbox doesn't leave an entry or locked inputs behind, also not
+// after restarting the account manager
+async fn outbox_cleanup_after_restart() -> Result<()> {
+    let storage_path = "test-storage/outbox_cleanup_after_restart";
+    std::fs::remove_dir_all(storage_path).unwrap_or(());
+
+    let manager = build_manager(storage_path, MNEMONIC).await?;
+    let account = manager
+        .create_account()
+        .with_alias("Alice".to_string())
+        .finish()
+        .await?;
+    let address = account.generate_addresses(1, None).await?[0].address().to_bech32();
+    request_funds_from_faucet("http://localhost:14265/api/enqueue", &address).await?;
+    tokio::time::sleep(Duration::new(15, 0)).await;
+    let balance = account.sync(None).await?;
+    drop(account);
+    drop(manager);
+
+    // The stored addresses don't belong to this mnemonic, so the signatures are invalid and the transaction fails
+    // after it got signed
+    let manager = build_manager(storage_path, OTHER_MNEMONIC).await?;
+    let account = manager.get_account("Alice".to_string()).await?;
+    let outputs = vec![AddressWithAmount {
+        address: address.clone(),
+        amount: 1_000_000,
+    }];
+    assert!(account.send_amount(outputs.clone(), None).await.is_err());
+    assert!(account.list_outbox_entries().await.is_empty());
+    assert_eq!(
+        account.balance().await?.base_coin.available,
+        balance.base_coin.available
+    );
+    drop(account);
+    drop(manager);
+
+    // Creating the account manager reconciles the outbox, so nothing is left from the failed transaction
+    let manager = build_manager(storage_path, MNEMONIC).await?;
+    let account = manager.get_account("Alice".to_string()).await?;
+    assert!(account.list_outbox_entries().await.is_empty());
+    let transaction = account.send_amount(outputs, None).await?;
+    assert!(account.get_transaction(&transaction.transaction_id).await.is_some());
+    assert!(account.list_outbox_entries().await.is_empty());
+
+    std::fs::remove_dir_all(storage_path).unwrap_or(());
+    Ok(())
+}